@@ -0,0 +1,77 @@
+use crate::authorize_non_interactive;
+use azure_sdk_core::{AccessToken, TokenCredential, TokenFuture};
+use oauth2::{ClientId, ClientSecret};
+use std::sync::Arc;
+
+/// A [`TokenCredential`](../azure_sdk_core/trait.TokenCredential.html) that
+/// authenticates a service principal with its client secret, using the
+/// OAuth2 client credentials flow.
+///
+/// Every call asks Azure Active Directory for a new token: wrap it in a
+/// `CachedTokenCredential` to reuse the tokens until they are about to expire.
+#[derive(Debug, Clone)]
+pub struct ClientSecretCredential {
+    client: Arc<reqwest::Client>,
+    tenant_id: String,
+    client_id: ClientId,
+    client_secret: ClientSecret,
+}
+
+impl ClientSecretCredential {
+    pub fn new(
+        client: Arc<reqwest::Client>,
+        tenant_id: String,
+        client_id: ClientId,
+        client_secret: ClientSecret,
+    ) -> ClientSecretCredential {
+        ClientSecretCredential {
+            client,
+            tenant_id,
+            client_id,
+            client_secret,
+        }
+    }
+}
+
+impl TokenCredential for ClientSecretCredential {
+    fn get_token<'a>(&'a self, scope: &'a str) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let login_response = authorize_non_interactive(
+                self.client.clone(),
+                &self.client_id,
+                &self.client_secret,
+                scope_to_resource(scope),
+                &self.tenant_id,
+            )
+            .await?;
+
+            Ok(AccessToken::new(
+                login_response.access_token().secret().to_owned(),
+                login_response.expires_on,
+            ))
+        })
+    }
+}
+
+/// The v1 token endpoint wants the resource the token is for, not a scope:
+/// `https://storage.azure.com/.default` becomes `https://storage.azure.com/`.
+fn scope_to_resource(scope: &str) -> &str {
+    scope.trim_end_matches(".default")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scopes_are_mapped_to_resources() {
+        assert_eq!(
+            scope_to_resource("https://storage.azure.com/.default"),
+            "https://storage.azure.com/"
+        );
+        assert_eq!(
+            scope_to_resource("https://management.azure.com/"),
+            "https://management.azure.com/"
+        );
+    }
+}
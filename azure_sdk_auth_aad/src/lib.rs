@@ -15,6 +15,8 @@ use url::form_urlencoded;
 use url::Url;
 mod login_response;
 pub use login_response::*;
+mod client_secret_credential;
+pub use client_secret_credential::ClientSecretCredential;
 use std::sync::Arc;
 pub mod errors;
 mod naive_server;
@@ -1,10 +1,12 @@
-use crate::{enumerations::ParsingError, range::ParseError};
+use crate::headers::{ACTIVITY_ID, REQUEST_ID};
+use crate::util::HeaderMapExt;
+use crate::{enumerations::ParsingError, range::ParseError, ResponseFuture};
 use base64;
 use chrono;
 use futures_util::TryStreamExt;
 use http;
 use http::header::ToStrError;
-use hyper::{self, Body, StatusCode};
+use hyper::{self, Body, HeaderMap, StatusCode};
 use serde_json;
 use serde_xml_rs;
 use std;
@@ -17,6 +19,7 @@ use std::string;
 use url::ParseError as URLParseError;
 use uuid;
 use xml::BuilderError as XMLError;
+use xml::{Element, Xml};
 
 quick_error! {
     #[derive(Debug)]
@@ -36,6 +39,34 @@ quick_error! {
     }
 }
 
+quick_error! {
+    #[derive(Debug)]
+    pub enum ConnectionStringError {
+        MalformedSegment(segment: String) {
+            display("Malformed connection string segment (expected key=value): {}", segment)
+        }
+        UnsupportedKey(key: String) {
+            display("Unsupported connection string key: {}", key)
+        }
+        DuplicateKey(key: String) {
+            display("Connection string key specified more than once: {}", key)
+        }
+        MissingKey(key: String) {
+            display("Missing connection string key: {}", key)
+        }
+        InvalidValue(key: String, value: String) {
+            display("Invalid value for connection string key {}: {}", key, value)
+        }
+        InvalidEndpoint(key: String, err: URLParseError) {
+            display("Invalid endpoint for connection string key {}: {}", key, err)
+            cause(err)
+        }
+        InvalidCombination(msg: String) {
+            display("Invalid combination of connection string keys: {}", msg)
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnexpectedHTTPResult {
     expected: StatusCode,
@@ -77,6 +108,167 @@ impl std::error::Error for UnexpectedHTTPResult {
     }
 }
 
+/// An error returned by an Azure service, as described by the response body.
+///
+/// Storage services describe their errors with an XML `<Error>` document, the Table
+/// service with an OData error (either JSON or XML) and Cosmos with a JSON object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError {
+    status: StatusCode,
+    code: String,
+    message: String,
+    request_id: Option<String>,
+    activity_id: Option<String>,
+    body: String,
+}
+
+impl ServiceError {
+    /// Parses the error described by a failed response. Returns `None` if the body
+    /// does not contain an error in any of the known formats.
+    pub fn from_response(
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Option<ServiceError> {
+        let (code, message) = parse_json_error(body).or_else(|| parse_xml_error(body))?;
+
+        let request_id = headers.get_as_string(REQUEST_ID);
+        let activity_id = headers
+            .get_as_string(ACTIVITY_ID)
+            .or_else(|| activity_id_from_message(&message));
+
+        Some(ServiceError {
+            status,
+            code,
+            message,
+            request_id,
+            activity_id,
+            body: body.to_owned(),
+        })
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The error code, for example `BlobNotFound`, `TableAlreadyExists` or `Conflict`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    pub fn activity_id(&self) -> Option<&str> {
+        self.activity_id.as_deref()
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}): {}", self.code, self.status, self.message)
+    }
+}
+
+impl std::error::Error for ServiceError {
+    fn description(&self) -> &str {
+        "Azure service error"
+    }
+
+    fn cause(&self) -> Option<&dyn std::error::Error> {
+        None
+    }
+}
+
+fn parse_json_error(body: &str) -> Option<(String, String)> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+
+    // Table service: {"odata.error":{"code":"...","message":{"lang":"en-US","value":"..."}}}
+    if let Some(error) = value.get("odata.error") {
+        let code = error.get("code")?.as_str()?.to_owned();
+        let message = error
+            .get("message")
+            .and_then(|m| m.get("value").or(Some(m)))
+            .and_then(|m| m.as_str())
+            .unwrap_or_default()
+            .to_owned();
+        return Some((code, message));
+    }
+
+    // Cosmos: {"code":"...","message":"..."}
+    let code = value.get("code")?.as_str()?.to_owned();
+    let message = value
+        .get("message")
+        .and_then(|m| m.as_str())
+        .unwrap_or_default()
+        .to_owned();
+    Some((code, message))
+}
+
+fn parse_xml_error(body: &str) -> Option<(String, String)> {
+    let elem: Element = body.trim_start_matches('\u{feff}').parse().ok()?;
+
+    // Storage services use <Error><Code/><Message/></Error> while the
+    // Table service uses <error><code/><message/></error>.
+    if !elem.name.eq_ignore_ascii_case("error") {
+        return None;
+    }
+
+    let child_text = |name: &str| {
+        elem.children.iter().find_map(|child| match child {
+            Xml::ElementNode(child) if child.name.eq_ignore_ascii_case(name) => {
+                Some(child.content_str())
+            }
+            _ => None,
+        })
+    };
+
+    let code = child_text("code")?;
+    let message = child_text("message").unwrap_or_default();
+    Some((code, message))
+}
+
+// Cosmos embeds the activity id in the message, e.g. "..., ActivityId: 9d1a..., Request URI: ..."
+fn activity_id_from_message(message: &str) -> Option<String> {
+    let start = message.find("ActivityId: ")? + "ActivityId: ".len();
+    let activity_id: String = message[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_hexdigit() || *c == '-')
+        .collect();
+    if activity_id.is_empty() {
+        None
+    } else {
+        Some(activity_id)
+    }
+}
+
+/// Builds the error returned when a response has a status code different from
+/// `expected`: a [`ServiceError`](struct.ServiceError.html) if the body describes
+/// the failure, an [`UnexpectedHTTPResult`](struct.UnexpectedHTTPResult.html) otherwise.
+pub fn unexpected_status_error(
+    expected: StatusCode,
+    received: StatusCode,
+    headers: &HeaderMap,
+    body: &str,
+) -> AzureError {
+    if received.is_client_error() || received.is_server_error() {
+        if let Some(service_error) = ServiceError::from_response(received, headers, body) {
+            return AzureError::ServiceError(Box::new(service_error));
+        }
+    }
+
+    AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(expected, received, body))
+}
+
 quick_error! {
     #[derive(Debug)]
     pub enum Not512ByteAlignedError {
@@ -163,10 +355,21 @@ quick_error! {
             display("Azure Path parse error: {}", err)
             cause(err)
         }
+        ConnectionStringError(err: ConnectionStringError){
+            from()
+            display("Connection string error: {}", err)
+            cause(err)
+        }
         UnexpectedHTTPResult(err: UnexpectedHTTPResult){
             from()
             display("UnexpectedHTTPResult error")
         }
+        ServiceError(err: Box<ServiceError>) {
+            from()
+            from(err: ServiceError) -> (Box::new(err))
+            display("Service error: {}", err)
+            cause(err)
+        }
         HeaderNotFound(msg: String) {
             display("Header not found: {}", msg)
         }
@@ -286,9 +489,45 @@ impl From<()> for AzureError {
     }
 }
 
+impl AzureError {
+    /// The status code of the response, if the error was caused by an unexpected response.
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            AzureError::UnexpectedHTTPResult(err) => Some(err.status_code()),
+            AzureError::ServiceError(err) => Some(err.status_code()),
+            _ => None,
+        }
+    }
+
+    /// The error code returned by the service, if any.
+    pub fn service_error_code(&self) -> Option<&str> {
+        match self {
+            AzureError::ServiceError(err) => Some(err.code()),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status_code() == Some(StatusCode::NOT_FOUND)
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.status_code() == Some(StatusCode::CONFLICT)
+    }
+
+    /// `true` if the service refused the request because of the request rate
+    /// (429 Too Many Requests or 503 Server Busy).
+    pub fn is_throttled(&self) -> bool {
+        matches!(
+            self.status_code(),
+            Some(StatusCode::TOO_MANY_REQUESTS) | Some(StatusCode::SERVICE_UNAVAILABLE)
+        )
+    }
+}
+
 #[inline]
 pub async fn extract_status_headers_and_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
 ) -> Result<(hyper::StatusCode, hyper::HeaderMap, hyper::Chunk), AzureError> {
     let res = resp.await?;
     let (head, body) = res.into_parts();
@@ -301,24 +540,25 @@ pub async fn extract_status_headers_and_body(
 
 #[inline]
 pub async fn check_status_extract_headers_and_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
     expected_status_code: hyper::StatusCode,
 ) -> Result<(hyper::HeaderMap, hyper::Chunk), AzureError> {
     let (status, headers, body) = extract_status_headers_and_body(resp).await?;
     if status == expected_status_code {
         Ok((headers, body))
     } else {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult {
-            expected: expected_status_code,
-            received: status,
-            body: str::from_utf8(&body)?.to_owned(),
-        }))
+        Err(unexpected_status_error(
+            expected_status_code,
+            status,
+            &headers,
+            str::from_utf8(&body)?,
+        ))
     }
 }
 
 #[inline]
 pub async fn check_status_extract_headers_and_body_as_string(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
     expected_status_code: hyper::StatusCode,
 ) -> Result<(hyper::HeaderMap, String), AzureError> {
     let (headers, body) = check_status_extract_headers_and_body(resp, expected_status_code).await?;
@@ -328,7 +568,7 @@ pub async fn check_status_extract_headers_and_body_as_string(
 
 #[inline]
 pub async fn extract_status_and_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
 ) -> Result<(StatusCode, String), AzureError> {
     let res = resp.await?;
     let status = res.status();
@@ -338,18 +578,20 @@ pub async fn extract_status_and_body(
 
 #[inline]
 pub async fn check_status_extract_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
     expected_status_code: hyper::StatusCode,
 ) -> Result<String, AzureError> {
-    let (status, body) = extract_status_and_body(resp).await?;
+    let (status, headers, body) = extract_status_headers_and_body(resp).await?;
+    let body = str::from_utf8(&body)?;
     if status == expected_status_code {
-        Ok(body)
+        Ok(body.to_owned())
     } else {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult {
-            expected: expected_status_code,
-            received: status,
+        Err(unexpected_status_error(
+            expected_status_code,
+            status,
+            &headers,
             body,
-        }))
+        ))
     }
 }
 
@@ -357,18 +599,115 @@ pub async fn check_status_extract_body_2(
     resp: hyper::Response<Body>,
     expected_status: StatusCode,
 ) -> Result<String, AzureError> {
-    let received_status = resp.status();
+    let (head, body) = resp.into_parts();
 
-    let body = resp.into_body().try_concat().await?;
+    let body = body.try_concat().await?;
     let s = String::from_utf8(body.to_vec())?;
     debug!("body: {}", s);
-    if received_status != expected_status {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult {
-            expected: expected_status,
-            received: received_status,
-            body: s,
-        }))
+    if head.status != expected_status {
+        Err(unexpected_status_error(
+            expected_status,
+            head.status,
+            &head.headers,
+            &s,
+        ))
     } else {
         Ok(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use http::header::HeaderValue;
+
+    #[test]
+    fn parse_storage_error() {
+        let body = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?><Error><Code>BlobNotFound</Code><Message>The specified blob does not exist.\nRequestId:4c2d1f7e-601e-0076-4d84-9ee5d3000000\nTime:2019-12-03T10:23:41.3479193Z</Message></Error>";
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            REQUEST_ID,
+            HeaderValue::from_static("4c2d1f7e-601e-0076-4d84-9ee5d3000000"),
+        );
+
+        let err = unexpected_status_error(StatusCode::OK, StatusCode::NOT_FOUND, &headers, body);
+        assert!(err.is_not_found());
+        assert!(!err.is_conflict());
+        assert_eq!(err.service_error_code(), Some("BlobNotFound"));
+
+        match err {
+            AzureError::ServiceError(err) => {
+                assert!(err
+                    .message()
+                    .starts_with("The specified blob does not exist."));
+                assert_eq!(
+                    err.request_id(),
+                    Some("4c2d1f7e-601e-0076-4d84-9ee5d3000000")
+                );
+                assert_eq!(err.activity_id(), None);
+            }
+            _ => panic!("expected a service error"),
+        }
+    }
+
+    #[test]
+    fn parse_table_json_error() {
+        let body = r#"{"odata.error":{"code":"TableAlreadyExists","message":{"lang":"en-US","value":"The table specified already exists.\nRequestId:bd2d7e5e-c002-0035-6b84-9e8cd4000000\nTime:2019-12-03T10:23:41.3479193Z"}}}"#;
+
+        let err =
+            ServiceError::from_response(StatusCode::CONFLICT, &HeaderMap::new(), body).unwrap();
+        assert_eq!(err.code(), "TableAlreadyExists");
+        assert!(err
+            .message()
+            .starts_with("The table specified already exists."));
+    }
+
+    #[test]
+    fn parse_table_xml_error() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?><error xmlns="http://schemas.microsoft.com/ado/2007/08/dataservices/metadata"><code>ResourceNotFound</code><message xml:lang="en-US">The specified resource does not exist.</message></error>"#;
+
+        let err =
+            ServiceError::from_response(StatusCode::NOT_FOUND, &HeaderMap::new(), body).unwrap();
+        assert_eq!(err.code(), "ResourceNotFound");
+        assert_eq!(err.message(), "The specified resource does not exist.");
+    }
+
+    #[test]
+    fn parse_cosmos_error() {
+        let body = r#"{"code":"Conflict","message":"Message: {\"Errors\":[\"Resource with specified id or name already exists.\"]}\r\nActivityId: 6c6f5d9a-06e1-4b1e-a5a4-24d4e1c2a6f1, Request URI: /apps/1, RequestStats: , SDK: Microsoft.Azure.Documents.Common/2.7.0"}"#;
+
+        let err = unexpected_status_error(
+            StatusCode::CREATED,
+            StatusCode::CONFLICT,
+            &HeaderMap::new(),
+            body,
+        );
+        assert!(err.is_conflict());
+        match err {
+            AzureError::ServiceError(err) => {
+                assert_eq!(err.code(), "Conflict");
+                assert_eq!(
+                    err.activity_id(),
+                    Some("6c6f5d9a-06e1-4b1e-a5a4-24d4e1c2a6f1")
+                );
+            }
+            _ => panic!("expected a service error"),
+        }
+    }
+
+    #[test]
+    fn unparsable_body_is_unexpected_http_result() {
+        let err = unexpected_status_error(
+            StatusCode::OK,
+            StatusCode::TOO_MANY_REQUESTS,
+            &HeaderMap::new(),
+            "not an error document",
+        );
+        assert!(err.is_throttled());
+        assert_eq!(err.service_error_code(), None);
+        match err {
+            AzureError::UnexpectedHTTPResult(_) => {}
+            _ => panic!("expected an unexpected HTTP result"),
+        }
+    }
+}
@@ -3,6 +3,8 @@ pub const LEASE_ID: &str = "x-ms-lease-id"; //=> [LeaseId] }
 pub const CLIENT_REQUEST_ID: &str = "x-ms-client-request-id"; //=> [String] }
 pub const BLOB_PUBLIC_ACCESS: &str = "x-ms-blob-public-access"; // [PublicAccess]
 pub const REQUEST_ID: &str = "x-ms-request-id"; //=> [String] }
+pub const VERSION: &str = "x-ms-version";
+pub const ERROR_CODE: &str = "x-ms-error-code";
 pub const LEASE_STATUS: &str = "x-ms-lease-status"; //=> [LeaseStatus] }
 pub const LEASE_STATE: &str = "x-ms-lease-state"; //=> [LeaseState] }
 pub const LEASE_DURATION: &str = "x-ms-lease-duration"; //=> [LeaseDuration] }
@@ -20,12 +22,32 @@ pub const COPY_COMPLETION_TIME: &str = "x-ms-copy-completion-time";
 pub const COPY_PROGRESS: &str = "x-ms-copy-progress";
 pub const COPY_SOURCE: &str = "x-ms-copy-source";
 pub const COPY_STATUS: &str = "x-ms-copy-status";
+pub const COPY_ACTION: &str = "x-ms-copy-action";
+pub const REQUIRES_SYNC: &str = "x-ms-requires-sync";
+pub const SOURCE_IF_MODIFIED_SINCE: &str = "x-ms-source-if-modified-since";
+pub const SOURCE_IF_UNMODIFIED_SINCE: &str = "x-ms-source-if-unmodified-since";
+pub const SOURCE_IF_MATCH: &str = "x-ms-source-if-match";
+pub const SOURCE_IF_NONE_MATCH: &str = "x-ms-source-if-none-match";
+pub const SOURCE_RANGE: &str = "x-ms-source-range";
+pub const SOURCE_CONTENT_MD5: &str = "x-ms-source-content-md5";
+pub const TAGS: &str = "x-ms-tags";
+pub const TAG_COUNT: &str = "x-ms-tag-count";
+pub const IF_TAGS: &str = "x-ms-if-tags";
 pub const CONTENT_MD5: &str = "Content-MD5"; //=> [String] }
 pub const SERVER_ENCRYPTED: &str = "x-ms-server-encrypted";
 pub const BLOB_TYPE: &str = "x-ms-blob-type";
 pub const BLOB_CONTENT_LENGTH: &str = "x-ms-blob-content-length";
 pub const BLOB_ACCESS_TIER: &str = "x-ms-access-tier";
+pub const ACCESS_TIER_INFERRED: &str = "x-ms-access-tier-inferred";
+pub const ACCESS_TIER_CHANGE_TIME: &str = "x-ms-access-tier-change-time";
+pub const ARCHIVE_STATUS: &str = "x-ms-archive-status";
+pub const REHYDRATE_PRIORITY: &str = "x-ms-rehydrate-priority";
 pub const BLOB_SEQUENCE_NUMBER: &str = "x-ms-blob-sequence-number";
+pub const BLOB_CONTENT_TYPE: &str = "x-ms-blob-content-type";
+pub const BLOB_CONTENT_ENCODING: &str = "x-ms-blob-content-encoding";
+pub const BLOB_CONTENT_LANGUAGE: &str = "x-ms-blob-content-language";
+pub const BLOB_CONTENT_MD5: &str = "x-ms-blob-content-md5";
+pub const SEQUENCE_NUMBER_ACTION: &str = "x-ms-sequence-number-action";
 pub const IF_SEQUENCE_NUMBER_LE: &str = "x-ms-if-sequence-number-le";
 pub const IF_SEQUENCE_NUMBER_LT: &str = "x-ms-if-sequence-number-lt";
 pub const IF_SEQUENCE_NUMBER_EQ: &str = "x-ms-if-sequence-number-eq";
@@ -38,3 +60,19 @@ pub const ACCOUNT_KIND: &str = "x-ms-account-kind";
 pub const APPEND_POSITION: &str = "x-ms-blob-condition-appendpos";
 pub const CACHE_CONTROL: &str = "x-ms-blob-cache-control";
 pub const CONTENT_DISPOSITION: &str = "x-ms-blob-content-disposition";
+pub const RETRY_AFTER_MS: &str = "x-ms-retry-after-ms";
+pub const ACTIVITY_ID: &str = "x-ms-activity-id";
+pub const APPROXIMATE_MESSAGES_COUNT: &str = "x-ms-approximate-messages-count";
+pub const POP_RECEIPT: &str = "x-ms-popreceipt";
+pub const TIME_NEXT_VISIBLE: &str = "x-ms-time-next-visible";
+pub const SHARE_QUOTA: &str = "x-ms-share-quota";
+pub const SNAPSHOT: &str = "x-ms-snapshot";
+pub const FILE_TYPE: &str = "x-ms-type";
+pub const FILE_WRITE: &str = "x-ms-write";
+pub const FILE_CONTENT_LENGTH: &str = "x-ms-content-length";
+pub const FILE_CONTENT_TYPE: &str = "x-ms-content-type";
+pub const FILE_CONTENT_ENCODING: &str = "x-ms-content-encoding";
+pub const FILE_CONTENT_LANGUAGE: &str = "x-ms-content-language";
+pub const FILE_CACHE_CONTROL: &str = "x-ms-cache-control";
+pub const FILE_CONTENT_DISPOSITION: &str = "x-ms-content-disposition";
+pub const FILE_CONTENT_MD5: &str = "x-ms-content-md5";
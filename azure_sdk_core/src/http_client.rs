@@ -0,0 +1,31 @@
+use crate::errors::AzureError;
+use hyper::client::HttpConnector;
+use hyper::{Body, Request, Response};
+use hyper_rustls::HttpsConnector;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// The pending response of a request sent through an [`HttpClient`](trait.HttpClient.html).
+pub type ResponseFuture = Pin<Box<dyn Future<Output = Result<Response<Body>, AzureError>> + Send>>;
+
+/// Transport used by the service clients to send their (already signed) requests.
+///
+/// The SDK uses a `hyper` client with a `rustls` connector by default. Implement this
+/// trait to route the requests through a proxy aware client, to share a connection pool
+/// between different service clients or to answer them in memory in tests.
+pub trait HttpClient: Send + Sync + std::fmt::Debug {
+    fn request(&self, request: Request<Body>) -> ResponseFuture;
+}
+
+impl HttpClient for hyper::Client<HttpsConnector<HttpConnector>> {
+    fn request(&self, request: Request<Body>) -> ResponseFuture {
+        let response = hyper::Client::request(self, request);
+        Box::pin(async move { Ok(response.await?) })
+    }
+}
+
+/// Returns the default transport: a `hyper` client using `rustls` for TLS.
+pub fn default_http_client() -> Arc<dyn HttpClient> {
+    Arc::new(hyper::Client::builder().build::<_, Body>(HttpsConnector::new()))
+}
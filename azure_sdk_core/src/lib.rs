@@ -1,4 +1,4 @@
-#![recursion_limit = "128"]
+#![recursion_limit = "256"]
 #![warn(rust_2018_idioms)]
 
 #[macro_use]
@@ -24,12 +24,24 @@ use self::modify_conditions::{IfMatchCondition, IfSinceCondition, SequenceNumber
 use std::fmt;
 use std::str::FromStr;
 pub mod headers;
+mod http_client;
+pub use self::http_client::{default_http_client, HttpClient, ResponseFuture};
 pub mod range;
+mod retry_policy;
+pub use self::retry_policy::{
+    retry_after_from_headers, RequestSigner, RetryHttpClient, RetryMode, RetryPolicy,
+};
+mod token_credential;
+pub use self::token_credential::{
+    AccessToken, CachedTokenCredential, TokenCredential, TokenFuture, TOKEN_REFRESH_MARGIN_SECONDS,
+};
 use self::headers::{
-    ACCOUNT_KIND, APPEND_POSITION, BLOB_ACCESS_TIER, BLOB_CONTENT_LENGTH, BLOB_SEQUENCE_NUMBER,
-    CACHE_CONTROL, CLIENT_REQUEST_ID, CONTENT_DISPOSITION, CONTENT_MD5, DELETE_SNAPSHOTS,
-    DELETE_TYPE_PERMANENT, LEASE_BREAK_PERIOD, LEASE_DURATION, LEASE_ID, LEASE_TIME,
-    PROPOSED_LEASE_ID, REQUEST_ID, REQUEST_SERVER_ENCRYPTED, SKU_NAME,
+    ACCOUNT_KIND, APPEND_POSITION, APPROXIMATE_MESSAGES_COUNT, BLOB_ACCESS_TIER,
+    BLOB_CONTENT_LENGTH, BLOB_SEQUENCE_NUMBER, CACHE_CONTROL, CLIENT_REQUEST_ID,
+    CONTENT_DISPOSITION, CONTENT_MD5, COPY_ID, COPY_SOURCE, DELETE_SNAPSHOTS,
+    DELETE_TYPE_PERMANENT, IF_TAGS, LEASE_BREAK_PERIOD, LEASE_DURATION, LEASE_ID, LEASE_TIME,
+    POP_RECEIPT, PROPOSED_LEASE_ID, REQUEST_ID, REQUEST_SERVER_ENCRYPTED, SHARE_QUOTA, SKU_NAME,
+    SNAPSHOT, SOURCE_CONTENT_MD5, SOURCE_RANGE, TAGS, TIME_NEXT_VISIBLE,
 };
 use hyper::header::{
     HeaderName, CONTENT_ENCODING, CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_TYPE, DATE, ETAG,
@@ -48,7 +60,7 @@ pub use self::stored_access_policy::{StoredAccessPolicy, StoredAccessPolicyList}
 pub mod prelude;
 use chrono::{DateTime, Utc};
 use http::status::StatusCode;
-use hyper::{Body, Client, Request};
+use hyper::{Body, Request};
 
 #[macro_export]
 macro_rules! response_from_headers {
@@ -95,6 +107,24 @@ impl NotAssigned for No {}
 
 create_enum!(DeleteSnapshotsMethod, (Include, "include"), (Only, "only"));
 
+create_enum!(
+    AccessTier,
+    (Hot, "Hot"),
+    (Cool, "Cool"),
+    (Archive, "Archive"),
+    (P4, "P4"),
+    (P6, "P6"),
+    (P10, "P10"),
+    (P15, "P15"),
+    (P20, "P20"),
+    (P30, "P30"),
+    (P40, "P40"),
+    (P50, "P50"),
+    (P60, "P60"),
+    (P70, "P70"),
+    (P80, "P80")
+);
+
 pub trait TimeoutSupport {
     type O;
     fn with_timeout(self, timeout: u64) -> Self::O;
@@ -174,6 +204,45 @@ pub trait MetadataOption<'a> {
     }
 }
 
+pub trait TagsSupport<'a> {
+    type O;
+    fn with_tags(self, tags: &'a HashMap<&'a str, &'a str>) -> Self::O;
+}
+
+pub trait TagsOption<'a> {
+    fn tags(&self) -> Option<&'a HashMap<&'a str, &'a str>>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(tags) = self.tags() {
+            let tags = url::form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(tags.iter())
+                .finish();
+            builder.header(TAGS, &tags as &str);
+        }
+    }
+}
+
+pub trait TagsRequired<'a> {
+    fn tags(&self) -> &'a HashMap<&'a str, &'a str>;
+}
+
+pub trait IfTagsSupport<'a> {
+    type O;
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O;
+}
+
+/// The condition is a tag filter expression such as `"status" = 'done'`:
+/// the request fails with 412 unless the blob tags satisfy it.
+pub trait IfTagsOption<'a> {
+    fn if_tags(&self) -> Option<&'a str>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(if_tags) = self.if_tags() {
+            builder.header(IF_TAGS, if_tags);
+        }
+    }
+}
+
 pub trait CacheControlSupport<'a> {
     type O;
     fn with_cache_control(self, cache_control: &'a str) -> Self::O;
@@ -234,21 +303,31 @@ pub trait ContentLanguageOption<'a> {
     }
 }
 
-pub trait AccessTierSupport<'a> {
+pub trait AccessTierSupport {
     type O;
-    fn with_access_tier(self, access_tier: &'a str) -> Self::O;
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O;
 }
 
-pub trait AccessTierOption<'a> {
-    fn access_tier(&self) -> Option<&'a str>;
+pub trait AccessTierOption {
+    fn access_tier(&self) -> Option<AccessTier>;
 
     fn add_header(&self, builder: &mut Builder) {
         if let Some(access_tier) = self.access_tier() {
-            builder.header(BLOB_ACCESS_TIER, access_tier);
+            let s: &'static str = access_tier.into();
+            builder.header(BLOB_ACCESS_TIER, s);
         }
     }
 }
 
+pub trait AccessTierRequired {
+    fn access_tier(&self) -> AccessTier;
+
+    fn add_header(&self, builder: &mut Builder) {
+        let s: &'static str = self.access_tier().into();
+        builder.header(BLOB_ACCESS_TIER, s);
+    }
+}
+
 pub trait DeleteSnapshotsMethodSupport {
     type O;
     fn with_delete_snapshots_method(
@@ -306,7 +385,7 @@ pub trait SnapshotOption {
 
     fn to_uri_parameter(&self) -> Option<String> {
         if let Some(ref nm) = self.snapshot() {
-            Some(format!("snapshot={}", nm.to_rfc2822()))
+            Some(format!("snapshot={}", snapshot_to_string(nm)))
         } else {
             None
         }
@@ -317,10 +396,38 @@ pub trait SnapshotRequired {
     fn snapshot(&self) -> DateTime<Utc>;
 
     fn to_uri_parameter(&self) -> String {
-        format!("snapshot={}", self.snapshot().to_rfc2822())
+        format!("snapshot={}", snapshot_to_string(&self.snapshot()))
+    }
+}
+
+pub trait PrevSnapshotSupport {
+    type O;
+    fn with_prev_snapshot(self, prev_snapshot: DateTime<Utc>) -> Self::O;
+}
+
+pub trait PrevSnapshotOption {
+    fn prev_snapshot(&self) -> Option<DateTime<Utc>>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        if let Some(ref nm) = self.prev_snapshot() {
+            Some(format!("prevsnapshot={}", snapshot_to_string(nm)))
+        } else {
+            None
+        }
     }
 }
 
+/// Formats a snapshot time the way the service returns it in
+/// `x-ms-snapshot`, with seven fractional digits. This is the only form
+/// accepted by the `snapshot` parameter.
+pub fn snapshot_to_string(snapshot: &DateTime<Utc>) -> String {
+    format!(
+        "{}.{:07}Z",
+        snapshot.format("%Y-%m-%dT%H:%M:%S"),
+        snapshot.timestamp_subsec_nanos() / 100
+    )
+}
+
 pub trait DelimiterSupport<'a> {
     type O;
     fn with_delimiter(self, delimiter: &'a str) -> Self::O;
@@ -448,9 +555,9 @@ pub trait IncludeListOptions:
         }
 
         if s.is_empty() {
-            Some(format!("include={}", s))
-        } else {
             None
+        } else {
+            Some(format!("include={}", s))
         }
     }
 }
@@ -536,6 +643,40 @@ pub trait IfMatchConditionOption<'a> {
     }
 }
 
+pub trait SourceIfSinceConditionSupport {
+    type O;
+    fn with_source_if_since_condition(self, source_if_since_condition: IfSinceCondition)
+        -> Self::O;
+}
+
+pub trait SourceIfSinceConditionOption {
+    fn source_if_since_condition(&self) -> Option<IfSinceCondition>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(source_if_since_condition) = self.source_if_since_condition() {
+            source_if_since_condition.add_source_header(builder);
+        }
+    }
+}
+
+pub trait SourceIfMatchConditionSupport<'a> {
+    type O;
+    fn with_source_if_match_condition(
+        self,
+        source_if_match_condition: IfMatchCondition<'a>,
+    ) -> Self::O;
+}
+
+pub trait SourceIfMatchConditionOption<'a> {
+    fn source_if_match_condition(&self) -> Option<IfMatchCondition<'a>>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(source_if_match_condition) = self.source_if_match_condition() {
+            source_if_match_condition.add_source_header(builder);
+        }
+    }
+}
+
 pub trait PageBlobLengthSupport {
     type O;
     fn with_content_length(self, content_length: u64) -> Self::O;
@@ -552,6 +693,16 @@ pub trait PageBlobLengthRequired {
     }
 }
 
+pub trait PageBlobLengthOption {
+    fn content_length(&self) -> Option<u64>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(content_length) = self.content_length() {
+            builder.header(BLOB_CONTENT_LENGTH, &content_length.to_string() as &str);
+        }
+    }
+}
+
 pub trait ContentLengthSupport {
     type O;
     fn with_content_length(self, content_length: u64) -> Self::O;
@@ -628,6 +779,22 @@ pub fn add_content_md5_header<'a>(content_md5: &'a [u8], builder: &mut Builder)
     builder.header(CONTENT_MD5, &s as &str);
 }
 
+pub trait SourceContentMD5Support<'a> {
+    type O;
+    fn with_source_content_md5(self, _: &'a [u8]) -> Self::O;
+}
+
+pub trait SourceContentMD5Option<'a> {
+    fn source_content_md5(&self) -> Option<&'a [u8]>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(source_content_md5) = self.source_content_md5() {
+            let s = encode(source_content_md5);
+            builder.header(SOURCE_CONTENT_MD5, &s as &str);
+        }
+    }
+}
+
 pub trait RangeSupport<'a> {
     type O;
     fn with_range(self, _: &'a range::Range) -> Self::O;
@@ -651,6 +818,21 @@ pub trait RangeRequired<'a> {
     }
 }
 
+pub trait SourceRangeSupport<'a> {
+    type O;
+    fn with_source_range(self, _: &'a range::Range) -> Self::O;
+}
+
+pub trait SourceRangeOption<'a> {
+    fn source_range(&self) -> Option<&'a range::Range>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(source_range) = self.source_range() {
+            builder.header(SOURCE_RANGE, &source_range.to_string() as &str);
+        }
+    }
+}
+
 pub trait BA512RangeSupport<'a> {
     type O;
     fn with_ba512_range(self, _: &'a ba512_range::BA512Range) -> Self::O;
@@ -757,6 +939,32 @@ pub trait BlobNameRequired<'a> {
     fn blob_name(&self) -> &'a str;
 }
 
+pub trait CopySourceSupport<'a> {
+    type O;
+    fn with_copy_source(self, copy_source: &'a str) -> Self::O;
+}
+
+pub trait CopySourceRequired<'a> {
+    fn copy_source(&self) -> &'a str;
+
+    fn add_header(&self, builder: &mut Builder) {
+        builder.header(COPY_SOURCE, self.copy_source());
+    }
+}
+
+pub trait CopyIdSupport<'a> {
+    type O;
+    fn with_copy_id(self, copy_id: &'a str) -> Self::O;
+}
+
+pub trait CopyIdRequired<'a> {
+    fn copy_id(&self) -> &'a str;
+
+    fn to_uri_parameter(&self) -> String {
+        format!("copyid={}", self.copy_id())
+    }
+}
+
 pub fn lease_id_from_headers(headers: &HeaderMap) -> Result<LeaseId, AzureError> {
     let lease_id = headers
         .get_as_str(LEASE_ID)
@@ -907,6 +1115,18 @@ pub fn sequence_number_from_headers(headers: &HeaderMap) -> Result<u64, AzureErr
     Ok(sequence_number)
 }
 
+pub fn blob_content_length_from_headers(headers: &HeaderMap) -> Result<u64, AzureError> {
+    let blob_content_length = headers
+        .get(BLOB_CONTENT_LENGTH)
+        .ok_or_else(|| AzureError::HeaderNotFound(BLOB_CONTENT_LENGTH.to_owned()))?
+        .to_str()?;
+
+    let blob_content_length = blob_content_length.parse::<u64>()?;
+
+    trace!("blob_content_length == {:?}", blob_content_length);
+    Ok(blob_content_length)
+}
+
 pub fn request_server_encrypted_from_headers(headers: &HeaderMap) -> Result<bool, AzureError> {
     let request_server_encrypted = headers
         .get(REQUEST_SERVER_ENCRYPTED)
@@ -920,7 +1140,7 @@ pub fn request_server_encrypted_from_headers(headers: &HeaderMap) -> Result<bool
 }
 
 pub async fn perform_http_request(
-    client: &Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>,
+    client: &dyn HttpClient,
     req: Request<Body>,
     expected_status: StatusCode,
 ) -> Result<String, AzureError> {
@@ -928,3 +1148,156 @@ pub async fn perform_http_request(
     let res = client.request(req).await?;
     check_status_extract_body_2(res, expected_status).await
 }
+
+pub fn approximate_messages_count_from_headers(headers: &HeaderMap) -> Result<u64, AzureError> {
+    let approximate_messages_count = headers
+        .get(APPROXIMATE_MESSAGES_COUNT)
+        .ok_or_else(|| AzureError::HeaderNotFound(APPROXIMATE_MESSAGES_COUNT.to_owned()))?
+        .to_str()?;
+
+    let approximate_messages_count = approximate_messages_count.parse::<u64>()?;
+
+    trace!(
+        "approximate_messages_count == {:?}",
+        approximate_messages_count
+    );
+    Ok(approximate_messages_count)
+}
+
+pub fn pop_receipt_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    let pop_receipt = headers
+        .get(POP_RECEIPT)
+        .ok_or_else(|| AzureError::HeaderNotFound(POP_RECEIPT.to_owned()))?
+        .to_str()?;
+    trace!("pop_receipt == {:?}", pop_receipt);
+    Ok(pop_receipt.to_owned())
+}
+
+pub fn time_next_visible_from_headers(headers: &HeaderMap) -> Result<DateTime<Utc>, AzureError> {
+    let time_next_visible = headers
+        .get(TIME_NEXT_VISIBLE)
+        .ok_or_else(|| AzureError::HeaderNotFound(TIME_NEXT_VISIBLE.to_owned()))?
+        .to_str()?;
+    let time_next_visible = DateTime::parse_from_rfc2822(time_next_visible)?;
+    let time_next_visible = DateTime::from_utc(time_next_visible.naive_utc(), Utc);
+
+    trace!("time_next_visible == {:?}", time_next_visible);
+    Ok(time_next_visible)
+}
+
+pub fn share_quota_from_headers(headers: &HeaderMap) -> Result<u64, AzureError> {
+    let share_quota = headers
+        .get(SHARE_QUOTA)
+        .ok_or_else(|| AzureError::HeaderNotFound(SHARE_QUOTA.to_owned()))?
+        .to_str()?;
+
+    let share_quota = share_quota.parse::<u64>()?;
+
+    trace!("share_quota == {:?}", share_quota);
+    Ok(share_quota)
+}
+
+pub fn snapshot_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    let snapshot = headers
+        .get(SNAPSHOT)
+        .ok_or_else(|| AzureError::HeaderNotFound(SNAPSHOT.to_owned()))?
+        .to_str()?;
+    trace!("snapshot == {:?}", snapshot);
+    Ok(snapshot.to_owned())
+}
+
+pub fn snapshot_time_from_headers(headers: &HeaderMap) -> Result<DateTime<Utc>, AzureError> {
+    let snapshot = snapshot_from_headers(headers)?;
+    let snapshot = DateTime::parse_from_rfc3339(&snapshot)?;
+    Ok(DateTime::from_utc(snapshot.naive_utc(), Utc))
+}
+
+pub fn copy_id_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    let copy_id = headers
+        .get(COPY_ID)
+        .ok_or_else(|| AzureError::HeaderNotFound(COPY_ID.to_owned()))?
+        .to_str()?;
+    trace!("copy_id == {:?}", copy_id);
+    Ok(copy_id.to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn snapshot_round_trip() {
+        let snapshot = "2011-03-09T01:42:34.9360000Z";
+
+        let mut headers = HeaderMap::new();
+        headers.insert(SNAPSHOT, snapshot.parse().unwrap());
+        let snapshot_time = snapshot_time_from_headers(&headers).unwrap();
+
+        assert_eq!(snapshot_time.timestamp_subsec_millis(), 936);
+        assert_eq!(snapshot_to_string(&snapshot_time), snapshot);
+    }
+
+    struct IncludeList {
+        snapshots: bool,
+        deleted: bool,
+    }
+
+    impl IncludeSnapshotsOption for IncludeList {
+        fn include_snapshots(&self) -> bool {
+            self.snapshots
+        }
+    }
+
+    impl IncludeMetadataOption for IncludeList {
+        fn include_metadata(&self) -> bool {
+            false
+        }
+    }
+
+    impl IncludeUncommittedBlobsOption for IncludeList {
+        fn include_uncommitted_blobs(&self) -> bool {
+            false
+        }
+    }
+
+    impl IncludeCopyOption for IncludeList {
+        fn include_copy(&self) -> bool {
+            false
+        }
+    }
+
+    impl IncludeDeletedOption for IncludeList {
+        fn include_deleted(&self) -> bool {
+            self.deleted
+        }
+    }
+
+    impl IncludeListOptions for IncludeList {}
+
+    #[test]
+    fn include_list_uri_parameter() {
+        let none = IncludeList {
+            snapshots: false,
+            deleted: false,
+        };
+        assert_eq!(IncludeListOptions::to_uri_parameter(&none), None);
+
+        let deleted = IncludeList {
+            snapshots: false,
+            deleted: true,
+        };
+        assert_eq!(
+            IncludeListOptions::to_uri_parameter(&deleted),
+            Some("include=deleted".to_owned())
+        );
+
+        let both = IncludeList {
+            snapshots: true,
+            deleted: true,
+        };
+        assert_eq!(
+            IncludeListOptions::to_uri_parameter(&both),
+            Some("include=snapshots,deleted".to_owned())
+        );
+    }
+}
@@ -2,19 +2,22 @@ pub use crate::ba512_range::BA512Range;
 pub use crate::modify_conditions::SequenceNumberCondition;
 pub use crate::range::Range;
 pub use crate::{
-    AccessTierOption, AccessTierSupport, AppendPositionOption, AppendPositionSupport, BA512RangeOption, BA512RangeRequired,
-    BA512RangeSupport, BlobNameRequired, BlobNameSupport, BlockIdRequired, BlockIdSupport, BodyRequired, BodySupport, CacheControlOption,
-    CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
+    AccessTier, AccessTierOption, AccessTierRequired, AccessTierSupport, AppendPositionOption, AppendPositionSupport, BA512RangeOption,
+    BA512RangeRequired, BA512RangeSupport, BlobNameRequired, BlobNameSupport, BlockIdRequired, BlockIdSupport, BodyRequired, BodySupport,
+    CacheControlOption, CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
     ContentDispositionOption, ContentDispositionSupport, ContentEncodingOption, ContentEncodingSupport, ContentLanguageOption,
     ContentLanguageSupport, ContentLengthOption, ContentLengthRequired, ContentLengthSupport, ContentMD5Option, ContentMD5Support,
-    ContentTypeOption, ContentTypeSupport, DeleteSnapshotsMethod, DeleteSnapshotsMethodSupport, DelimiterOption, DelimiterSupport,
-    IfMatchConditionOption, IfMatchConditionSupport, IfSinceConditionOption, IfSinceConditionSupport, IncludeCopyOption,
-    IncludeCopySupport, IncludeDeletedOption, IncludeDeletedSupport, IncludeListOptions, IncludeMetadataOption, IncludeMetadataSupport,
-    IncludeSnapshotsOption, IncludeSnapshotsSupport, IncludeUncommittedBlobsOption, IncludeUncommittedBlobsSupport, LeaseBreakPeriodOption,
+    ContentTypeOption, ContentTypeSupport, CopyIdRequired, CopyIdSupport, CopySourceRequired, CopySourceSupport, DeleteSnapshotsMethod,
+    DeleteSnapshotsMethodSupport, DelimiterOption, DelimiterSupport, IfMatchConditionOption, IfMatchConditionSupport,
+    IfSinceConditionOption, IfSinceConditionSupport, IfTagsOption, IfTagsSupport, IncludeCopyOption, IncludeCopySupport,
+    IncludeDeletedOption, IncludeDeletedSupport, IncludeListOptions, IncludeMetadataOption, IncludeMetadataSupport, IncludeSnapshotsOption,
+    IncludeSnapshotsSupport, IncludeUncommittedBlobsOption, IncludeUncommittedBlobsSupport, LeaseBreakPeriodOption,
     LeaseBreakPeriodRequired, LeaseBreakPeriodSupport, LeaseDurationRequired, LeaseDurationSupport, LeaseIdOption, LeaseIdRequired,
     LeaseIdSupport, MaxResultsOption, MaxResultsSupport, MetadataOption, MetadataSupport, NextMarkerOption, NextMarkerSupport,
-    PageBlobLengthRequired, PageBlobLengthSupport, PrefixOption, PrefixSupport, ProposedLeaseIdOption, ProposedLeaseIdRequired,
-    ProposedLeaseIdSupport, RangeOption, RangeSupport, SequenceNumberConditionOption, SequenceNumberConditionSupport, SequenceNumberOption,
-    SequenceNumberSupport, SnapshotOption, SnapshotRequired, SnapshotSupport, StoredAccessPolicy, StoredAccessPolicyList, TimeoutOption,
-    TimeoutSupport,
+    PageBlobLengthOption, PageBlobLengthRequired, PageBlobLengthSupport, PrefixOption, PrefixSupport, PrevSnapshotOption,
+    PrevSnapshotSupport, ProposedLeaseIdOption, ProposedLeaseIdRequired, ProposedLeaseIdSupport, RangeOption, RangeSupport,
+    SequenceNumberConditionOption, SequenceNumberConditionSupport, SequenceNumberOption, SequenceNumberSupport, SnapshotOption,
+    SnapshotRequired, SnapshotSupport, SourceContentMD5Option, SourceContentMD5Support, SourceIfMatchConditionOption,
+    SourceIfMatchConditionSupport, SourceIfSinceConditionOption, SourceIfSinceConditionSupport, SourceRangeOption, SourceRangeSupport,
+    StoredAccessPolicy, StoredAccessPolicyList, TagsOption, TagsRequired, TagsSupport, TimeoutOption, TimeoutSupport,
 };
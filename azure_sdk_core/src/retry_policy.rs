@@ -0,0 +1,450 @@
+use crate::errors::AzureError;
+use crate::headers::RETRY_AFTER_MS;
+use crate::http_client::{HttpClient, ResponseFuture};
+use crate::util::HeaderMapExt;
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use http::request::Parts;
+use http::HeaderMap;
+use hyper::header::RETRY_AFTER;
+use hyper::{Body, Method, Request, StatusCode};
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RetryMode {
+    None,
+    Fixed,
+    Exponential,
+}
+
+/// Describes if, when and how many times a failed request is sent again.
+///
+/// Requests are retried when the service answers with a transient status code (408, 429,
+/// 500, 502, 503 and 504, plus 449 for Cosmos) or when the request could not be sent at all.
+/// Non idempotent requests (`POST` and `PATCH`) are retried only when the service
+/// explicitly throttled them (429 and 503), since in that case the request was not processed.
+/// The `Retry-After` and `x-ms-retry-after-ms` response headers, when present, take
+/// precedence over the computed delay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    mode: RetryMode,
+    max_attempts: u32,
+    delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Every request is sent exactly once.
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            mode: RetryMode::None,
+            max_attempts: 1,
+            delay: Duration::from_secs(0),
+            max_delay: Duration::from_secs(0),
+            jitter: false,
+        }
+    }
+
+    /// Waits `delay` between consecutive attempts.
+    pub fn fixed(delay: Duration, max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            mode: RetryMode::Fixed,
+            max_attempts,
+            delay,
+            max_delay: delay,
+            jitter: false,
+        }
+    }
+
+    /// Doubles the wait between consecutive attempts, starting from `initial_delay`
+    /// and never waiting more than `max_delay`.
+    pub fn exponential(
+        initial_delay: Duration,
+        max_delay: Duration,
+        max_attempts: u32,
+    ) -> RetryPolicy {
+        RetryPolicy {
+            mode: RetryMode::Exponential,
+            max_attempts,
+            delay: initial_delay,
+            max_delay,
+            jitter: true,
+        }
+    }
+
+    /// Randomizes each computed delay by +/- 20% to avoid retrying in lockstep
+    /// with other clients, still never waiting more than the maximum delay.
+    /// Delays requested by the service are never randomized.
+    pub fn with_jitter(self, jitter: bool) -> RetryPolicy {
+        RetryPolicy { jitter, ..self }
+    }
+
+    pub fn mode(&self) -> RetryMode {
+        self.mode
+    }
+
+    /// The total number of attempts, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        match self.mode {
+            RetryMode::None => 1,
+            _ => self.max_attempts.max(1),
+        }
+    }
+
+    /// The delay to wait before sending attempt `attempt + 1` (`attempt` starts from 1),
+    /// without taking into account the response headers. Attempt 0 is treated as attempt 1.
+    pub fn backoff(&self, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts() {
+            return None;
+        }
+
+        let delay = match self.mode {
+            RetryMode::None => return None,
+            RetryMode::Fixed => self.delay,
+            RetryMode::Exponential => {
+                let factor = 2u32
+                    .checked_pow(attempt.saturating_sub(1))
+                    .unwrap_or(u32::MAX);
+                self.delay
+                    .checked_mul(factor)
+                    .unwrap_or(self.max_delay)
+                    .min(self.max_delay)
+            }
+        };
+
+        if self.jitter {
+            let delay = delay.mul_f64(rand::thread_rng().gen_range(0.8, 1.2));
+            Some(delay.min(self.max_delay))
+        } else {
+            Some(delay)
+        }
+    }
+
+    /// The delay to wait before sending again a request that received `status`
+    /// and `headers` at attempt `attempt`. `None` means the response must be
+    /// returned to the caller as is.
+    pub fn delay_for_response(
+        &self,
+        attempt: u32,
+        method: &Method,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if !is_retriable(method, status) {
+            return None;
+        }
+        let backoff = self.backoff(attempt)?;
+        Some(retry_after_from_headers(headers).unwrap_or(backoff))
+    }
+
+    /// The delay to wait before sending again a request that could not be sent
+    /// at attempt `attempt`.
+    pub fn delay_for_error(&self, attempt: u32, method: &Method) -> Option<Duration> {
+        if is_idempotent(method) {
+            self.backoff(attempt)
+        } else {
+            None
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    *method != Method::POST && *method != Method::PATCH
+}
+
+fn is_retriable(method: &Method, status: StatusCode) -> bool {
+    match status.as_u16() {
+        429 | 503 => true,
+        408 | 449 | 500 | 502 | 504 => is_idempotent(method),
+        _ => false,
+    }
+}
+
+/// Extracts the wait requested by the service, either from the Cosmos specific
+/// `x-ms-retry-after-ms` header or from the standard `Retry-After` header
+/// (expressed in seconds or as an HTTP date).
+pub fn retry_after_from_headers(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(ms) = headers.get_as_u64(RETRY_AFTER_MS) {
+        return Some(Duration::from_millis(ms));
+    }
+
+    let retry_after = headers.get_as_str(RETRY_AFTER)?;
+    if let Ok(seconds) = retry_after.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(retry_after).ok()?;
+    let wait = date.with_timezone(&Utc) - Utc::now();
+    Some(wait.to_std().unwrap_or_else(|_| Duration::from_secs(0)))
+}
+
+/// Signs a request again before it is retried, so that the retry does not carry
+/// the date and the signature of the first attempt.
+pub trait RequestSigner: Send + Sync + std::fmt::Debug {
+    fn sign(&self, request: &mut Request<Body>) -> Result<(), AzureError>;
+}
+
+/// An [`HttpClient`](trait.HttpClient.html) that sends again the failed requests
+/// according to a [`RetryPolicy`](struct.RetryPolicy.html).
+///
+/// The request body is buffered so it can be sent more than once. Without a
+/// [`RequestSigner`](trait.RequestSigner.html) every attempt carries the date and
+/// the signature of the first one, and Azure rejects signed requests older than
+/// 15 minutes: only requests whose signature does not depend on the time they are
+/// sent (for example the ones authorized by a SAS token) can then be retried safely
+/// for longer.
+#[derive(Debug, Clone)]
+pub struct RetryHttpClient {
+    http_client: Arc<dyn HttpClient>,
+    retry_policy: RetryPolicy,
+    signer: Option<Arc<dyn RequestSigner>>,
+}
+
+impl RetryHttpClient {
+    pub fn new(http_client: Arc<dyn HttpClient>, retry_policy: RetryPolicy) -> RetryHttpClient {
+        RetryHttpClient {
+            http_client,
+            retry_policy,
+            signer: None,
+        }
+    }
+
+    /// Signs each retry again with `signer`.
+    pub fn with_signer(self, signer: Arc<dyn RequestSigner>) -> RetryHttpClient {
+        RetryHttpClient {
+            signer: Some(signer),
+            ..self
+        }
+    }
+}
+
+// Rebuilds the request for attempt `attempt`, signing it again if it is a retry.
+fn prepare_attempt(
+    parts: &Parts,
+    body: &[u8],
+    attempt: u32,
+    signer: Option<&dyn RequestSigner>,
+) -> Result<Request<Body>, AzureError> {
+    let mut request = Request::builder()
+        .method(parts.method.clone())
+        .uri(parts.uri.clone())
+        .version(parts.version)
+        .body(Body::from(body.to_vec()))?;
+    *request.headers_mut() = parts.headers.clone();
+
+    if let (true, Some(signer)) = (attempt > 1, signer) {
+        signer.sign(&mut request)?;
+    }
+    Ok(request)
+}
+
+impl HttpClient for RetryHttpClient {
+    fn request(&self, request: Request<Body>) -> ResponseFuture {
+        if self.retry_policy.max_attempts() <= 1 {
+            return self.http_client.request(request);
+        }
+
+        let http_client = self.http_client.clone();
+        let retry_policy = self.retry_policy.clone();
+        let signer = self.signer.clone();
+
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = body.try_concat().await?.to_vec();
+
+            let mut attempt = 1;
+            loop {
+                let request = prepare_attempt(&parts, &body, attempt, signer.as_deref())?;
+
+                let result = http_client.request(request).await;
+                let delay = match result {
+                    Ok(ref response) => retry_policy.delay_for_response(
+                        attempt,
+                        &parts.method,
+                        response.status(),
+                        response.headers(),
+                    ),
+                    Err(ref error) => {
+                        debug!("attempt {} failed: {}", attempt, error);
+                        retry_policy.delay_for_error(attempt, &parts.method)
+                    }
+                };
+
+                match delay {
+                    Some(delay) => {
+                        debug!(
+                            "retrying {} {} in {:?} (attempt {} of {})",
+                            parts.method,
+                            parts.uri,
+                            delay,
+                            attempt + 1,
+                            retry_policy.max_attempts()
+                        );
+                        tokio::timer::delay_for(delay).await;
+                        attempt += 1;
+                    }
+                    None => return result,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use http::header::HeaderValue;
+
+    #[test]
+    fn none_never_retries() {
+        let policy = RetryPolicy::none();
+        assert_eq!(policy.max_attempts(), 1);
+        assert_eq!(policy.backoff(1), None);
+        assert_eq!(
+            policy.delay_for_response(
+                1,
+                &Method::GET,
+                StatusCode::SERVICE_UNAVAILABLE,
+                &HeaderMap::new()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn fixed_backoff() {
+        let policy = RetryPolicy::fixed(Duration::from_millis(500), 3);
+        assert_eq!(policy.backoff(1), Some(Duration::from_millis(500)));
+        assert_eq!(policy.backoff(2), Some(Duration::from_millis(500)));
+        assert_eq!(policy.backoff(3), None);
+    }
+
+    #[test]
+    fn exponential_backoff_is_capped() {
+        let policy =
+            RetryPolicy::exponential(Duration::from_millis(100), Duration::from_millis(350), 10)
+                .with_jitter(false);
+        assert_eq!(policy.backoff(1), Some(Duration::from_millis(100)));
+        assert_eq!(policy.backoff(2), Some(Duration::from_millis(200)));
+        assert_eq!(policy.backoff(3), Some(Duration::from_millis(350)));
+        assert_eq!(policy.backoff(9), Some(Duration::from_millis(350)));
+        assert_eq!(policy.backoff(10), None);
+    }
+
+    #[test]
+    fn exponential_backoff_jitter() {
+        let policy =
+            RetryPolicy::exponential(Duration::from_millis(1000), Duration::from_secs(60), 5);
+        for _ in 0..100 {
+            let delay = policy.backoff(1).unwrap();
+            assert!(delay >= Duration::from_millis(800));
+            assert!(delay <= Duration::from_millis(1200));
+        }
+
+        // the jitter never exceeds the maximum delay
+        let policy =
+            RetryPolicy::exponential(Duration::from_millis(1000), Duration::from_millis(1000), 5);
+        for _ in 0..100 {
+            assert!(policy.backoff(3).unwrap() <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn attempt_zero_is_the_first_attempt() {
+        let policy =
+            RetryPolicy::exponential(Duration::from_millis(100), Duration::from_secs(1), 3)
+                .with_jitter(false);
+        assert_eq!(policy.backoff(0), Some(Duration::from_millis(100)));
+    }
+
+    #[derive(Debug)]
+    struct DateSigner;
+
+    impl RequestSigner for DateSigner {
+        fn sign(&self, request: &mut Request<Body>) -> Result<(), AzureError> {
+            request
+                .headers_mut()
+                .insert("x-ms-date", HeaderValue::from_static("now"));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn retries_are_signed_again() {
+        let mut request = Request::builder()
+            .method(Method::PUT)
+            .uri("https://account.blob.core.windows.net/container")
+            .header("x-ms-date", "then")
+            .body(())
+            .unwrap();
+        request
+            .headers_mut()
+            .insert("x-ms-meta-a", HeaderValue::from_static("b"));
+        let (parts, _) = request.into_parts();
+
+        let first = prepare_attempt(&parts, b"body", 1, Some(&DateSigner)).unwrap();
+        assert_eq!(first.headers()["x-ms-date"], "then");
+        assert_eq!(first.method(), Method::PUT);
+
+        let retry = prepare_attempt(&parts, b"body", 2, Some(&DateSigner)).unwrap();
+        assert_eq!(retry.headers()["x-ms-date"], "now");
+        assert_eq!(retry.headers()["x-ms-meta-a"], "b");
+
+        let retry = prepare_attempt(&parts, b"body", 2, None).unwrap();
+        assert_eq!(retry.headers()["x-ms-date"], "then");
+    }
+
+    #[test]
+    fn retriable_statuses() {
+        let policy = RetryPolicy::fixed(Duration::from_millis(10), 2);
+        let h = HeaderMap::new();
+
+        assert!(policy
+            .delay_for_response(1, &Method::PUT, StatusCode::INTERNAL_SERVER_ERROR, &h)
+            .is_some());
+        assert!(policy
+            .delay_for_response(1, &Method::POST, StatusCode::INTERNAL_SERVER_ERROR, &h)
+            .is_none());
+        assert!(policy
+            .delay_for_response(1, &Method::POST, StatusCode::TOO_MANY_REQUESTS, &h)
+            .is_some());
+        assert!(policy
+            .delay_for_response(1, &Method::GET, StatusCode::NOT_FOUND, &h)
+            .is_none());
+        assert!(policy.delay_for_error(1, &Method::DELETE).is_some());
+        assert!(policy.delay_for_error(1, &Method::POST).is_none());
+    }
+
+    #[test]
+    fn retry_after_headers_take_precedence() {
+        let policy = RetryPolicy::fixed(Duration::from_millis(10), 2);
+
+        let mut h = HeaderMap::new();
+        h.insert(RETRY_AFTER, HeaderValue::from_static("3"));
+        assert_eq!(
+            policy.delay_for_response(1, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &h),
+            Some(Duration::from_secs(3))
+        );
+
+        h.insert(RETRY_AFTER_MS, HeaderValue::from_static("1500"));
+        assert_eq!(
+            policy.delay_for_response(1, &Method::POST, StatusCode::TOO_MANY_REQUESTS, &h),
+            Some(Duration::from_millis(1500))
+        );
+
+        // the service cannot extend the number of attempts
+        assert_eq!(
+            policy.delay_for_response(2, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &h),
+            None
+        );
+    }
+}
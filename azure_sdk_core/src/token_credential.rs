@@ -0,0 +1,152 @@
+use crate::errors::AzureError;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// An OAuth2 access token, along with the time it expires.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessToken {
+    pub token: String,
+    pub expires_on: DateTime<Utc>,
+}
+
+impl AccessToken {
+    pub fn new<T: Into<String>>(token: T, expires_on: DateTime<Utc>) -> AccessToken {
+        AccessToken {
+            token: token.into(),
+            expires_on,
+        }
+    }
+
+    /// Returns `true` if the token is expired or expires within `margin` from now.
+    pub fn is_expiring(&self, margin: Duration) -> bool {
+        self.expires_on - margin <= Utc::now()
+    }
+}
+
+/// The pending token of a [`TokenCredential`](trait.TokenCredential.html).
+pub type TokenFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<AccessToken, AzureError>> + Send + 'a>>;
+
+/// Source of the OAuth2 tokens used to authorize requests with an
+/// `Authorization: Bearer` header instead of the account key.
+///
+/// `azure_sdk_auth_aad` implements this trait for Azure Active Directory service principals.
+pub trait TokenCredential: Send + Sync + std::fmt::Debug {
+    /// Gets a token for `scope`, for example `https://storage.azure.com/.default`.
+    fn get_token<'a>(&'a self, scope: &'a str) -> TokenFuture<'a>;
+}
+
+/// Tokens are refreshed when they expire within this margin, so a request
+/// never reaches the service with a token expired in transit.
+pub const TOKEN_REFRESH_MARGIN_SECONDS: i64 = 300;
+
+/// A [`TokenCredential`](trait.TokenCredential.html) that caches the tokens of
+/// another one, asking it for a new token only when the cached one is about to expire.
+#[derive(Debug)]
+pub struct CachedTokenCredential {
+    credential: Arc<dyn TokenCredential>,
+    refresh_margin: Duration,
+    tokens: Mutex<HashMap<String, AccessToken>>,
+}
+
+impl CachedTokenCredential {
+    pub fn new(credential: Arc<dyn TokenCredential>) -> CachedTokenCredential {
+        CachedTokenCredential {
+            credential,
+            refresh_margin: Duration::seconds(TOKEN_REFRESH_MARGIN_SECONDS),
+            tokens: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_refresh_margin(self, refresh_margin: Duration) -> CachedTokenCredential {
+        CachedTokenCredential {
+            refresh_margin,
+            ..self
+        }
+    }
+
+    fn cached_token(&self, scope: &str) -> Option<AccessToken> {
+        self.tokens
+            .lock()
+            .unwrap()
+            .get(scope)
+            .filter(|token| !token.is_expiring(self.refresh_margin))
+            .cloned()
+    }
+}
+
+impl TokenCredential for CachedTokenCredential {
+    fn get_token<'a>(&'a self, scope: &'a str) -> TokenFuture<'a> {
+        Box::pin(async move {
+            if let Some(token) = self.cached_token(scope) {
+                return Ok(token);
+            }
+
+            debug!("refreshing the token for scope {}", scope);
+            let token = self.credential.get_token(scope).await?;
+            self.tokens
+                .lock()
+                .unwrap()
+                .insert(scope.to_owned(), token.clone());
+            Ok(token)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct CountingCredential {
+        lifetime: Duration,
+        calls: AtomicUsize,
+    }
+
+    impl TokenCredential for CountingCredential {
+        fn get_token<'a>(&'a self, _scope: &'a str) -> TokenFuture<'a> {
+            let calls = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            let token = AccessToken::new(format!("token{}", calls), Utc::now() + self.lifetime);
+            Box::pin(async move { Ok(token) })
+        }
+    }
+
+    #[test]
+    fn is_expiring() {
+        let token = AccessToken::new("abc", Utc::now() + Duration::minutes(10));
+        assert!(!token.is_expiring(Duration::minutes(5)));
+        assert!(token.is_expiring(Duration::minutes(15)));
+
+        let token = AccessToken::new("abc", Utc::now() - Duration::seconds(1));
+        assert!(token.is_expiring(Duration::zero()));
+    }
+
+    #[test]
+    fn cached_token_is_refreshed_when_expiring() {
+        let inner = Arc::new(CountingCredential {
+            lifetime: Duration::hours(1),
+            calls: AtomicUsize::new(0),
+        });
+        let credential = CachedTokenCredential::new(inner.clone());
+        let scope = "https://storage.azure.com/.default";
+
+        assert_eq!(credential.cached_token(scope), None);
+        credential.tokens.lock().unwrap().insert(
+            scope.to_owned(),
+            AccessToken::new("old", Utc::now() + Duration::minutes(1)),
+        );
+        // expires within the refresh margin
+        assert_eq!(credential.cached_token(scope), None);
+
+        let credential = credential.with_refresh_margin(Duration::seconds(10));
+        assert_eq!(
+            credential.cached_token(scope).map(|token| token.token),
+            Some("old".to_owned())
+        );
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 0);
+    }
+}
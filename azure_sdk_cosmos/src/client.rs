@@ -9,8 +9,10 @@ use super::{
 use crate::create_collection_builder::CreateCollectionBuilder;
 use azure_sdk_core::No;
 use azure_sdk_core::{
+    default_http_client,
     errors::{check_status_extract_body, AzureError},
     util::RequestBuilderExt,
+    HttpClient, ResponseFuture, RetryHttpClient, RetryPolicy,
 };
 use base64;
 use chrono;
@@ -20,7 +22,6 @@ use hyper::{
     header::{self, HeaderValue},
     StatusCode,
 };
-use hyper_rustls::HttpsConnector;
 use ring::hmac;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json;
@@ -69,7 +70,8 @@ pub struct Client<CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    hyper_client: Arc<hyper::Client<HttpsConnector<hyper::client::HttpConnector>>>,
+    http_client: Arc<dyn HttpClient>,
+    retry_policy: RetryPolicy,
     auth_token: AuthorizationToken,
     cosmos_uri_builder: CUB,
 }
@@ -132,22 +134,22 @@ pub struct ClientBuilder {}
 impl ClientBuilder {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(auth_token: AuthorizationToken) -> Result<Client<DefaultCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
         let cosmos_uri_builder = DefaultCosmosUri::new(auth_token.account());
 
         Ok(Client {
-            hyper_client: Arc::new(client),
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
             auth_token,
             cosmos_uri_builder,
         })
     }
 
     pub fn new_china(auth_token: AuthorizationToken) -> Result<Client<ChinaCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
         let cosmos_uri_builder = ChinaCosmosUri::new(auth_token.account());
 
         Ok(Client {
-            hyper_client: Arc::new(client),
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
             auth_token,
             cosmos_uri_builder,
         })
@@ -157,18 +159,15 @@ impl ClientBuilder {
         auth_token: AuthorizationToken,
         uri: String,
     ) -> Result<Client<CustomCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
-
         Ok(Client {
-            hyper_client: Arc::new(client),
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
             auth_token,
             cosmos_uri_builder: CustomCosmosUri { uri },
         })
     }
 
     pub fn new_emulator(address: &str, port: u16) -> Result<Client<CustomCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
-
         //Account name: localhost:<port>
         //Account key: C2y6yDjf5/R+ob0N8A7Cgv30VRDJIWEHLM+4QDU5DE2nQ9nDuVTqobD4b8mGGyPMbIZnqyMsEcaGQy67XIw/Jw==
         let auth_token = AuthorizationToken::new(
@@ -177,7 +176,8 @@ impl ClientBuilder {
             "C2y6yDjf5/R+ob0N8A7Cgv30VRDJIWEHLM+4QDU5DE2nQ9nDuVTqobD4b8mGGyPMbIZnqyMsEcaGQy67XIw/Jw==",
         ).unwrap();
         Ok(Client {
-            hyper_client: Arc::new(client),
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
             auth_token,
             cosmos_uri_builder: CustomCosmosUri {
                 uri: format!("https://{}:{}", address, port),
@@ -194,7 +194,41 @@ where
         self.auth_token = at;
     }
 
-    fn list_databases_create_request(&self) -> Result<hyper::client::ResponseFuture, AzureError> {
+    /// Replaces the transport used to send the requests of this client.
+    pub fn with_http_client(self, http_client: Arc<dyn HttpClient>) -> Client<CUB> {
+        Client {
+            http_client,
+            retry_policy: self.retry_policy,
+            auth_token: self.auth_token,
+            cosmos_uri_builder: self.cosmos_uri_builder,
+        }
+    }
+
+    /// Sets the policy used to retry the requests of this client that fail
+    /// with a transient error (for example when the request rate is too large).
+    /// By default requests are not retried.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Client<CUB> {
+        Client {
+            http_client: self.http_client,
+            retry_policy,
+            auth_token: self.auth_token,
+            cosmos_uri_builder: self.cosmos_uri_builder,
+        }
+    }
+
+    #[inline]
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    fn http_client(&self) -> Arc<dyn HttpClient> {
+        Arc::new(RetryHttpClient::new(
+            self.http_client.clone(),
+            self.retry_policy.clone(),
+        ))
+    }
+
+    fn list_databases_create_request(&self) -> Result<ResponseFuture, AzureError> {
         // No specific headers are required, list databases only needs standard headers
         // which will be provied by perform_request. This is handled by passing an
         // empty closure.
@@ -204,7 +238,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     /// Returns database list associated to the account
@@ -223,7 +257,7 @@ where
     fn list_collections_create_request(
         &self,
         database_name: &str,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         // No specific headers are required, list collections only needs standard headers
         // which will be provied by perform_request. This is handled by passing an
         // empty closure.
@@ -237,7 +271,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub async fn list_collections(
@@ -256,7 +290,7 @@ where
     fn create_database_create_request(
         &self,
         database_name: &str,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         #[derive(Serialize, Debug)]
         pub struct CreateDatabaseRequest<'a> {
             pub id: &'a str,
@@ -270,7 +304,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub async fn create_database(&self, database_name: &str) -> Result<Database, AzureError> {
@@ -288,7 +322,7 @@ where
     fn get_database_create_request(
         &self,
         database_name: &str,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         // No specific headers are required, get database only needs standard headers
         // which will be provied by perform_request
         let request = self
@@ -301,7 +335,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub async fn get_database(&self, database_name: &str) -> Result<Database, AzureError> {
@@ -316,7 +350,7 @@ where
     fn delete_database_create_request(
         &self,
         database_name: &str,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         // No specific headers are required, delete database only needs standard headers
         // which will be provied by perform_request
         let request = self
@@ -329,7 +363,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub async fn delete_database(&self, database_name: &str) -> Result<(), AzureError> {
@@ -348,7 +382,7 @@ where
         &self,
         database_name: &str,
         collection_name: &str,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         // No specific headers are required, get database only needs standard headers
         // which will be provied by perform_request
         let request = self
@@ -361,7 +395,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub async fn get_collection(
@@ -386,7 +420,7 @@ where
         database_name: &str,
         offer: Offer,
         collection: &Collection,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         // Headers added as per
         // https://docs.microsoft.com/en-us/rest/api/documentdb/create-a-collection
         // Standard headers (auth and version) will be provied by perform_request
@@ -411,7 +445,7 @@ where
         let request = request.body(collection_serialized.into())?;
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub fn create_collection_builder(&self) -> CreateCollectionBuilder<CUB, No, No, No, No> {
@@ -443,7 +477,7 @@ where
         &self,
         database_name: &str,
         collection_name: &str,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         // No specific headers are required.
         // Standard headers (auth and version) will be provied by perform_request
         let request = self
@@ -456,7 +490,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub async fn delete_collection(
@@ -482,7 +516,7 @@ where
         &self,
         database_name: &str,
         collection: &str,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         // No specific headers are required.
         // Standard headers (auth and version) will be provied by perform_request
         let collection_serialized = serde_json::to_string(collection)?;
@@ -498,7 +532,7 @@ where
 
         trace!("request prepared");
 
-        Ok(self.hyper_client.request(request))
+        Ok(self.http_client().request(request))
     }
 
     pub async fn replace_collection(
@@ -553,7 +587,7 @@ where
         );
 
         let req = self.create_document_as_str_create_request(database, collection);
-        CreateDocumentRequest::new(self.hyper_client.clone(), req, Ok(document))
+        CreateDocumentRequest::new(self.http_client(), req, Ok(document))
     }
 
     pub fn create_document<T, S1, S2>(
@@ -577,7 +611,7 @@ where
             document_serialized
         );
         let req = self.create_document_as_str_create_request(db, coll);
-        CreateDocumentRequest::new(self.hyper_client.clone(), req, document_serialized)
+        CreateDocumentRequest::new(self.http_client(), req, document_serialized)
     }
 
     pub fn delete_document<D: AsRef<str>, C: AsRef<str>, Dc: AsRef<str>>(
@@ -601,7 +635,7 @@ where
         );
 
         let req = self.prepare_request(&uri, hyper::Method::DELETE, ResourceType::Documents);
-        DeleteDocumentRequest::new(self.hyper_client.clone(), req)
+        DeleteDocumentRequest::new(self.http_client(), req)
     }
 
     pub fn replace_document<D: AsRef<str>, C: AsRef<str>, T: Serialize + DeserializeOwned>(
@@ -626,7 +660,7 @@ where
             &document.document_attributes.rid().to_lowercase(),
         );
 
-        ReplaceDocumentRequest::new(self.hyper_client.clone(), req, document_serialized)
+        ReplaceDocumentRequest::new(self.http_client(), req, document_serialized)
     }
 
     pub fn list_documents<S1: AsRef<str>, S2: AsRef<str>>(
@@ -649,7 +683,7 @@ where
             ResourceType::Documents,
         );
 
-        ListDocumentsRequest::new(self.hyper_client.clone(), req)
+        ListDocumentsRequest::new(self.http_client(), req)
     }
 
     pub fn get_document<S1, S2, S3>(
@@ -673,7 +707,7 @@ where
             ResourceType::Documents,
         );
 
-        GetDocumentRequest::new(self.hyper_client.clone(), req)
+        GetDocumentRequest::new(self.http_client(), req)
     }
 
     pub fn query_documents<'b, S1: AsRef<str>, S2: AsRef<str>, Q: AsRef<Query<'b>>>(
@@ -691,7 +725,7 @@ where
             ResourceType::Documents,
         );
         let query_json = serde_json::to_string(query.as_ref());
-        QueryDocumentRequest::new(self.hyper_client.clone(), req, query_json)
+        QueryDocumentRequest::new(self.http_client(), req, query_json)
     }
 
     pub fn execute_stored_procedure<S1, S2, S3, I>(
@@ -718,7 +752,7 @@ where
             ResourceType::StoredProcedures,
         );
         let input_json = serde_json::to_string(&input);
-        ExecuteStoredProcedureRequest::new(self.hyper_client.clone(), req, input_json)
+        ExecuteStoredProcedureRequest::new(self.http_client(), req, input_json)
     }
 
     #[inline]
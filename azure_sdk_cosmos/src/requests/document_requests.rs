@@ -1,7 +1,7 @@
 use super::*;
 
 pub struct CreateDocumentRequest {
-    hyper_client: HyperClient,
+    http_client: HttpClientArc,
     request: RequestBuilder,
     payload: Result<String, serde_json::Error>,
 }
@@ -14,12 +14,12 @@ impl DocumentRequestExt for CreateDocumentRequest {
 
 impl CreateDocumentRequest {
     pub(crate) fn new(
-        hyper_client: HyperClient,
+        http_client: HttpClientArc,
         request: RequestBuilder,
         payload: Result<String, serde_json::Error>,
     ) -> CreateDocumentRequest {
         CreateDocumentRequest {
-            hyper_client,
+            http_client,
             request,
             payload,
         }
@@ -40,7 +40,7 @@ impl CreateDocumentRequest {
 
     pub async fn execute(self) -> Result<DocumentAttributes, AzureError> {
         trace!("get_document called(request == {:?}", self.request);
-        let hc = self.hyper_client;
+        let hc = self.http_client;
         let mut req = self.request;
         let payload = self.payload?;
         let r = req.body(payload.into())?;
@@ -50,7 +50,7 @@ impl CreateDocumentRequest {
 }
 
 pub struct GetDocumentRequest {
-    hyper_client: HyperClient,
+    http_client: HttpClientArc,
     request: RequestBuilder,
 }
 
@@ -61,9 +61,9 @@ impl DocumentRequestExt for GetDocumentRequest {
 }
 
 impl GetDocumentRequest {
-    pub(crate) fn new(hyper_client: HyperClient, request: RequestBuilder) -> GetDocumentRequest {
+    pub(crate) fn new(http_client: HttpClientArc, request: RequestBuilder) -> GetDocumentRequest {
         GetDocumentRequest {
-            hyper_client,
+            http_client,
             request,
         }
     }
@@ -83,7 +83,7 @@ impl GetDocumentRequest {
 
         let r = self.request.body(hyper::Body::empty())?;
         let (status, headers, body) =
-            extract_status_headers_and_body(self.hyper_client.request(r)).await?;
+            extract_status_headers_and_body(self.http_client.request(r)).await?;
         Self::extract_result(status, &headers, &body)
     }
 
@@ -115,18 +115,19 @@ impl GetDocumentRequest {
                 // handle 304 (Not modified) in a specific way but
                 // for now we do not.
                 let error_text = str::from_utf8(body)?;
-                Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(
+                Err(unexpected_status_error(
                     StatusCode::OK,
                     status,
+                    headers,
                     error_text,
-                )))
+                ))
             }
         }
     }
 }
 
 pub struct QueryDocumentRequest {
-    hyper_client: HyperClient,
+    http_client: HttpClientArc,
     request: RequestBuilder,
     payload: Result<String, serde_json::Error>,
 }
@@ -141,7 +142,7 @@ const QUERY_CONTENT_TYPE: &str = "application/query+json";
 
 impl QueryDocumentRequest {
     pub(crate) fn new(
-        hyper_client: HyperClient,
+        http_client: HttpClientArc,
         mut request: RequestBuilder,
         payload: Result<String, serde_json::Error>,
     ) -> QueryDocumentRequest {
@@ -152,7 +153,7 @@ impl QueryDocumentRequest {
                 HeaderValue::from_static(QUERY_CONTENT_TYPE),
             );
         QueryDocumentRequest {
-            hyper_client,
+            http_client,
             request,
             payload,
         }
@@ -193,7 +194,7 @@ impl QueryDocumentRequest {
         self,
     ) -> Result<QueryDocumentResponse<serde_json::Value>, AzureError> {
         trace!("query_document called(request == {:?}", self.request);
-        let hc = self.hyper_client;
+        let hc = self.http_client;
         let mut req = self.request;
         let payload = self.payload?;
         let r = req.body(payload.into())?;
@@ -288,14 +289,14 @@ impl QueryDocumentRequest {
 }
 
 pub struct ListDocumentsRequest {
-    hyper_client: HyperClient,
+    http_client: HttpClientArc,
     request: RequestBuilder,
 }
 
 impl ListDocumentsRequest {
-    pub(crate) fn new(hyper_client: HyperClient, request: RequestBuilder) -> ListDocumentsRequest {
+    pub(crate) fn new(http_client: HttpClientArc, request: RequestBuilder) -> ListDocumentsRequest {
         ListDocumentsRequest {
-            hyper_client,
+            http_client,
             request,
         }
     }
@@ -327,7 +328,7 @@ impl ListDocumentsRequest {
     ) -> Result<ListDocumentsResponse<T>, AzureError> {
         let r = self.request.body(hyper::Body::empty())?;
         let (headers, whole_body) =
-            check_status_extract_headers_and_body(self.hyper_client.request(r), StatusCode::OK)
+            check_status_extract_headers_and_body(self.http_client.request(r), StatusCode::OK)
                 .await?;
         Self::extract_result::<T>(&whole_body, &headers)
     }
@@ -387,7 +388,7 @@ impl ListDocumentsRequest {
 }
 
 pub struct ReplaceDocumentRequest<T> {
-    hyper_client: HyperClient,
+    http_client: HttpClientArc,
     request: RequestBuilder,
     payload: Result<String, serde_json::Error>,
     _t: PhantomData<T>,
@@ -401,12 +402,12 @@ impl<T> DocumentRequestExt for ReplaceDocumentRequest<T> {
 
 impl<T: DeserializeOwned> ReplaceDocumentRequest<T> {
     pub(crate) fn new(
-        hyper_client: HyperClient,
+        http_client: HttpClientArc,
         request: RequestBuilder,
         payload: Result<String, serde_json::Error>,
     ) -> ReplaceDocumentRequest<T> {
         ReplaceDocumentRequest {
-            hyper_client,
+            http_client,
             request,
             payload,
             _t: PhantomData,
@@ -428,7 +429,7 @@ impl<T: DeserializeOwned> ReplaceDocumentRequest<T> {
 
     pub async fn execute(self) -> Result<ReplaceDocumentResponse<T>, AzureError> {
         trace!("get_document called(request == {:?}", self.request);
-        let hc = self.hyper_client;
+        let hc = self.http_client;
         let mut req = self.request;
         let payload = self.payload?;
         let r = req.body(payload.into())?;
@@ -451,7 +452,7 @@ impl<T: DeserializeOwned> ReplaceDocumentRequest<T> {
 }
 
 pub struct DeleteDocumentRequest {
-    hyper_client: HyperClient,
+    http_client: HttpClientArc,
     request: RequestBuilder,
 }
 
@@ -462,9 +463,12 @@ impl DocumentRequestExt for DeleteDocumentRequest {
 }
 
 impl DeleteDocumentRequest {
-    pub(crate) fn new(hyper_client: HyperClient, request: RequestBuilder) -> DeleteDocumentRequest {
+    pub(crate) fn new(
+        http_client: HttpClientArc,
+        request: RequestBuilder,
+    ) -> DeleteDocumentRequest {
         DeleteDocumentRequest {
-            hyper_client,
+            http_client,
             request,
         }
     }
@@ -481,7 +485,7 @@ impl DeleteDocumentRequest {
         trace!("get_document called(request == {:?}", self.request);
 
         let r = self.request.body(hyper::Body::empty())?;
-        check_status_extract_body(self.hyper_client.request(r), StatusCode::NO_CONTENT).await?;
+        check_status_extract_body(self.http_client.request(r), StatusCode::NO_CONTENT).await?;
         Ok(())
     }
 }
@@ -9,10 +9,11 @@ use crate::{
 use azure_sdk_core::{
     errors::{
         check_status_extract_body, check_status_extract_headers_and_body,
-        extract_status_headers_and_body, AzureError, UnexpectedHTTPResult,
+        extract_status_headers_and_body, unexpected_status_error, AzureError,
     },
     incompletevector::ContinuationToken,
     util::RequestBuilderExt,
+    HttpClient,
 };
 use http::request::Builder as RequestBuilder;
 use hyper::{
@@ -20,13 +21,12 @@ use hyper::{
     header::{self, HeaderMap, HeaderValue},
     StatusCode,
 };
-use hyper_rustls::HttpsConnector;
 use serde::de::DeserializeOwned;
 use serde_json;
 use std::sync::Arc;
 use std::{marker::PhantomData, str};
 
-type HyperClient = Arc<hyper::Client<HttpsConnector<hyper::client::HttpConnector>>>;
+type HttpClientArc = Arc<dyn HttpClient>;
 
 macro_rules! request_bytes_ref {
     ($name:ident, $h:path) => {
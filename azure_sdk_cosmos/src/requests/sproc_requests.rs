@@ -1,7 +1,7 @@
 use super::*;
 
 pub struct ExecuteStoredProcedureRequest {
-    hyper_client: HyperClient,
+    http_client: HttpClientArc,
     request: RequestBuilder,
     payload: Result<String, serde_json::Error>,
 }
@@ -14,12 +14,12 @@ impl DocumentRequestExt for ExecuteStoredProcedureRequest {
 
 impl ExecuteStoredProcedureRequest {
     pub(crate) fn new(
-        hyper_client: HyperClient,
+        http_client: HttpClientArc,
         request: RequestBuilder,
         payload: Result<String, serde_json::Error>,
     ) -> ExecuteStoredProcedureRequest {
         ExecuteStoredProcedureRequest {
-            hyper_client,
+            http_client,
             request,
             payload,
         }
@@ -39,7 +39,7 @@ impl ExecuteStoredProcedureRequest {
             "execute_stored_procedure called(request == {:?}",
             self.request
         );
-        let hc = self.hyper_client;
+        let hc = self.http_client;
         let mut req = self.request;
         let payload = self.payload?;
         let r = req.body(payload.into())?;
@@ -1,18 +1,16 @@
 use crate::event_hub::send_event;
 use azure_sdk_core::errors::AzureError;
-use hyper;
-use hyper_rustls::HttpsConnector;
+use azure_sdk_core::{default_http_client, HttpClient};
 use ring::hmac::Key;
+use std::sync::Arc;
 use time::Duration;
 
-type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
-
 pub struct Client {
     namespace: String,
     event_hub: String,
     policy_name: String,
     signing_key: Key,
-    http_client: HttpClient,
+    http_client: Arc<dyn HttpClient>,
 }
 
 impl Client {
@@ -29,17 +27,24 @@ impl Client {
         K: AsRef<str>,
     {
         let signing_key = Key::new(ring::hmac::HMAC_SHA256, key.as_ref().as_bytes());
-        let http_client = hyper::Client::builder().build(HttpsConnector::new());
 
         Ok(Client {
             namespace: namespace.into(),
             event_hub: event_hub.into(),
             policy_name: policy_name.into(),
             signing_key,
-            http_client,
+            http_client: default_http_client(),
         })
     }
 
+    /// Replaces the transport used to send the events.
+    pub fn with_http_client(self, http_client: Arc<dyn HttpClient>) -> Client {
+        Client {
+            http_client,
+            ..self
+        }
+    }
+
     pub async fn send_event(
         &mut self,
         event_body: &str,
@@ -47,7 +52,7 @@ impl Client {
     ) -> Result<(), AzureError> {
         {
             send_event(
-                &self.http_client,
+                self.http_client.as_ref(),
                 &self.namespace,
                 &self.event_hub,
                 &self.policy_name,
@@ -1,6 +1,6 @@
 use azure_sdk_core::errors::{check_status_extract_body, AzureError};
+use azure_sdk_core::{HttpClient, ResponseFuture};
 use hyper::{self, header, StatusCode};
-use hyper_rustls::HttpsConnector;
 use ring::hmac;
 use std::ops::Add;
 use time::Duration;
@@ -9,18 +9,16 @@ use url::form_urlencoded;
 mod client;
 pub use self::client::Client;
 
-type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
-
 #[inline]
 fn send_event_prepare<B: Into<String>>(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     event_body: B,
     duration: Duration,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
         "https://{}.servicebus.windows.net/{}/messages",
@@ -41,7 +39,7 @@ fn send_event_prepare<B: Into<String>>(
 }
 
 async fn send_event(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -1,8 +1,98 @@
 pub mod requests;
 pub mod responses;
+mod service_properties;
+pub use self::service_properties::{
+    BlobServiceProperties, CorsRule, Logging, Metrics, RetentionPolicy, StaticWebsite,
+};
+mod service_stats;
+pub use self::service_stats::{GeoReplication, GeoReplicationStatus, ServiceStats};
+use azure_sdk_storage_core::client::Client;
+use chrono::{DateTime, SecondsFormat, Utc};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Account {
     pub sku_name: String,
     pub kind: String,
 }
+
+pub trait BlobServicePropertiesSupport<'a> {
+    type O;
+    fn with_blob_service_properties(self, _: &'a BlobServiceProperties) -> Self::O;
+}
+
+pub trait BlobServicePropertiesRequired<'a> {
+    fn blob_service_properties(&self) -> &'a BlobServiceProperties;
+}
+
+pub trait KeyStartSupport {
+    type O;
+    fn with_key_start(self, key_start: DateTime<Utc>) -> Self::O;
+}
+
+pub trait KeyStartOption {
+    fn key_start(&self) -> Option<DateTime<Utc>>;
+}
+
+pub trait KeyExpirySupport {
+    type O;
+    fn with_key_expiry(self, key_expiry: DateTime<Utc>) -> Self::O;
+}
+
+pub trait KeyExpiryRequired {
+    fn key_expiry(&self) -> DateTime<Utc>;
+}
+
+// A user delegation key carries the service version of the request that
+// created it, which cannot be newer than the version of the SAS it signs.
+pub(crate) const USER_DELEGATION_KEY_VERSION: &str = "2019-02-02";
+
+pub(crate) fn key_info_to_xml(start: &DateTime<Utc>, expiry: &DateTime<Utc>) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<KeyInfo>\n\t<Start>{}</Start>\n\t<Expiry>{}</Expiry>\n</KeyInfo>",
+        start.to_rfc3339_opts(SecondsFormat::Secs, true),
+        expiry.to_rfc3339_opts(SecondsFormat::Secs, true)
+    )
+}
+
+// The secondary endpoint of a read-access geo-redundant account is the
+// primary one with the "-secondary" suffix appended to the account name.
+pub(crate) fn secondary_blob_uri(client: &Client) -> String {
+    let primary = format!("://{}.", client.account());
+    let secondary = format!("://{}-secondary.", client.account());
+    client.blob_uri().replacen(&primary, &secondary, 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn key_info() {
+        let start = DateTime::parse_from_rfc3339("2020-03-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let expiry = DateTime::parse_from_rfc3339("2020-03-02T10:00:00.250Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            key_info_to_xml(&start, &expiry),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<KeyInfo>\n\t<Start>2020-03-01T10:00:00Z</Start>\n\
+             \t<Expiry>2020-03-02T10:00:00Z</Expiry>\n</KeyInfo>"
+        );
+    }
+
+    #[test]
+    fn secondary_blob_uri_appends_suffix_to_account() {
+        let client = Client::azure(
+            "mindrust",
+            "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+        )
+        .unwrap();
+
+        assert_eq!(
+            secondary_blob_uri(&client),
+            "https://mindrust-secondary.blob.core.windows.net"
+        );
+    }
+}
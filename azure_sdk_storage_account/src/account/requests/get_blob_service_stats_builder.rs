@@ -0,0 +1,105 @@
+use crate::account::responses::GetBlobServiceStatsResponse;
+use crate::account::secondary_blob_uri;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct GetBlobServiceStatsBuilder<'a> {
+    client: &'a Client,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetBlobServiceStatsBuilder<'a> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetBlobServiceStatsBuilder<'a> {
+        GetBlobServiceStatsBuilder {
+            client,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a> ClientRequired<'a> for GetBlobServiceStatsBuilder<'a> {
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> TimeoutOption for GetBlobServiceStatsBuilder<'a> {
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a> ClientRequestIdOption<'a> for GetBlobServiceStatsBuilder<'a> {
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a> TimeoutSupport for GetBlobServiceStatsBuilder<'a> {
+    type O = GetBlobServiceStatsBuilder<'a>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetBlobServiceStatsBuilder {
+            client: self.client,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> ClientRequestIdSupport<'a> for GetBlobServiceStatsBuilder<'a> {
+    type O = GetBlobServiceStatsBuilder<'a>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetBlobServiceStatsBuilder {
+            client: self.client,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a> GetBlobServiceStatsBuilder<'a> {
+    /// The statistics are served by the secondary endpoint of the account,
+    /// so this call fails unless read-access geo-redundant replication is
+    /// enabled.
+    pub async fn finalize(self) -> Result<GetBlobServiceStatsResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?restype=service&comp=stats",
+            secondary_blob_uri(self.client())
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        GetBlobServiceStatsResponse::from_response(&headers, &body)
+    }
+}
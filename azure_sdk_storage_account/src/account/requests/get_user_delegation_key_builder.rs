@@ -0,0 +1,202 @@
+use crate::account::responses::GetUserDelegationKeyResponse;
+use crate::account::{
+    key_info_to_xml, KeyExpiryRequired, KeyExpirySupport, KeyStartOption, KeyStartSupport,
+    USER_DELEGATION_KEY_VERSION,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Utc};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    client: &'a Client,
+    p_key_expiry: PhantomData<KeyExpirySet>,
+    key_expiry: Option<DateTime<Utc>>,
+    key_start: Option<DateTime<Utc>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetUserDelegationKeyBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetUserDelegationKeyBuilder<'a, No> {
+        GetUserDelegationKeyBuilder {
+            client,
+            p_key_expiry: PhantomData {},
+            key_expiry: None,
+            key_start: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, KeyExpirySet> ClientRequired<'a> for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> KeyExpiryRequired for GetUserDelegationKeyBuilder<'a, Yes> {
+    #[inline]
+    fn key_expiry(&self) -> DateTime<Utc> {
+        self.key_expiry.unwrap()
+    }
+}
+
+impl<'a, KeyExpirySet> KeyStartOption for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    #[inline]
+    fn key_start(&self) -> Option<DateTime<Utc>> {
+        self.key_start
+    }
+}
+
+impl<'a, KeyExpirySet> TimeoutOption for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, KeyExpirySet> ClientRequestIdOption<'a> for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, KeyExpirySet> KeyExpirySupport for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    type O = GetUserDelegationKeyBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_key_expiry(self, key_expiry: DateTime<Utc>) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_expiry: PhantomData {},
+            key_expiry: Some(key_expiry),
+            key_start: self.key_start,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, KeyExpirySet> KeyStartSupport for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    type O = GetUserDelegationKeyBuilder<'a, KeyExpirySet>;
+
+    #[inline]
+    fn with_key_start(self, key_start: DateTime<Utc>) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_expiry: PhantomData {},
+            key_expiry: self.key_expiry,
+            key_start: Some(key_start),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, KeyExpirySet> TimeoutSupport for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    type O = GetUserDelegationKeyBuilder<'a, KeyExpirySet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_expiry: PhantomData {},
+            key_expiry: self.key_expiry,
+            key_start: self.key_start,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, KeyExpirySet> ClientRequestIdSupport<'a> for GetUserDelegationKeyBuilder<'a, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+{
+    type O = GetUserDelegationKeyBuilder<'a, KeyExpirySet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_expiry: PhantomData {},
+            key_expiry: self.key_expiry,
+            key_start: self.key_start,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, KeyExpirySet> GetUserDelegationKeyBuilder<'a, KeyExpirySet> where KeyExpirySet: ToAssign {}
+
+impl<'a> GetUserDelegationKeyBuilder<'a, Yes> {
+    /// The key is valid from `key_start`, or from now if not specified. The client must be
+    /// authorized with a token credential: requests signed with the account key are rejected.
+    pub async fn finalize(self) -> Result<GetUserDelegationKeyResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?restype=service&comp=userdelegationkey",
+            self.client().blob_uri()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        trace!("uri == {:?}", uri);
+
+        let key_start = self.key_start().unwrap_or_else(Utc::now);
+        let xml = key_info_to_xml(&key_start, &self.key_expiry());
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::POST,
+            |ref mut request| {
+                request.header_static(VERSION, USER_DELEGATION_KEY_VERSION);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(xml.as_bytes()),
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        GetUserDelegationKeyResponse::from_response(&headers, &body)
+    }
+}
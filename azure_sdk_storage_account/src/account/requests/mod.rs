@@ -1,2 +1,10 @@
 mod get_account_information_builder;
 pub use self::get_account_information_builder::GetAccountInformationBuilder;
+mod get_blob_service_properties_builder;
+pub use self::get_blob_service_properties_builder::GetBlobServicePropertiesBuilder;
+mod get_blob_service_stats_builder;
+pub use self::get_blob_service_stats_builder::GetBlobServiceStatsBuilder;
+mod get_user_delegation_key_builder;
+pub use self::get_user_delegation_key_builder::GetUserDelegationKeyBuilder;
+mod set_blob_service_properties_builder;
+pub use self::set_blob_service_properties_builder::SetBlobServicePropertiesBuilder;
@@ -0,0 +1,172 @@
+use crate::account::responses::SetBlobServicePropertiesResponse;
+use crate::account::{
+    BlobServiceProperties, BlobServicePropertiesRequired, BlobServicePropertiesSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+{
+    client: &'a Client,
+    p_blob_service_properties: PhantomData<BlobServicePropertiesSet>,
+    blob_service_properties: Option<&'a BlobServiceProperties>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetBlobServicePropertiesBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetBlobServicePropertiesBuilder<'a, No> {
+        SetBlobServicePropertiesBuilder {
+            client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, BlobServicePropertiesSet> ClientRequired<'a>
+    for SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> BlobServicePropertiesRequired<'a> for SetBlobServicePropertiesBuilder<'a, Yes> {
+    #[inline]
+    fn blob_service_properties(&self) -> &'a BlobServiceProperties {
+        self.blob_service_properties.unwrap()
+    }
+}
+
+impl<'a, BlobServicePropertiesSet> TimeoutOption
+    for SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, BlobServicePropertiesSet> ClientRequestIdOption<'a>
+    for SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, BlobServicePropertiesSet> BlobServicePropertiesSupport<'a>
+    for SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+{
+    type O = SetBlobServicePropertiesBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_blob_service_properties(
+        self,
+        blob_service_properties: &'a BlobServiceProperties,
+    ) -> Self::O {
+        SetBlobServicePropertiesBuilder {
+            client: self.client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: Some(blob_service_properties),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, BlobServicePropertiesSet> TimeoutSupport
+    for SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+{
+    type O = SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobServicePropertiesBuilder {
+            client: self.client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: self.blob_service_properties,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, BlobServicePropertiesSet> ClientRequestIdSupport<'a>
+    for SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+{
+    type O = SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobServicePropertiesBuilder {
+            client: self.client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: self.blob_service_properties,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, BlobServicePropertiesSet> SetBlobServicePropertiesBuilder<'a, BlobServicePropertiesSet> where
+    BlobServicePropertiesSet: ToAssign
+{
+}
+
+impl<'a> SetBlobServicePropertiesBuilder<'a, Yes> {
+    pub async fn finalize(self) -> Result<SetBlobServicePropertiesResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?restype=service&comp=properties",
+            self.client().blob_uri()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        trace!("uri == {:?}", uri);
+
+        let xml = self.blob_service_properties().to_xml();
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(xml.as_bytes()),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        SetBlobServicePropertiesResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::account::ServiceStats;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::str::from_utf8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBlobServiceStatsResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub service_stats: ServiceStats,
+}
+
+impl GetBlobServiceStatsResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetBlobServiceStatsResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        let body = from_utf8(body)?;
+        let service_stats = ServiceStats::from_xml(body)?;
+
+        Ok(GetBlobServiceStatsResponse {
+            request_id,
+            date,
+            service_stats,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use azure_sdk_storage_core::UserDelegationKey;
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::str::from_utf8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetUserDelegationKeyResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub user_delegation_key: UserDelegationKey,
+}
+
+impl GetUserDelegationKeyResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetUserDelegationKeyResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        let body = from_utf8(body)?;
+        let user_delegation_key = UserDelegationKey::from_xml(body)?;
+
+        Ok(GetUserDelegationKeyResponse {
+            request_id,
+            date,
+            user_delegation_key,
+        })
+    }
+}
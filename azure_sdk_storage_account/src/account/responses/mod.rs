@@ -1,2 +1,10 @@
 mod get_account_information_response;
 pub use get_account_information_response::GetAccountInformationResponse;
+mod get_blob_service_properties_response;
+pub use get_blob_service_properties_response::GetBlobServicePropertiesResponse;
+mod get_blob_service_stats_response;
+pub use get_blob_service_stats_response::GetBlobServiceStatsResponse;
+mod get_user_delegation_key_response;
+pub use get_user_delegation_key_response::GetUserDelegationKeyResponse;
+mod set_blob_service_properties_response;
+pub use set_blob_service_properties_response::SetBlobServicePropertiesResponse;
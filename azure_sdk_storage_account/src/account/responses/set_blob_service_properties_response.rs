@@ -0,0 +1,8 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::RequestId;
+use chrono::{DateTime, Utc};
+
+response_from_headers!(SetBlobServicePropertiesResponse,
+                       request_id_from_headers -> request_id: RequestId,
+                       date_from_headers -> date: DateTime<Utc>
+);
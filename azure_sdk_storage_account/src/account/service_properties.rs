@@ -0,0 +1,401 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse, traverse_single_optional};
+use xml::Element;
+
+/// The properties of the Blob service of a storage account, as returned by
+/// Get Blob Service Properties and accepted by Set Blob Service Properties.
+///
+/// Every field is optional: when setting the properties, the elements left
+/// to `None` are not sent and the service keeps their current value.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlobServiceProperties {
+    pub logging: Option<Logging>,
+    pub hour_metrics: Option<Metrics>,
+    pub minute_metrics: Option<Metrics>,
+    /// `Some(vec![])` removes all the CORS rules of the service.
+    pub cors: Option<Vec<CorsRule>>,
+    pub default_service_version: Option<String>,
+    pub delete_retention_policy: Option<RetentionPolicy>,
+    pub static_website: Option<StaticWebsite>,
+}
+
+/// Storage Analytics logging settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logging {
+    pub version: String,
+    pub delete: bool,
+    pub read: bool,
+    pub write: bool,
+    pub retention_policy: RetentionPolicy,
+}
+
+/// Storage Analytics hour or minute metrics settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metrics {
+    pub version: String,
+    pub enabled: bool,
+    /// Whether the metrics include per-API statistics. Only meaningful if
+    /// `enabled` is true.
+    pub include_apis: Option<bool>,
+    pub retention_policy: RetentionPolicy,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RetentionPolicy {
+    pub enabled: bool,
+    /// Number of days the data is retained. Required if `enabled` is true.
+    pub days: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CorsRule {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub exposed_headers: Vec<String>,
+    pub max_age_in_seconds: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StaticWebsite {
+    pub enabled: bool,
+    pub index_document: Option<String>,
+    pub error_document_404_path: Option<String>,
+}
+
+impl BlobServiceProperties {
+    pub fn new() -> BlobServiceProperties {
+        BlobServiceProperties::default()
+    }
+
+    pub fn from_xml(xml: &str) -> Result<BlobServiceProperties, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+        let logging = match traverse_single_optional(&elem, &["Logging"])? {
+            Some(node) => Some(Logging {
+                version: cast_must::<String>(node, &["Version"])?,
+                delete: cast_must::<bool>(node, &["Delete"])?,
+                read: cast_must::<bool>(node, &["Read"])?,
+                write: cast_must::<bool>(node, &["Write"])?,
+                retention_policy: parse_retention_policy(node, "RetentionPolicy")?
+                    .unwrap_or_default(),
+            }),
+            None => None,
+        };
+
+        let cors = match traverse_single_optional(&elem, &["Cors"])? {
+            Some(node) => {
+                let mut rules = Vec::new();
+                for rule in traverse(node, &["CorsRule"], true)? {
+                    rules.push(CorsRule {
+                        allowed_origins: split_list(&cast_must::<String>(
+                            rule,
+                            &["AllowedOrigins"],
+                        )?),
+                        allowed_methods: split_list(&cast_must::<String>(
+                            rule,
+                            &["AllowedMethods"],
+                        )?),
+                        allowed_headers: split_list(
+                            &cast_optional::<String>(rule, &["AllowedHeaders"])?
+                                .unwrap_or_default(),
+                        ),
+                        exposed_headers: split_list(
+                            &cast_optional::<String>(rule, &["ExposedHeaders"])?
+                                .unwrap_or_default(),
+                        ),
+                        max_age_in_seconds: cast_must::<u64>(rule, &["MaxAgeInSeconds"])?,
+                    });
+                }
+                Some(rules)
+            }
+            None => None,
+        };
+
+        let static_website = match traverse_single_optional(&elem, &["StaticWebsite"])? {
+            Some(node) => Some(StaticWebsite {
+                enabled: cast_must::<bool>(node, &["Enabled"])?,
+                index_document: non_empty(cast_optional::<String>(node, &["IndexDocument"])?),
+                error_document_404_path: non_empty(cast_optional::<String>(
+                    node,
+                    &["ErrorDocument404Path"],
+                )?),
+            }),
+            None => None,
+        };
+
+        Ok(BlobServiceProperties {
+            logging,
+            hour_metrics: parse_metrics(&elem, "HourMetrics")?,
+            minute_metrics: parse_metrics(&elem, "MinuteMetrics")?,
+            cors,
+            default_service_version: non_empty(cast_optional::<String>(
+                &elem,
+                &["DefaultServiceVersion"],
+            )?),
+            delete_retention_policy: parse_retention_policy(&elem, "DeleteRetentionPolicy")?,
+            static_website,
+        })
+    }
+
+    pub fn to_xml(&self) -> String {
+        let mut s = String::new();
+        s.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<StorageServiceProperties>\n");
+
+        if let Some(logging) = &self.logging {
+            s.push_str("\t<Logging>\n");
+            s.push_str(&format!("\t\t<Version>{}</Version>\n", logging.version));
+            s.push_str(&format!("\t\t<Delete>{}</Delete>\n", logging.delete));
+            s.push_str(&format!("\t\t<Read>{}</Read>\n", logging.read));
+            s.push_str(&format!("\t\t<Write>{}</Write>\n", logging.write));
+            push_retention_policy(&mut s, "RetentionPolicy", &logging.retention_policy, 2);
+            s.push_str("\t</Logging>\n");
+        }
+
+        if let Some(metrics) = &self.hour_metrics {
+            push_metrics(&mut s, "HourMetrics", metrics);
+        }
+        if let Some(metrics) = &self.minute_metrics {
+            push_metrics(&mut s, "MinuteMetrics", metrics);
+        }
+
+        if let Some(cors) = &self.cors {
+            s.push_str("\t<Cors>\n");
+            for rule in cors {
+                s.push_str("\t\t<CorsRule>\n");
+                s.push_str(&format!(
+                    "\t\t\t<AllowedOrigins>{}</AllowedOrigins>\n",
+                    rule.allowed_origins.join(",")
+                ));
+                s.push_str(&format!(
+                    "\t\t\t<AllowedMethods>{}</AllowedMethods>\n",
+                    rule.allowed_methods.join(",")
+                ));
+                s.push_str(&format!(
+                    "\t\t\t<MaxAgeInSeconds>{}</MaxAgeInSeconds>\n",
+                    rule.max_age_in_seconds
+                ));
+                s.push_str(&format!(
+                    "\t\t\t<ExposedHeaders>{}</ExposedHeaders>\n",
+                    rule.exposed_headers.join(",")
+                ));
+                s.push_str(&format!(
+                    "\t\t\t<AllowedHeaders>{}</AllowedHeaders>\n",
+                    rule.allowed_headers.join(",")
+                ));
+                s.push_str("\t\t</CorsRule>\n");
+            }
+            s.push_str("\t</Cors>\n");
+        }
+
+        if let Some(default_service_version) = &self.default_service_version {
+            s.push_str(&format!(
+                "\t<DefaultServiceVersion>{}</DefaultServiceVersion>\n",
+                default_service_version
+            ));
+        }
+
+        if let Some(delete_retention_policy) = &self.delete_retention_policy {
+            push_retention_policy(&mut s, "DeleteRetentionPolicy", delete_retention_policy, 1);
+        }
+
+        if let Some(static_website) = &self.static_website {
+            s.push_str("\t<StaticWebsite>\n");
+            s.push_str(&format!(
+                "\t\t<Enabled>{}</Enabled>\n",
+                static_website.enabled
+            ));
+            if let Some(index_document) = &static_website.index_document {
+                s.push_str(&format!(
+                    "\t\t<IndexDocument>{}</IndexDocument>\n",
+                    index_document
+                ));
+            }
+            if let Some(error_document_404_path) = &static_website.error_document_404_path {
+                s.push_str(&format!(
+                    "\t\t<ErrorDocument404Path>{}</ErrorDocument404Path>\n",
+                    error_document_404_path
+                ));
+            }
+            s.push_str("\t</StaticWebsite>\n");
+        }
+
+        s.push_str("</StorageServiceProperties>");
+        s
+    }
+}
+
+fn parse_metrics(elem: &Element, name: &str) -> Result<Option<Metrics>, AzureError> {
+    match traverse_single_optional(elem, &[name])? {
+        Some(node) => Ok(Some(Metrics {
+            version: cast_must::<String>(node, &["Version"])?,
+            enabled: cast_must::<bool>(node, &["Enabled"])?,
+            include_apis: cast_optional::<bool>(node, &["IncludeAPIs"])?,
+            retention_policy: parse_retention_policy(node, "RetentionPolicy")?.unwrap_or_default(),
+        })),
+        None => Ok(None),
+    }
+}
+
+fn parse_retention_policy(
+    elem: &Element,
+    name: &str,
+) -> Result<Option<RetentionPolicy>, AzureError> {
+    match traverse_single_optional(elem, &[name])? {
+        Some(node) => Ok(Some(RetentionPolicy {
+            enabled: cast_must::<bool>(node, &["Enabled"])?,
+            days: cast_optional::<u64>(node, &["Days"])?,
+        })),
+        None => Ok(None),
+    }
+}
+
+fn push_metrics(s: &mut String, name: &str, metrics: &Metrics) {
+    s.push_str(&format!("\t<{}>\n", name));
+    s.push_str(&format!("\t\t<Version>{}</Version>\n", metrics.version));
+    s.push_str(&format!("\t\t<Enabled>{}</Enabled>\n", metrics.enabled));
+    if let Some(include_apis) = metrics.include_apis {
+        s.push_str(&format!(
+            "\t\t<IncludeAPIs>{}</IncludeAPIs>\n",
+            include_apis
+        ));
+    }
+    push_retention_policy(s, "RetentionPolicy", &metrics.retention_policy, 2);
+    s.push_str(&format!("\t</{}>\n", name));
+}
+
+fn push_retention_policy(s: &mut String, name: &str, policy: &RetentionPolicy, indent: usize) {
+    let tabs = "\t".repeat(indent);
+    s.push_str(&format!("{}<{}>\n", tabs, name));
+    s.push_str(&format!(
+        "{}\t<Enabled>{}</Enabled>\n",
+        tabs, policy.enabled
+    ));
+    if let Some(days) = policy.days {
+        s.push_str(&format!("{}\t<Days>{}</Days>\n", tabs, days));
+    }
+    s.push_str(&format!("{}</{}>\n", tabs, name));
+}
+
+fn split_list(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+fn non_empty(s: Option<String>) -> Option<String> {
+    s.filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const XML: &str = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>
+        <StorageServiceProperties>
+            <Logging>
+                <Version>1.0</Version>
+                <Read>false</Read>
+                <Write>true</Write>
+                <Delete>true</Delete>
+                <RetentionPolicy><Enabled>true</Enabled><Days>7</Days></RetentionPolicy>
+            </Logging>
+            <HourMetrics>
+                <Version>1.0</Version>
+                <Enabled>true</Enabled>
+                <IncludeAPIs>false</IncludeAPIs>
+                <RetentionPolicy><Enabled>true</Enabled><Days>7</Days></RetentionPolicy>
+            </HourMetrics>
+            <MinuteMetrics>
+                <Version>1.0</Version>
+                <Enabled>false</Enabled>
+                <RetentionPolicy><Enabled>false</Enabled></RetentionPolicy>
+            </MinuteMetrics>
+            <Cors>
+                <CorsRule>
+                    <AllowedMethods>GET,PUT</AllowedMethods>
+                    <AllowedOrigins>http://www.contoso.com,http://www.fabrikam.com</AllowedOrigins>
+                    <AllowedHeaders>x-ms-meta-*</AllowedHeaders>
+                    <ExposedHeaders />
+                    <MaxAgeInSeconds>500</MaxAgeInSeconds>
+                </CorsRule>
+            </Cors>
+            <DefaultServiceVersion>2018-03-28</DefaultServiceVersion>
+            <DeleteRetentionPolicy><Enabled>true</Enabled><Days>5</Days></DeleteRetentionPolicy>
+            <StaticWebsite>
+                <Enabled>true</Enabled>
+                <IndexDocument>index.html</IndexDocument>
+                <ErrorDocument404Path>error/404.html</ErrorDocument404Path>
+            </StaticWebsite>
+        </StorageServiceProperties>";
+
+    #[test]
+    fn parse_from_xml() {
+        let properties = BlobServiceProperties::from_xml(XML).unwrap();
+
+        let logging = properties.logging.as_ref().unwrap();
+        assert!(!logging.read && logging.write && logging.delete);
+        assert_eq!(logging.retention_policy.days, Some(7));
+
+        let minute_metrics = properties.minute_metrics.as_ref().unwrap();
+        assert!(!minute_metrics.enabled);
+        assert_eq!(minute_metrics.include_apis, None);
+        assert_eq!(minute_metrics.retention_policy, RetentionPolicy::default());
+
+        let cors = properties.cors.as_ref().unwrap();
+        assert_eq!(cors.len(), 1);
+        assert_eq!(
+            cors[0].allowed_origins,
+            vec!["http://www.contoso.com", "http://www.fabrikam.com"]
+        );
+        assert_eq!(cors[0].allowed_methods, vec!["GET", "PUT"]);
+        assert!(cors[0].exposed_headers.is_empty());
+        assert_eq!(cors[0].max_age_in_seconds, 500);
+
+        assert_eq!(
+            properties.default_service_version.as_deref(),
+            Some("2018-03-28")
+        );
+        assert_eq!(
+            properties.delete_retention_policy,
+            Some(RetentionPolicy {
+                enabled: true,
+                days: Some(5)
+            })
+        );
+        assert_eq!(
+            properties
+                .static_website
+                .as_ref()
+                .unwrap()
+                .error_document_404_path
+                .as_deref(),
+            Some("error/404.html")
+        );
+    }
+
+    #[test]
+    fn xml_round_trip() {
+        let properties = BlobServiceProperties::from_xml(XML).unwrap();
+        let round_tripped = BlobServiceProperties::from_xml(&properties.to_xml()).unwrap();
+        assert_eq!(properties, round_tripped);
+    }
+
+    #[test]
+    fn to_xml_skips_unset_elements() {
+        let properties = BlobServiceProperties {
+            cors: Some(Vec::new()),
+            ..BlobServiceProperties::default()
+        };
+
+        let xml = properties.to_xml();
+        assert!(xml.contains("<Cors>"));
+        assert!(!xml.contains("<Logging>"));
+        assert!(!xml.contains("<StaticWebsite>"));
+
+        let parsed = BlobServiceProperties::from_xml(&xml).unwrap();
+        assert_eq!(parsed, properties);
+    }
+}
@@ -0,0 +1,90 @@
+use azure_sdk_core::enumerations;
+use azure_sdk_core::errors::{AzureError, TraversingError};
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse_single_must, FromStringOptional};
+use chrono::{DateTime, Utc};
+use std::{fmt, str::FromStr};
+use xml::Element;
+
+create_enum!(
+    GeoReplicationStatus,
+    (Live, "live"),
+    (Bootstrap, "bootstrap"),
+    (Unavailable, "unavailable")
+);
+
+/// Statistics of the Blob service, as returned by Get Blob Service Stats.
+/// Only available for accounts with read-access geo-redundant replication.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceStats {
+    pub geo_replication: GeoReplication,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoReplication {
+    pub status: GeoReplicationStatus,
+    /// All the writes preceding this time are guaranteed to be available
+    /// for reads from the secondary. `None` if the status is not `Live`.
+    pub last_sync_time: Option<DateTime<Utc>>,
+}
+
+impl ServiceStats {
+    pub fn from_xml(xml: &str) -> Result<ServiceStats, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+        let node = traverse_single_must(&elem, &["GeoReplication"])?;
+
+        let last_sync_time = match cast_optional::<String>(node, &["LastSyncTime"])? {
+            Some(ref last_sync_time) if !last_sync_time.is_empty() => {
+                Some(DateTime::<Utc>::from_str_optional(last_sync_time)?)
+            }
+            _ => None,
+        };
+
+        Ok(ServiceStats {
+            geo_replication: GeoReplication {
+                status: cast_must::<GeoReplicationStatus>(node, &["Status"])?,
+                last_sync_time,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    #[test]
+    fn parse_from_xml() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <StorageServiceStats>
+                <GeoReplication>
+                    <Status>live</Status>
+                    <LastSyncTime>Wed, 12 Feb 2020 16:41:33 GMT</LastSyncTime>
+                </GeoReplication>
+            </StorageServiceStats>";
+
+        let stats = ServiceStats::from_xml(xml).unwrap();
+        assert_eq!(stats.geo_replication.status, GeoReplicationStatus::Live);
+        let last_sync_time = stats.geo_replication.last_sync_time.unwrap();
+        assert_eq!(last_sync_time.day(), 12);
+        assert_eq!(last_sync_time.hour(), 16);
+    }
+
+    #[test]
+    fn parse_from_xml_without_sync_time() {
+        let xml = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <StorageServiceStats>
+                <GeoReplication>
+                    <Status>bootstrap</Status>
+                    <LastSyncTime />
+                </GeoReplication>
+            </StorageServiceStats>";
+
+        let stats = ServiceStats::from_xml(xml).unwrap();
+        assert_eq!(
+            stats.geo_replication.status,
+            GeoReplicationStatus::Bootstrap
+        );
+        assert_eq!(stats.geo_replication.last_sync_time, None);
+    }
+}
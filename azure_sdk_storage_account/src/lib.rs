@@ -2,14 +2,25 @@
 
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate azure_sdk_core;
 pub mod account;
 pub mod prelude;
 
+use azure_sdk_core::No;
 use azure_sdk_storage_core::client::Client;
 
 pub trait Account {
     #[allow(clippy::needless_lifetimes)]
     fn get_account_information<'a>(&'a self) -> account::requests::GetAccountInformationBuilder<'a>;
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_properties<'a>(&'a self) -> account::requests::GetBlobServicePropertiesBuilder<'a>;
+    #[allow(clippy::needless_lifetimes)]
+    fn set_blob_service_properties<'a>(&'a self) -> account::requests::SetBlobServicePropertiesBuilder<'a, No>;
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_stats<'a>(&'a self) -> account::requests::GetBlobServiceStatsBuilder<'a>;
+    #[allow(clippy::needless_lifetimes)]
+    fn get_user_delegation_key<'a>(&'a self) -> account::requests::GetUserDelegationKeyBuilder<'a, No>;
 }
 
 impl Account for Client {
@@ -17,4 +28,24 @@ impl Account for Client {
     fn get_account_information<'a>(&'a self) -> account::requests::GetAccountInformationBuilder<'a> {
         account::requests::GetAccountInformationBuilder::new(self)
     }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_properties<'a>(&'a self) -> account::requests::GetBlobServicePropertiesBuilder<'a> {
+        account::requests::GetBlobServicePropertiesBuilder::new(self)
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn set_blob_service_properties<'a>(&'a self) -> account::requests::SetBlobServicePropertiesBuilder<'a, No> {
+        account::requests::SetBlobServicePropertiesBuilder::new(self)
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_stats<'a>(&'a self) -> account::requests::GetBlobServiceStatsBuilder<'a> {
+        account::requests::GetBlobServiceStatsBuilder::new(self)
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn get_user_delegation_key<'a>(&'a self) -> account::requests::GetUserDelegationKeyBuilder<'a, No> {
+        account::requests::GetUserDelegationKeyBuilder::new(self)
+    }
 }
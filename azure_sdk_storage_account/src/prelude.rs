@@ -1 +1,4 @@
+pub use crate::account::{
+    BlobServiceProperties, BlobServicePropertiesSupport, KeyExpirySupport, KeyStartSupport,
+};
 pub use crate::Account as AccountTrait;
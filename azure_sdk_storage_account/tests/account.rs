@@ -15,3 +15,35 @@ async fn get_account_information() {
     // values depend on the Azure storage account
     client.get_account_information().finalize().await.unwrap();
 }
+
+#[tokio::test]
+async fn blob_service_properties() {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let client = Client::new(&account, &master_key).unwrap();
+
+    let res = client
+        .get_blob_service_properties()
+        .finalize()
+        .await
+        .unwrap();
+    let properties = res.blob_service_properties;
+
+    // writing back the current properties must leave the account unchanged
+    client
+        .set_blob_service_properties()
+        .with_blob_service_properties(&properties)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob_service_properties()
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.blob_service_properties, properties);
+}
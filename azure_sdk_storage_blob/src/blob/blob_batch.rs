@@ -0,0 +1,238 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::{BLOB_ACCESS_TIER, DELETE_SNAPSHOTS};
+use azure_sdk_core::{AccessTier, DeleteSnapshotsMethod};
+use azure_sdk_storage_core::client::Client;
+use hyper::header::{HeaderValue, CONTENT_LENGTH};
+use hyper::{HeaderMap, Method};
+use std::fmt::Write;
+use url::{form_urlencoded, Url};
+
+/// Most sub-requests a single batch can hold.
+pub const MAX_BLOB_BATCH_OPERATIONS: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlobBatchOperation<'a> {
+    Delete {
+        container_name: &'a str,
+        blob_name: &'a str,
+        delete_snapshots_method: Option<DeleteSnapshotsMethod>,
+    },
+    SetTier {
+        container_name: &'a str,
+        blob_name: &'a str,
+        access_tier: AccessTier,
+    },
+}
+
+impl<'a> BlobBatchOperation<'a> {
+    pub fn container_name(&self) -> &'a str {
+        match self {
+            BlobBatchOperation::Delete { container_name, .. } => container_name,
+            BlobBatchOperation::SetTier { container_name, .. } => container_name,
+        }
+    }
+
+    pub fn blob_name(&self) -> &'a str {
+        match self {
+            BlobBatchOperation::Delete { blob_name, .. } => blob_name,
+            BlobBatchOperation::SetTier { blob_name, .. } => blob_name,
+        }
+    }
+
+    fn method(&self) -> Method {
+        match self {
+            BlobBatchOperation::Delete { .. } => Method::DELETE,
+            BlobBatchOperation::SetTier { .. } => Method::PUT,
+        }
+    }
+
+    fn uri(&self, client: &Client) -> String {
+        let uri = format!(
+            "{}/{}/{}",
+            client.blob_uri(),
+            form_urlencoded::byte_serialize(self.container_name().as_bytes()).collect::<String>(),
+            form_urlencoded::byte_serialize(self.blob_name().as_bytes()).collect::<String>(),
+        );
+        match self {
+            BlobBatchOperation::Delete { .. } => uri,
+            BlobBatchOperation::SetTier { .. } => format!("{}?comp=tier", uri),
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        match self {
+            BlobBatchOperation::Delete {
+                delete_snapshots_method: Some(delete_snapshots_method),
+                ..
+            } => {
+                let s: &'static str = (*delete_snapshots_method).into();
+                headers.insert(DELETE_SNAPSHOTS, HeaderValue::from_static(s));
+            }
+            BlobBatchOperation::Delete { .. } => {}
+            BlobBatchOperation::SetTier { access_tier, .. } => {
+                let s: &'static str = (*access_tier).into();
+                headers.insert(BLOB_ACCESS_TIER, HeaderValue::from_static(s));
+            }
+        }
+        headers
+    }
+
+    fn same_kind(&self, other: &BlobBatchOperation) -> bool {
+        matches!(
+            (self, other),
+            (BlobBatchOperation::Delete { .. }, BlobBatchOperation::Delete { .. })
+                | (BlobBatchOperation::SetTier { .. }, BlobBatchOperation::SetTier { .. })
+        )
+    }
+}
+
+/// The sub-requests of a Blob Batch request. A batch holds at most
+/// `MAX_BLOB_BATCH_OPERATIONS` operations, all deletes or all tier changes.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct BlobBatch<'a> {
+    pub operations: Vec<BlobBatchOperation<'a>>,
+}
+
+impl<'a> BlobBatch<'a> {
+    pub fn new() -> BlobBatch<'a> {
+        BlobBatch::default()
+    }
+
+    pub fn delete(
+        &mut self,
+        container_name: &'a str,
+        blob_name: &'a str,
+        delete_snapshots_method: Option<DeleteSnapshotsMethod>,
+    ) -> &mut Self {
+        self.operations.push(BlobBatchOperation::Delete {
+            container_name,
+            blob_name,
+            delete_snapshots_method,
+        });
+        self
+    }
+
+    pub fn set_tier(
+        &mut self,
+        container_name: &'a str,
+        blob_name: &'a str,
+        access_tier: AccessTier,
+    ) -> &mut Self {
+        self.operations.push(BlobBatchOperation::SetTier {
+            container_name,
+            blob_name,
+            access_tier,
+        });
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), AzureError> {
+        let first = match self.operations.first() {
+            Some(first) => first,
+            None => {
+                return Err(AzureError::InputParametersError(
+                    "a blob batch must hold at least one operation".to_owned(),
+                ))
+            }
+        };
+        if self.operations.len() > MAX_BLOB_BATCH_OPERATIONS {
+            return Err(AzureError::InputParametersError(format!(
+                "a blob batch can hold at most {} operations, not {}",
+                MAX_BLOB_BATCH_OPERATIONS,
+                self.operations.len()
+            )));
+        }
+        if !self.operations.iter().all(|op| op.same_kind(first)) {
+            return Err(AzureError::InputParametersError(
+                "a blob batch cannot mix deletes and tier changes".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Serializes the batch as a multipart/mixed body, signing every
+    /// sub-request with the credentials of `client`. The Content-ID of each
+    /// part is the index of its operation.
+    pub(crate) fn to_multipart(
+        &self,
+        client: &Client,
+        boundary: &str,
+    ) -> Result<String, AzureError> {
+        let mut s = String::new();
+
+        for (content_id, operation) in self.operations.iter().enumerate() {
+            let method = operation.method();
+            let mut headers = operation.headers();
+            let uri = client.sign_sub_request(&operation.uri(client), &method, &mut headers)?;
+
+            let url = Url::parse(&uri)?;
+            let path = match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_owned(),
+            };
+
+            write!(
+                s,
+                "--{}\r\nContent-Type: application/http\r\nContent-Transfer-Encoding: binary\r\n\
+                 Content-ID: {}\r\n\r\n{} {} HTTP/1.1\r\n",
+                boundary, content_id, method, path
+            )
+            .unwrap();
+            for (name, value) in headers.iter() {
+                write!(s, "{}: {}\r\n", name, value.to_str()?).unwrap();
+            }
+            s.push_str("\r\n");
+        }
+        write!(s, "--{}--\r\n", boundary).unwrap();
+
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate() {
+        assert!(BlobBatch::new().validate().is_err());
+
+        let mut batch = BlobBatch::new();
+        batch.delete("container", "a", None).delete(
+            "container",
+            "b",
+            Some(DeleteSnapshotsMethod::Include),
+        );
+        assert!(batch.validate().is_ok());
+
+        batch.set_tier("container", "c", AccessTier::Cool);
+        assert!(batch.validate().is_err());
+
+        let mut batch = BlobBatch::new();
+        for _ in 0..=MAX_BLOB_BATCH_OPERATIONS {
+            batch.set_tier("container", "blob", AccessTier::Cool);
+        }
+        assert!(batch.validate().is_err());
+    }
+
+    #[test]
+    fn to_multipart() {
+        let client = Client::new("mindrust", "MDEyMzQ1Njc4OWFiY2RlZg==").unwrap();
+
+        let mut batch = BlobBatch::new();
+        batch
+            .set_tier("container", "a b", AccessTier::Archive)
+            .set_tier("container", "c", AccessTier::Hot);
+
+        let body = batch.to_multipart(&client, "batch_boundary").unwrap();
+        let parts = body.split("--batch_boundary").collect::<Vec<_>>();
+        assert_eq!(parts.len(), 4);
+        assert!(parts[1].contains("Content-ID: 0\r\n\r\nPUT /container/a+b?comp=tier HTTP/1.1\r\n"));
+        assert!(parts[1].contains("x-ms-access-tier: Archive\r\n"));
+        assert!(parts[1].contains("authorization: SharedKey mindrust:"));
+        assert!(parts[2].contains("Content-ID: 1\r\n\r\nPUT /container/c?comp=tier HTTP/1.1\r\n"));
+        assert_eq!(parts[3], "--\r\n");
+    }
+}
@@ -0,0 +1,122 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse};
+use std::collections::HashMap;
+use xml::Element;
+
+/// A blob returned by Find Blobs by Tags.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedBlob {
+    pub container_name: String,
+    pub name: String,
+    /// Value of the tag matched by the filter expression, if the service
+    /// returned it.
+    pub tag_value: Option<String>,
+}
+
+pub(crate) fn tags_to_xml(tags: &HashMap<&str, &str>) -> String {
+    // sorted so the body does not depend on the hash map iteration order
+    let mut tags = tags.iter().collect::<Vec<_>>();
+    tags.sort();
+
+    let mut s = String::new();
+    s.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<Tags>\n\t<TagSet>\n");
+    for (key, value) in tags {
+        s.push_str(&format!(
+            "\t\t<Tag>\n\t\t\t<Key>{}</Key>\n\t\t\t<Value>{}</Value>\n\t\t</Tag>\n",
+            key, value
+        ));
+    }
+    s.push_str("\t</TagSet>\n</Tags>");
+    s
+}
+
+pub(crate) fn tags_from_xml(xml: &str) -> Result<HashMap<String, String>, AzureError> {
+    let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+    let mut tags = HashMap::new();
+    for node in traverse(&elem, &["TagSet", "Tag"], true)? {
+        tags.insert(
+            cast_must::<String>(node, &["Key"])?,
+            cast_must::<String>(node, &["Value"])?,
+        );
+    }
+    Ok(tags)
+}
+
+pub(crate) fn tagged_blobs_from_response(
+    body: &str,
+) -> Result<IncompleteVector<TaggedBlob>, AzureError> {
+    let elem: Element = body.trim_start_matches('\u{feff}').parse()?;
+
+    let next_marker = match cast_optional::<String>(&elem, &["NextMarker"])? {
+        Some(ref nm) if nm.is_empty() => None,
+        Some(nm) => Some(nm),
+        None => None,
+    };
+
+    let mut v = Vec::new();
+    for node in traverse(&elem, &["Blobs", "Blob"], true)? {
+        v.push(TaggedBlob {
+            container_name: cast_must::<String>(node, &["ContainerName"])?,
+            name: cast_must::<String>(node, &["Name"])?,
+            tag_value: cast_optional::<String>(node, &["TagValue"])?,
+        });
+    }
+
+    Ok(IncompleteVector::new(next_marker, v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tags_xml_round_trip() {
+        let mut tags = HashMap::new();
+        tags.insert("project", "rust");
+        tags.insert("status", "done");
+
+        let xml = tags_to_xml(&tags);
+        assert!(xml.find("<Key>project</Key>").unwrap() < xml.find("<Key>status</Key>").unwrap());
+
+        let parsed = tags_from_xml(&xml).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed["project"], "rust");
+        assert_eq!(parsed["status"], "done");
+    }
+
+    #[test]
+    fn parse_empty_tags() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?><Tags><TagSet /></Tags>";
+        assert!(tags_from_xml(xml).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_tagged_blobs() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <EnumerationResults ServiceEndpoint=\"https://mindrust.blob.core.windows.net/\">
+                <Where>\"status\" = 'done'</Where>
+                <Blobs>
+                    <Blob>
+                        <Name>a.txt</Name>
+                        <ContainerName>pippo</ContainerName>
+                        <TagValue>done</TagValue>
+                    </Blob>
+                    <Blob>
+                        <Name>b.txt</Name>
+                        <ContainerName>pluto</ContainerName>
+                    </Blob>
+                </Blobs>
+                <NextMarker>2!84!MDAwMDE5</NextMarker>
+            </EnumerationResults>";
+
+        let blobs = tagged_blobs_from_response(body).unwrap();
+        assert_eq!(blobs.token(), Some("2!84!MDAwMDE5"));
+        assert_eq!(blobs.len(), 2);
+        assert_eq!(blobs[0].container_name, "pippo");
+        assert_eq!(blobs[0].tag_value.as_deref(), Some("done"));
+        assert_eq!(blobs[1].name, "b.txt");
+        assert_eq!(blobs[1].tag_value, None);
+    }
+}
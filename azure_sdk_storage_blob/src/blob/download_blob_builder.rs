@@ -0,0 +1,736 @@
+use crate::blob::requests::{GetBlobBuilder, GetBlobPropertiesBuilder};
+use crate::blob::responses::DownloadBlobResponse;
+use crate::blob::{
+    BlockSizeOption, BlockSizeSupport, MaxConcurrencyOption, MaxConcurrencySupport,
+    MaxRetriesOption, MaxRetriesSupport, ValidateMD5Option, ValidateMD5Support,
+};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::range::Range;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, LeaseIdOption, LeaseIdSupport, No, RangeSupport,
+    RetryPolicy, SnapshotOption, SnapshotSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Utc};
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::stream::{StreamExt, TryStreamExt};
+use std::fs::File;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+// The backoff between the attempts of a range.
+const RANGE_RETRY_DELAY: Duration = Duration::from_millis(500);
+const RANGE_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    snapshot: Option<DateTime<Utc>>,
+    block_size: u64,
+    max_concurrency: usize,
+    max_retries: u32,
+    validate_md5: bool,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> DownloadBlobBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> DownloadBlobBuilder<'a, No, No> {
+        DownloadBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            snapshot: None,
+            block_size: 4 * 1024 * 1024,
+            max_concurrency: 4,
+            max_retries: 3,
+            validate_md5: false,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequired<'a>
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet> ContainerNameRequired<'a> for DownloadBlobBuilder<'a, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> BlobNameRequired<'a> for DownloadBlobBuilder<'a, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> SnapshotOption
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn snapshot(&self) -> Option<DateTime<Utc>> {
+        self.snapshot
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlockSizeOption
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn block_size(&self) -> u64 {
+        self.block_size
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxConcurrencyOption
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxRetriesOption
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ValidateMD5Option
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn validate_md5(&self) -> bool {
+        self.validate_md5
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutOption
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContainerNameSupport<'a>
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlobNameSupport<'a>
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> SnapshotSupport
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_snapshot(self, snapshot: DateTime<Utc>) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: Some(snapshot),
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlockSizeSupport
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_block_size(self, block_size: u64) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxConcurrencySupport
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_concurrency(self, max_concurrency: usize) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxRetriesSupport
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_retries(self, max_retries: u32) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ValidateMD5Support
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_validate_md5(self) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: true,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            validate_md5: self.validate_md5,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet> DownloadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+}
+
+impl<'a> DownloadBlobBuilder<'a, Yes, Yes> {
+    /// Downloads the blob into `writer`. The blob is fetched in ranges of
+    /// `block_size` bytes with at most `max_concurrency` requests in
+    /// flight; the ranges are written in order as soon as the preceding
+    /// ones are. A failed range is requested again, up to `max_retries`
+    /// times with an exponential backoff, without downloading the other
+    /// ranges again.
+    pub async fn finalize<W>(self, writer: W) -> Result<DownloadBlobResponse, AzureError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        check_max_concurrency(self.max_concurrency())?;
+
+        let mut writer = writer;
+        let response = self.get_properties().await?;
+
+        let bytes_written = {
+            let builder = &self;
+            let etag = response.blob.etag.as_deref();
+            let mut ranges = futures::stream::iter(split_in_ranges(
+                response.blob.content_length,
+                self.block_size(),
+            )?)
+            .map(|range| async move { builder.get_range(&range, etag).await })
+            .buffered(self.max_concurrency());
+
+            let mut bytes_written = 0;
+            while let Some(data) = ranges.next().await {
+                let data = data?;
+                writer.write_all(&data).await?;
+                bytes_written += data.len() as u64;
+            }
+            bytes_written
+        };
+        writer.flush().await?;
+
+        Ok(response.with_bytes_written(bytes_written))
+    }
+
+    /// Same as `finalize` but writes every range at its offset in `file` as
+    /// soon as it is downloaded, so the ranges do not have to wait for the
+    /// preceding ones.
+    pub async fn finalize_to_file(self, file: &File) -> Result<DownloadBlobResponse, AzureError> {
+        check_max_concurrency(self.max_concurrency())?;
+
+        let response = self.get_properties().await?;
+        file.set_len(response.blob.content_length)?;
+
+        let builder = &self;
+        let etag = response.blob.etag.as_deref();
+        let bytes_written = futures::stream::iter(split_in_ranges(
+            response.blob.content_length,
+            self.block_size(),
+        )?)
+        .map(|range| async move {
+            let data = builder.get_range(&range, etag).await?;
+            Ok::<_, AzureError>((range.start, data))
+        })
+        .buffer_unordered(self.max_concurrency())
+        .try_fold(0, |bytes_written, (offset, data)| {
+            futures::future::ready(
+                write_at(file, &data, offset)
+                    .map(|_| bytes_written + data.len() as u64)
+                    .map_err(AzureError::from),
+            )
+        })
+        .await?;
+
+        Ok(response.with_bytes_written(bytes_written))
+    }
+
+    async fn get_properties(&self) -> Result<DownloadBlobResponse, AzureError> {
+        let mut request = GetBlobPropertiesBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name());
+
+        if let Some(snapshot) = self.snapshot() {
+            request = request.with_snapshot(snapshot);
+        }
+        if let Some(lease_id) = self.lease_id() {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+
+        let response = request.finalize().await?;
+        Ok(DownloadBlobResponse::from_get_blob_properties(response))
+    }
+
+    async fn get_range(&self, range: &Range, etag: Option<&str>) -> Result<Vec<u8>, AzureError> {
+        if self.validate_md5() && range.len() > 4 * 1024 * 1024 {
+            return Err(AzureError::InputParametersError(format!(
+                "cannot validate the MD5 of ranges larger than 4 MiB ({} bytes requested)",
+                range.len()
+            )));
+        }
+
+        let retry_policy = RetryPolicy::exponential(
+            RANGE_RETRY_DELAY,
+            RANGE_RETRY_MAX_DELAY,
+            self.max_retries() + 1,
+        );
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let mut request = GetBlobBuilder::new(self.client())
+                .with_container_name(self.container_name())
+                .with_blob_name(self.blob_name())
+                .with_range(range);
+
+            if let Some(snapshot) = self.snapshot() {
+                request = request.with_snapshot(snapshot);
+            }
+            if let Some(lease_id) = self.lease_id() {
+                request = request.with_lease_id(lease_id);
+            }
+            if let Some(timeout) = self.timeout() {
+                request = request.with_timeout(timeout);
+            }
+            if let Some(client_request_id) = self.client_request_id() {
+                request = request.with_client_request_id(client_request_id);
+            }
+
+            let response = match request.finalize().await {
+                Ok(response) => response,
+                Err(err) => match retry_policy.backoff(attempt) {
+                    Some(delay) => {
+                        warn!("range {} failed, retrying in {:?}: {}", range, delay, err);
+                        tokio::timer::delay_for(delay).await;
+                        continue;
+                    }
+                    None => return Err(err),
+                },
+            };
+
+            // the blob must not change while its ranges are being downloaded
+            if response.blob.etag.as_deref() != etag {
+                return Err(AzureError::GenericErrorWithText(format!(
+                    "blob changed during the download: etag {:?} instead of {:?}",
+                    response.blob.etag, etag
+                )));
+            }
+
+            if self.validate_md5() {
+                let md5 = base64::encode(&md5::compute(&response.data)[..]);
+                if response.blob.content_md5.as_ref() != Some(&md5) {
+                    if let Some(delay) = retry_policy.backoff(attempt) {
+                        warn!(
+                            "range {} failed MD5 validation, retrying in {:?}",
+                            range, delay
+                        );
+                        tokio::timer::delay_for(delay).await;
+                        continue;
+                    }
+                    return Err(AzureError::GenericErrorWithText(format!(
+                        "MD5 mismatch on range {}: expected {:?}, computed {}",
+                        range, response.blob.content_md5, md5
+                    )));
+                }
+            }
+
+            return Ok(response.data);
+        }
+    }
+}
+
+/// Splits `length` bytes in consecutive ranges of at most `block_size`
+/// bytes.
+// A buffered stream of zero futures never polls any of them, so the
+// download would never complete.
+fn check_max_concurrency(max_concurrency: usize) -> Result<(), AzureError> {
+    if max_concurrency == 0 {
+        return Err(AzureError::InputParametersError(
+            "the maximum concurrency must be greater than zero".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+fn split_in_ranges(
+    length: u64,
+    block_size: u64,
+) -> Result<impl Iterator<Item = Range>, AzureError> {
+    if block_size == 0 {
+        return Err(AzureError::InputParametersError(
+            "the block size must be greater than zero".to_owned(),
+        ));
+    }
+
+    Ok((0..length)
+        .step_by(block_size as usize)
+        .map(move |start| Range::new(start, std::cmp::min(start + block_size, length) - 1)))
+}
+
+#[cfg(unix)]
+fn write_at(file: &File, buf: &[u8], offset: u64) -> std::io::Result<()> {
+    use std::os::unix::fs::FileExt;
+    file.write_all_at(buf, offset)
+}
+
+#[cfg(windows)]
+fn write_at(file: &File, buf: &[u8], offset: u64) -> std::io::Result<()> {
+    use std::os::windows::fs::FileExt;
+    let mut written = 0;
+    while written < buf.len() {
+        written += file.seek_write(&buf[written..], offset + written as u64)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn split_in_ranges_covers_the_blob() {
+        let ranges = split_in_ranges(2600, 1024).unwrap().collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            vec![
+                Range::new(0, 1023),
+                Range::new(1024, 2047),
+                Range::new(2048, 2599)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_in_ranges_exact_multiple() {
+        let ranges = split_in_ranges(2048, 1024).unwrap().collect::<Vec<_>>();
+        assert_eq!(ranges, vec![Range::new(0, 1023), Range::new(1024, 2047)]);
+        assert_eq!(split_in_ranges(0, 1024).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn split_in_ranges_rejects_empty_blocks() {
+        assert!(split_in_ranges(2048, 0).is_err());
+    }
+
+    #[test]
+    fn check_max_concurrency_rejects_zero() {
+        assert!(check_max_concurrency(4).is_ok());
+        assert!(check_max_concurrency(0).is_err());
+    }
+}
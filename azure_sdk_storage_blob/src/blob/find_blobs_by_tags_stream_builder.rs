@@ -0,0 +1,219 @@
+use crate::blob::requests::FindBlobsByTagsBuilder;
+use crate::blob::{TagFilterRequired, TagFilterSupport, TaggedBlob};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, MaxResultsOption, MaxResultsSupport,
+    NextMarkerSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use futures::stream::Stream;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    client: &'a Client,
+    p_tag_filter: PhantomData<TagFilterSet>,
+    tag_filter: Option<&'a str>,
+    max_results: Option<u32>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> FindBlobsByTagsStreamBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> FindBlobsByTagsStreamBuilder<'a, No> {
+        FindBlobsByTagsStreamBuilder {
+            client,
+            p_tag_filter: PhantomData {},
+            tag_filter: None,
+            max_results: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> ClientRequired<'a> for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> TagFilterRequired<'a> for FindBlobsByTagsStreamBuilder<'a, Yes> {
+    #[inline]
+    fn tag_filter(&self) -> &'a str {
+        self.tag_filter.unwrap()
+    }
+}
+
+impl<'a, TagFilterSet> MaxResultsOption for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn max_results(&self) -> Option<u32> {
+        self.max_results
+    }
+}
+
+impl<'a, TagFilterSet> TimeoutOption for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, TagFilterSet> ClientRequestIdOption<'a> for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, TagFilterSet> TagFilterSupport<'a> for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsStreamBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_tag_filter(self, tag_filter: &'a str) -> Self::O {
+        FindBlobsByTagsStreamBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: Some(tag_filter),
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> MaxResultsSupport for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsStreamBuilder<'a, TagFilterSet>;
+
+    #[inline]
+    fn with_max_results(self, max_results: u32) -> Self::O {
+        FindBlobsByTagsStreamBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            max_results: Some(max_results),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> TimeoutSupport for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsStreamBuilder<'a, TagFilterSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        FindBlobsByTagsStreamBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            max_results: self.max_results,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> ClientRequestIdSupport<'a> for FindBlobsByTagsStreamBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsStreamBuilder<'a, TagFilterSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        FindBlobsByTagsStreamBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, TagFilterSet> FindBlobsByTagsStreamBuilder<'a, TagFilterSet> where TagFilterSet: ToAssign {}
+
+enum ContinuationState {
+    Start,
+    Next(Option<String>),
+}
+
+impl<'a> FindBlobsByTagsStreamBuilder<'a, Yes> {
+    #[inline]
+    pub fn finalize(self) -> impl Stream<Item = Result<Vec<TaggedBlob>, AzureError>> + 'a {
+        let client = self.client().clone();
+        let tag_filter = self.tag_filter().to_owned();
+        let max_results = self.max_results();
+        let timeout = self.timeout();
+        let client_request_id = self.client_request_id().map(|v| v.to_owned());
+
+        futures::stream::unfold(Some(ContinuationState::Start), move |cont_token| {
+            let client = client.clone();
+            let tag_filter = tag_filter.clone();
+            let client_request_id = client_request_id.clone();
+
+            async move {
+                let marker = match cont_token {
+                    Some(ContinuationState::Start) => None,
+                    Some(ContinuationState::Next(Some(marker))) => Some(marker),
+                    Some(ContinuationState::Next(None)) => return None,
+                    None => return None,
+                };
+
+                let mut req = FindBlobsByTagsBuilder::new(&client).with_tag_filter(&tag_filter);
+
+                if let Some(ref marker) = &marker {
+                    req = req.with_next_marker(marker);
+                }
+                if let Some(max_results) = max_results {
+                    req = req.with_max_results(max_results);
+                }
+                if let Some(timeout) = timeout {
+                    req = req.with_timeout(timeout);
+                }
+                if let Some(ref client_request_id) = &client_request_id {
+                    req = req.with_client_request_id(client_request_id);
+                }
+
+                let response = match req.finalize().await {
+                    Ok(response) => response,
+                    Err(err) => return Some((Err(err), None)),
+                };
+                let IncompleteVector { token, vector } = response.incomplete_vector;
+
+                Some((Ok(vector), Some(ContinuationState::Next(token))))
+            }
+        })
+    }
+}
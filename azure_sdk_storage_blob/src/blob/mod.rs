@@ -14,20 +14,46 @@ mod block_with_size_list;
 pub use self::block_with_size_list::BlockWithSizeList;
 mod block_list;
 pub use self::block_list::BlockList;
+mod blob_batch;
+pub use self::blob_batch::{BlobBatch, BlobBatchOperation, MAX_BLOB_BATCH_OPERATIONS};
+mod page_range_list;
+pub use self::page_range_list::PageRangeList;
+mod blob_tags;
+pub(crate) use self::blob_tags::{tagged_blobs_from_response, tags_from_xml, tags_to_xml};
+pub use self::blob_tags::TaggedBlob;
 mod shared_access;
 pub use self::shared_access::SignedUrlBuilder;
+mod upload_blob_builder;
+pub use self::upload_blob_builder::UploadBlobBuilder;
+mod download_blob_builder;
+pub use self::download_blob_builder::DownloadBlobBuilder;
+mod wait_for_blob_copy_builder;
+pub use self::wait_for_blob_copy_builder::WaitForBlobCopyBuilder;
+mod restore_blob_from_snapshot_builder;
+pub use self::restore_blob_from_snapshot_builder::RestoreBlobFromSnapshotBuilder;
+mod move_old_blobs_to_tier_builder;
+pub use self::move_old_blobs_to_tier_builder::MoveOldBlobsToTierBuilder;
+mod recover_deleted_blobs_builder;
+pub use self::recover_deleted_blobs_builder::RecoverDeletedBlobsBuilder;
+mod find_blobs_by_tags_stream_builder;
+pub use self::find_blobs_by_tags_stream_builder::FindBlobsByTagsStreamBuilder;
 pub mod requests;
 pub mod responses;
 use azure_sdk_core::headers::{
-    BLOB_SEQUENCE_NUMBER, BLOB_TYPE, CONTENT_MD5, COPY_COMPLETION_TIME, COPY_ID, COPY_PROGRESS,
-    COPY_SOURCE, COPY_STATUS, COPY_STATUS_DESCRIPTION, CREATION_TIME, LEASE_DURATION, LEASE_STATE,
-    LEASE_STATUS, SERVER_ENCRYPTED,
+    ACCESS_TIER_CHANGE_TIME, ACCESS_TIER_INFERRED, ARCHIVE_STATUS, BLOB_ACCESS_TIER,
+    BLOB_CONTENT_ENCODING, BLOB_CONTENT_LANGUAGE, BLOB_CONTENT_MD5, BLOB_CONTENT_TYPE,
+    BLOB_SEQUENCE_NUMBER, BLOB_TYPE, CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_MD5,
+    COPY_COMPLETION_TIME, COPY_ID, COPY_PROGRESS, COPY_SOURCE, COPY_STATUS,
+    COPY_STATUS_DESCRIPTION, CREATION_TIME, LEASE_DURATION, LEASE_STATE, LEASE_STATUS, META_PREFIX,
+    REHYDRATE_PRIORITY, SEQUENCE_NUMBER_ACTION, SERVER_ENCRYPTED,
 };
 use azure_sdk_storage_core::ClientRequired;
 use chrono::{DateTime, Utc};
+use http::request::Builder;
 use hyper::header;
 use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::time::Duration;
 use std::{fmt, str::FromStr};
 use url::form_urlencoded;
 use xml::Element;
@@ -43,7 +69,9 @@ use azure_sdk_core::{
     },
     range::Range,
     util::HeaderMapExt,
-    BlobNameRequired, ContainerNameRequired,
+    AccessTier, BlobNameRequired, CacheControlOption, ContainerNameRequired,
+    ContentDispositionOption, ContentEncodingOption, ContentLanguageOption, ContentMD5Option,
+    ContentTypeOption,
 };
 
 pub trait BlockListTypeSupport {
@@ -59,6 +87,138 @@ pub trait BlockListTypeRequired {
     }
 }
 
+pub trait BlockSizeSupport {
+    type O;
+    fn with_block_size(self, block_size: u64) -> Self::O;
+}
+
+pub trait BlockSizeOption {
+    /// Size of the blocks the payload is split into (or fetched in), in bytes.
+    fn block_size(&self) -> u64;
+}
+
+pub trait MaxConcurrencySupport {
+    type O;
+    fn with_max_concurrency(self, max_concurrency: usize) -> Self::O;
+}
+
+pub trait MaxConcurrencyOption {
+    /// Maximum number of requests in flight at the same time.
+    fn max_concurrency(&self) -> usize;
+}
+
+pub trait MaxRetriesSupport {
+    type O;
+    fn with_max_retries(self, max_retries: u32) -> Self::O;
+}
+
+pub trait MaxRetriesOption {
+    /// Number of times a failed request is attempted again.
+    fn max_retries(&self) -> u32;
+}
+
+pub trait MaxPollsSupport {
+    type O;
+    fn with_max_polls(self, max_polls: u32) -> Self::O;
+}
+
+pub trait MaxPollsOption {
+    /// Number of times the service is polled before giving up.
+    fn max_polls(&self) -> u32;
+}
+
+pub trait PollIntervalSupport {
+    type O;
+    fn with_poll_interval(self, poll_interval: Duration) -> Self::O;
+}
+
+pub trait PollIntervalOption {
+    /// Time to wait between two polls of the service.
+    fn poll_interval(&self) -> Duration;
+}
+
+pub trait ValidateMD5Support {
+    type O;
+    fn with_validate_md5(self) -> Self::O;
+}
+
+pub trait ValidateMD5Option {
+    /// Whether the MD5 of every downloaded range is checked against the
+    /// one computed by the service.
+    fn validate_md5(&self) -> bool;
+}
+
+/// How Set Blob Properties changes the sequence number of a page blob.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SequenceNumberAction {
+    /// Sets the sequence number to the higher of the current one and the
+    /// given one.
+    Max(u64),
+    /// Sets the sequence number to the given one.
+    Update(u64),
+    /// Increments the sequence number by one.
+    Increment,
+}
+
+impl SequenceNumberAction {
+    fn add_header(&self, builder: &mut Builder) {
+        match self {
+            SequenceNumberAction::Max(sequence_number) => {
+                builder.header(SEQUENCE_NUMBER_ACTION, "max");
+                builder.header(BLOB_SEQUENCE_NUMBER, &sequence_number.to_string() as &str);
+            }
+            SequenceNumberAction::Update(sequence_number) => {
+                builder.header(SEQUENCE_NUMBER_ACTION, "update");
+                builder.header(BLOB_SEQUENCE_NUMBER, &sequence_number.to_string() as &str);
+            }
+            SequenceNumberAction::Increment => {
+                builder.header(SEQUENCE_NUMBER_ACTION, "increment");
+            }
+        };
+    }
+}
+
+pub trait RehydratePrioritySupport {
+    type O;
+    fn with_rehydrate_priority(self, rehydrate_priority: RehydratePriority) -> Self::O;
+}
+
+pub trait RehydratePriorityOption {
+    fn rehydrate_priority(&self) -> Option<RehydratePriority>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(rehydrate_priority) = self.rehydrate_priority() {
+            let s: &'static str = rehydrate_priority.into();
+            builder.header(REHYDRATE_PRIORITY, s);
+        }
+    }
+}
+
+pub trait OlderThanSupport {
+    type O;
+    fn with_older_than(self, older_than: chrono::Duration) -> Self::O;
+}
+
+pub trait OlderThanRequired {
+    /// Minimum time elapsed since the last modification of a blob.
+    fn older_than(&self) -> chrono::Duration;
+}
+
+pub trait SequenceNumberActionSupport {
+    type O;
+    fn with_sequence_number_action(self, sequence_number_action: SequenceNumberAction) -> Self::O;
+}
+
+pub trait SequenceNumberActionOption {
+    fn sequence_number_action(&self) -> Option<SequenceNumberAction>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(sequence_number_action) = self.sequence_number_action() {
+            sequence_number_action.add_header(builder);
+        }
+    }
+}
+
 pub trait BlockListSupport<'a, T>
 where
     T: Borrow<[u8]>,
@@ -78,6 +238,34 @@ where
     }
 }
 
+pub trait BlobBatchSupport<'a> {
+    type O;
+    fn with_blob_batch(self, _: &'a BlobBatch<'a>) -> Self::O;
+}
+
+pub trait BlobBatchRequired<'a> {
+    fn blob_batch(&self) -> &'a BlobBatch<'a>;
+}
+
+pub trait TagFilterSupport<'a> {
+    type O;
+    fn with_tag_filter(self, tag_filter: &'a str) -> Self::O;
+}
+
+/// The filter is a tag expression such as `"project" = 'rust' AND "year" > '2019'`.
+pub trait TagFilterRequired<'a> {
+    fn tag_filter(&self) -> &'a str;
+
+    fn to_uri_parameter(&self) -> String {
+        // spaces are percent encoded instead of turned into '+' as the
+        // expression usually contains many of them
+        let tag_filter = form_urlencoded::byte_serialize(self.tag_filter().as_bytes())
+            .collect::<String>()
+            .replace('+', "%20");
+        format!("where={}", tag_filter)
+    }
+}
+
 create_enum!(
     BlobType,
     (BlockBlob, "BlockBlob"),
@@ -95,11 +283,21 @@ create_enum!(
 
 create_enum!(PageWriteType, (Update, "update"), (Clear, "clear"));
 
+create_enum!(
+    ArchiveStatus,
+    (RehydratePendingToHot, "rehydrate-pending-to-hot"),
+    (RehydratePendingToCool, "rehydrate-pending-to-cool")
+);
+
+create_enum!(RehydratePriority, (Standard, "Standard"), (High, "High"));
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Blob {
     pub name: String,
     pub container_name: String,
     pub snapshot_time: Option<DateTime<Utc>>,
+    /// Only listings that include deleted blobs can return `true`.
+    pub deleted: bool,
     pub creation_time: DateTime<Utc>,
     pub last_modified: Option<DateTime<Utc>>, // optional because unavailable in uncommitted blobs
     pub etag: Option<String>,                 // optional because unavailable in uncommitted blobs
@@ -112,7 +310,7 @@ pub struct Blob {
     pub content_disposition: Option<String>,
     pub x_ms_blob_sequence_number: Option<u64>,
     pub blob_type: BlobType,
-    pub access_tier: Option<String>,
+    pub access_tier: Option<AccessTier>,
     pub lease_status: Option<LeaseStatus>,
     pub lease_state: LeaseState,
     pub lease_duration: Option<LeaseDuration>,
@@ -126,6 +324,8 @@ pub struct Blob {
     pub server_encrypted: bool,
     pub access_tier_inferred: Option<bool>,
     pub access_tier_change_time: Option<DateTime<Utc>>,
+    /// Set while the blob is being rehydrated out of the archive tier.
+    pub archive_status: Option<ArchiveStatus>,
     pub deleted_time: Option<DateTime<Utc>>,
     pub remaining_retention_days: Option<u64>,
     pub metadata: HashMap<String, String>,
@@ -135,6 +335,7 @@ impl Blob {
     pub(crate) fn parse(elem: &Element, container_name: &str) -> Result<Blob, AzureError> {
         let name = cast_must::<String>(elem, &["Name"])?;
         let snapshot_time = cast_optional::<DateTime<Utc>>(elem, &["Snapshot"])?;
+        let deleted = cast_optional::<bool>(elem, &["Deleted"])?.unwrap_or(false);
         let creation_time = cast_must::<DateTime<Utc>>(elem, &["Properties", "Creation-Time"])?;
         let last_modified = cast_optional::<DateTime<Utc>>(elem, &["Properties", "Last-Modified"])?;
         let etag = cast_optional::<String>(elem, &["Properties", "Etag"])?;
@@ -152,7 +353,11 @@ impl Blob {
             cast_optional::<u64>(elem, &["Properties", "x-ms-blob-sequence-number"])?;
 
         let blob_type = cast_must::<BlobType>(elem, &["Properties", "BlobType"])?;
-        let access_tier = cast_optional::<String>(elem, &["Properties", "AccessTier"])?;
+        // New tiers and rehydration targets keep being added by the service:
+        // values we do not know about are reported as None rather than
+        // failing the whole listing.
+        let access_tier = cast_optional::<String>(elem, &["Properties", "AccessTier"])?
+            .and_then(|at| at.parse::<AccessTier>().ok());
 
         let lease_status = cast_optional::<LeaseStatus>(elem, &["Properties", "LeaseStatus"])?;
         let lease_state = cast_must::<LeaseState>(elem, &["Properties", "LeaseState"])?;
@@ -180,6 +385,8 @@ impl Blob {
 
         let access_tier_change_time =
             cast_optional::<DateTime<Utc>>(elem, &["Properties", "AccessTierChangeTime"])?;
+        let archive_status = cast_optional::<String>(elem, &["Properties", "ArchiveStatus"])?
+            .and_then(|ars| ars.parse::<ArchiveStatus>().ok());
         let deleted_time = cast_optional::<DateTime<Utc>>(elem, &["Properties", "DeletedTime"])?;
         let remaining_retention_days =
             cast_optional::<u64>(elem, &["Properties", "RemainingRetentionDays"])?;
@@ -218,6 +425,7 @@ impl Blob {
             name,
             container_name: container_name.to_owned(),
             snapshot_time,
+            deleted,
             creation_time,
             last_modified,
             etag,
@@ -244,6 +452,7 @@ impl Blob {
             server_encrypted,
             access_tier_inferred,
             access_tier_change_time,
+            archive_status,
             deleted_time,
             remaining_retention_days,
             metadata,
@@ -359,15 +568,51 @@ impl Blob {
         let copy_status_description = h.get_as_string(COPY_STATUS_DESCRIPTION);
         trace!("copy_status_description == {:?}", copy_status_description);
 
+        // Unknown tiers are reported as None, see Blob::parse.
+        let access_tier = h
+            .get_as_str(BLOB_ACCESS_TIER)
+            .and_then(|at| at.parse::<AccessTier>().ok());
+        trace!("access_tier == {:?}", access_tier);
+
+        let access_tier_inferred = h
+            .get_as_str(ACCESS_TIER_INFERRED)
+            .map(|ati| ati.parse::<bool>())
+            .transpose()?;
+        trace!("access_tier_inferred == {:?}", access_tier_inferred);
+
+        let access_tier_change_time = h
+            .get_as_str(ACCESS_TIER_CHANGE_TIME)
+            .map(DateTime::parse_from_rfc2822)
+            .transpose()?
+            .map(|atct| DateTime::from_utc(atct.naive_utc(), Utc));
+        trace!("access_tier_change_time == {:?}", access_tier_change_time);
+
+        let archive_status = h
+            .get_as_str(ARCHIVE_STATUS)
+            .and_then(|ars| ars.parse::<ArchiveStatus>().ok());
+        trace!("archive_status == {:?}", archive_status);
+
         let server_encrypted = h
             .get_as_str(SERVER_ENCRYPTED)
             .ok_or_else(|| AzureError::HeaderNotFound(SERVER_ENCRYPTED.to_owned()))?
             .parse::<bool>()?;
 
+        let mut metadata = HashMap::new();
+        for (key, value) in h {
+            if key.as_str().starts_with(META_PREFIX) {
+                metadata.insert(
+                    key.as_str()[META_PREFIX.len()..].to_owned(),
+                    value.to_str()?.to_owned(),
+                );
+            }
+        }
+        trace!("metadata == {:?}", metadata);
+
         Ok(Blob {
             name: blob_name.to_owned(),
             container_name: container_name.to_owned(),
             snapshot_time,
+            deleted: false,
             creation_time,
             last_modified: Some(last_modified),
             etag: Some(etag),
@@ -380,7 +625,7 @@ impl Blob {
             content_disposition,
             x_ms_blob_sequence_number,
             blob_type,
-            access_tier: None,
+            access_tier,
             lease_status,
             lease_state,
             lease_duration,
@@ -392,11 +637,12 @@ impl Blob {
             copy_status_description,
             incremental_copy: None, // TODO: Not present or documentation bug?
             server_encrypted,
-            access_tier_inferred: None,     // TODO: Not present
-            access_tier_change_time: None,  // TODO: Not present
+            access_tier_inferred,
+            access_tier_change_time,
+            archive_status,
             deleted_time: None,             // TODO
             remaining_retention_days: None, // TODO: Not present or documentation bug?
-            metadata: HashMap::new(),       // TODO: Not present or documentation bug?
+            metadata,
         })
     }
 }
@@ -429,6 +675,42 @@ pub(crate) fn incomplete_vector_from_response(
 }
 
 #[inline]
+// Set Blob Properties expects the HTTP properties under x-ms-blob- prefixed
+// headers so the add_header of the core traits cannot be used.
+pub(crate) fn add_blob_content_headers<'a, T>(t: &T, builder: &mut Builder)
+where
+    T: ContentTypeOption<'a>
+        + ContentEncodingOption<'a>
+        + ContentLanguageOption<'a>
+        + ContentMD5Option<'a>
+        + CacheControlOption<'a>
+        + ContentDispositionOption<'a>,
+{
+    if let Some(content_type) = t.content_type() {
+        builder.header(BLOB_CONTENT_TYPE, content_type);
+    }
+    if let Some(content_encoding) = t.content_encoding() {
+        builder.header(BLOB_CONTENT_ENCODING, content_encoding);
+    }
+    if let Some(content_language) = t.content_language() {
+        builder.header(BLOB_CONTENT_LANGUAGE, content_language);
+    }
+    if let Some(content_md5) = t.content_md5() {
+        builder.header(BLOB_CONTENT_MD5, &base64::encode(content_md5) as &str);
+    }
+    if let Some(cache_control) = t.cache_control() {
+        builder.header(CACHE_CONTROL, cache_control);
+    }
+    if let Some(content_disposition) = t.content_disposition() {
+        builder.header(CONTENT_DISPOSITION, content_disposition);
+    }
+}
+
+// Service version needed by the operations introduced after the version
+// perform_request sends by default.
+pub(crate) const VERSION_2018_11_09: &str = "2018-11-09";
+pub(crate) const VERSION_2019_12_12: &str = "2019-12-12";
+
 pub(crate) fn generate_blob_uri<'a, T>(t: &T, params: Option<&str>) -> String
 where
     T: ClientRequired<'a> + ContainerNameRequired<'a> + BlobNameRequired<'a>,
@@ -449,3 +731,61 @@ where
         ),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_unknown_access_tier() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <EnumerationResults>
+                <Blobs>
+                    <Blob>
+                        <Name>cold.txt</Name>
+                        <Properties>
+                            <Creation-Time>Mon, 12 Oct 2026 10:00:00 GMT</Creation-Time>
+                            <Content-Length>0</Content-Length>
+                            <Content-Type>text/plain</Content-Type>
+                            <BlobType>BlockBlob</BlobType>
+                            <AccessTier>Cold</AccessTier>
+                            <ArchiveStatus>rehydrate-pending-to-cold</ArchiveStatus>
+                            <LeaseState>available</LeaseState>
+                            <ServerEncrypted>true</ServerEncrypted>
+                        </Properties>
+                    </Blob>
+                </Blobs>
+                <NextMarker />
+            </EnumerationResults>";
+
+        let blobs = incomplete_vector_from_response(body, "container").unwrap();
+        let blob = &blobs[0];
+        assert_eq!(blob.name, "cold.txt");
+        assert_eq!(blob.access_tier, None);
+        assert_eq!(blob.archive_status, None);
+    }
+
+    #[test]
+    fn from_headers_unknown_access_tier() {
+        let date = "Mon, 12 Oct 2026 10:00:00 GMT";
+        let mut h = header::HeaderMap::new();
+        h.insert(CREATION_TIME, date.parse().unwrap());
+        h.insert(header::CONTENT_LENGTH, "0".parse().unwrap());
+        h.insert(header::LAST_MODIFIED, date.parse().unwrap());
+        h.insert(header::ETAG, "\"0x8D86E9A2C7D1A5B\"".parse().unwrap());
+        h.insert(BLOB_TYPE, "BlockBlob".parse().unwrap());
+        h.insert(LEASE_STATUS, "unlocked".parse().unwrap());
+        h.insert(LEASE_STATE, "available".parse().unwrap());
+        h.insert(SERVER_ENCRYPTED, "true".parse().unwrap());
+        h.insert(BLOB_ACCESS_TIER, "Cold".parse().unwrap());
+        h.insert(ARCHIVE_STATUS, "rehydrate-pending-to-cold".parse().unwrap());
+
+        let blob = Blob::from_headers("cold.txt", "container", None, &h).unwrap();
+        assert_eq!(blob.access_tier, None);
+        assert_eq!(blob.archive_status, None);
+
+        h.insert(BLOB_ACCESS_TIER, "Cool".parse().unwrap());
+        let blob = Blob::from_headers("cold.txt", "container", None, &h).unwrap();
+        assert_eq!(blob.access_tier, Some(AccessTier::Cool));
+    }
+}
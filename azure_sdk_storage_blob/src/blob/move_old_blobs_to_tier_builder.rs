@@ -0,0 +1,376 @@
+use crate::blob::requests::SetBlobTierBuilder;
+use crate::blob::{Blob, BlobType, ListBlobStreamBuilder, OlderThanRequired, OlderThanSupport};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    AccessTier, AccessTierRequired, AccessTierSupport, BlobNameSupport, ClientRequestIdOption,
+    ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport, No, PrefixOption,
+    PrefixSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::StreamExt;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_access_tier: PhantomData<AccessTierSet>,
+    p_older_than: PhantomData<OlderThanSet>,
+    container_name: Option<&'a str>,
+    access_tier: Option<AccessTier>,
+    older_than: Option<Duration>,
+    prefix: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> MoveOldBlobsToTierBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> MoveOldBlobsToTierBuilder<'a, No, No, No> {
+        MoveOldBlobsToTierBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_access_tier: PhantomData {},
+            access_tier: None,
+            p_older_than: PhantomData {},
+            older_than: None,
+            prefix: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> ClientRequired<'a>
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, AccessTierSet, OlderThanSet> ContainerNameRequired<'a>
+    for MoveOldBlobsToTierBuilder<'a, Yes, AccessTierSet, OlderThanSet>
+where
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, OlderThanSet> AccessTierRequired
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, Yes, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    #[inline]
+    fn access_tier(&self) -> AccessTier {
+        self.access_tier.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet> OlderThanRequired
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn older_than(&self) -> Duration {
+        self.older_than.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> PrefixOption<'a>
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    #[inline]
+    fn prefix(&self) -> Option<&'a str> {
+        self.prefix
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> TimeoutOption
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> ClientRequestIdOption<'a>
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> ContainerNameSupport<'a>
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    type O = MoveOldBlobsToTierBuilder<'a, Yes, AccessTierSet, OlderThanSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        MoveOldBlobsToTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            p_older_than: PhantomData {},
+            container_name: Some(container_name),
+            access_tier: self.access_tier,
+            older_than: self.older_than,
+            prefix: self.prefix,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> AccessTierSupport
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    type O = MoveOldBlobsToTierBuilder<'a, ContainerNameSet, Yes, OlderThanSet>;
+
+    #[inline]
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
+        MoveOldBlobsToTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            p_older_than: PhantomData {},
+            container_name: self.container_name,
+            access_tier: Some(access_tier),
+            older_than: self.older_than,
+            prefix: self.prefix,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> OlderThanSupport
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    type O = MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, Yes>;
+
+    #[inline]
+    fn with_older_than(self, older_than: Duration) -> Self::O {
+        MoveOldBlobsToTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            p_older_than: PhantomData {},
+            container_name: self.container_name,
+            access_tier: self.access_tier,
+            older_than: Some(older_than),
+            prefix: self.prefix,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> PrefixSupport<'a>
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    type O = MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>;
+
+    #[inline]
+    fn with_prefix(self, prefix: &'a str) -> Self::O {
+        MoveOldBlobsToTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            p_older_than: PhantomData {},
+            container_name: self.container_name,
+            access_tier: self.access_tier,
+            older_than: self.older_than,
+            prefix: Some(prefix),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> TimeoutSupport
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    type O = MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        MoveOldBlobsToTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            p_older_than: PhantomData {},
+            container_name: self.container_name,
+            access_tier: self.access_tier,
+            older_than: self.older_than,
+            prefix: self.prefix,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet> ClientRequestIdSupport<'a>
+    for MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+    type O = MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        MoveOldBlobsToTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            p_older_than: PhantomData {},
+            container_name: self.container_name,
+            access_tier: self.access_tier,
+            older_than: self.older_than,
+            prefix: self.prefix,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+    MoveOldBlobsToTierBuilder<'a, ContainerNameSet, AccessTierSet, OlderThanSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    OlderThanSet: ToAssign,
+{
+}
+
+impl<'a> MoveOldBlobsToTierBuilder<'a, Yes, Yes, Yes> {
+    /// Lists the block blobs of the container (only those starting with
+    /// `prefix`, if specified) and moves to `access_tier` the ones not
+    /// modified for `older_than`. Blobs already in `access_tier` or in a
+    /// colder tier are left alone, so archived blobs are never rehydrated.
+    /// Returns the blobs whose tier was changed, as they were listed.
+    pub async fn finalize(self) -> Result<Vec<Blob>, AzureError> {
+        let access_tier = self.access_tier();
+        if access_tier != AccessTier::Cool && access_tier != AccessTier::Archive {
+            return Err(AzureError::InputParametersError(format!(
+                "blobs can only be moved to the Cool or Archive tier, not {}",
+                access_tier
+            )));
+        }
+
+        let threshold = Utc::now() - self.older_than();
+
+        let mut request =
+            ListBlobStreamBuilder::new(self.client()).with_container_name(self.container_name());
+        if let Some(prefix) = self.prefix() {
+            request = request.with_prefix(prefix);
+        }
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+        let mut pages = Box::pin(request.finalize());
+
+        let mut moved = Vec::new();
+        while let Some(page) = pages.next().await {
+            for blob in page? {
+                if !must_move(&blob, access_tier, threshold) {
+                    continue;
+                }
+
+                let mut request = SetBlobTierBuilder::new(self.client())
+                    .with_container_name(self.container_name())
+                    .with_blob_name(&blob.name)
+                    .with_access_tier(access_tier);
+                if let Some(timeout) = self.timeout() {
+                    request = request.with_timeout(timeout);
+                }
+                if let Some(client_request_id) = self.client_request_id() {
+                    request = request.with_client_request_id(client_request_id);
+                }
+                request.finalize().await?;
+
+                moved.push(blob);
+            }
+        }
+
+        Ok(moved)
+    }
+}
+
+fn must_move(blob: &Blob, access_tier: AccessTier, threshold: DateTime<Utc>) -> bool {
+    if blob.blob_type != BlobType::BlockBlob {
+        return false;
+    }
+    match blob.last_modified {
+        Some(last_modified) if last_modified < threshold => {}
+        _ => return false,
+    }
+    match blob.access_tier {
+        Some(AccessTier::Archive) => false,
+        Some(current) => current != access_tier,
+        None => true,
+    }
+}
@@ -0,0 +1,70 @@
+use azure_sdk_core::ba512_range::BA512Range;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, traverse};
+use xml::Element;
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PageRangeList {
+    /// Ranges holding written pages.
+    pub page_ranges: Vec<BA512Range>,
+    /// Ranges cleared since the previous snapshot. Always empty unless the
+    /// list was requested with `prevsnapshot`.
+    pub clear_ranges: Vec<BA512Range>,
+}
+
+impl PageRangeList {
+    pub fn try_from(xml: &str) -> Result<PageRangeList, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+        Ok(PageRangeList {
+            page_ranges: parse_ranges(&elem, "PageRange")?,
+            clear_ranges: parse_ranges(&elem, "ClearRange")?,
+        })
+    }
+}
+
+fn parse_ranges(elem: &Element, name: &str) -> Result<Vec<BA512Range>, AzureError> {
+    let mut ranges = Vec::new();
+    for node in traverse(elem, &[name], true)? {
+        let start = cast_must::<u64>(node, &["Start"])?;
+        let end = cast_must::<u64>(node, &["End"])?;
+        let range = BA512Range::new(start, end)
+            .map_err(|_| AzureError::PageNot512ByteAlignedError(start, end))?;
+        ranges.push(range);
+    }
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn try_parse() {
+        let xml = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <PageList>
+                <PageRange><Start>0</Start><End>511</End></PageRange>
+                <ClearRange><Start>512</Start><End>1023</End></ClearRange>
+                <PageRange><Start>1024</Start><End>2047</End></PageRange>
+            </PageList>";
+
+        let list = PageRangeList::try_from(xml).unwrap();
+        assert_eq!(
+            list.page_ranges,
+            vec![
+                BA512Range::new(0, 511).unwrap(),
+                BA512Range::new(1024, 2047).unwrap()
+            ]
+        );
+        assert_eq!(list.clear_ranges, vec![BA512Range::new(512, 1023).unwrap()]);
+    }
+
+    #[test]
+    fn try_parse_empty() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?><PageList />";
+
+        let list = PageRangeList::try_from(xml).unwrap();
+        assert!(list.page_ranges.is_empty());
+        assert!(list.clear_ranges.is_empty());
+    }
+}
@@ -0,0 +1,217 @@
+use crate::blob::requests::UndeleteBlobBuilder;
+use crate::blob::{Blob, ListBlobStreamBuilder};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired,
+    ContainerNameSupport, IncludeDeletedSupport, No, PrefixOption, PrefixSupport, TimeoutOption,
+    TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use futures::stream::StreamExt;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    container_name: Option<&'a str>,
+    prefix: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> RecoverDeletedBlobsBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> RecoverDeletedBlobsBuilder<'a, No> {
+        RecoverDeletedBlobsBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            prefix: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequired<'a> for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ContainerNameRequired<'a> for RecoverDeletedBlobsBuilder<'a, Yes> {
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> PrefixOption<'a> for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn prefix(&self) -> Option<&'a str> {
+        self.prefix
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutOption for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdOption<'a>
+    for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet> ContainerNameSupport<'a>
+    for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = RecoverDeletedBlobsBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        RecoverDeletedBlobsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: Some(container_name),
+            prefix: self.prefix,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> PrefixSupport<'a> for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = RecoverDeletedBlobsBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_prefix(self, prefix: &'a str) -> Self::O {
+        RecoverDeletedBlobsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            prefix: Some(prefix),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutSupport for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = RecoverDeletedBlobsBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        RecoverDeletedBlobsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            prefix: self.prefix,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdSupport<'a>
+    for RecoverDeletedBlobsBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = RecoverDeletedBlobsBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        RecoverDeletedBlobsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            prefix: self.prefix,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet> RecoverDeletedBlobsBuilder<'a, ContainerNameSet> where
+    ContainerNameSet: ToAssign
+{
+}
+
+impl<'a> RecoverDeletedBlobsBuilder<'a, Yes> {
+    /// Lists the container including soft-deleted blobs (only those
+    /// starting with `prefix`, if specified) and undeletes every deleted
+    /// one. The soft-deleted snapshots of a blob are restored with it.
+    /// Returns the recovered blobs, as they were listed.
+    pub async fn finalize(self) -> Result<Vec<Blob>, AzureError> {
+        let mut request = ListBlobStreamBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_include_deleted();
+        if let Some(prefix) = self.prefix() {
+            request = request.with_prefix(prefix);
+        }
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+        let mut pages = Box::pin(request.finalize());
+
+        let mut recovered = Vec::new();
+        while let Some(page) = pages.next().await {
+            for blob in page? {
+                if !blob.deleted {
+                    continue;
+                }
+
+                let mut request = UndeleteBlobBuilder::new(self.client())
+                    .with_container_name(self.container_name())
+                    .with_blob_name(&blob.name);
+                if let Some(timeout) = self.timeout() {
+                    request = request.with_timeout(timeout);
+                }
+                if let Some(client_request_id) = self.client_request_id() {
+                    request = request.with_client_request_id(client_request_id);
+                }
+                request.finalize().await?;
+
+                recovered.push(blob);
+            }
+        }
+
+        Ok(recovered)
+    }
+}
@@ -0,0 +1,334 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::responses::AbortCopyBlobResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::COPY_ACTION;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, CopyIdRequired, CopyIdSupport, LeaseIdOption,
+    LeaseIdSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_copy_id: PhantomData<CopyIdSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    copy_id: Option<&'a str>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> AbortCopyBlobBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> AbortCopyBlobBuilder<'a, No, No, No> {
+        AbortCopyBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_copy_id: PhantomData {},
+            copy_id: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> ClientRequired<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, CopyIdSet> ContainerNameRequired<'a>
+    for AbortCopyBlobBuilder<'a, Yes, BlobNameSet, CopyIdSet>
+where
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, CopyIdSet> BlobNameRequired<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, Yes, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> CopyIdRequired<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn copy_id(&self) -> &'a str {
+        self.copy_id.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> LeaseIdOption<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> TimeoutOption
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> ClientRequestIdOption<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> ContainerNameSupport<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    type O = AbortCopyBlobBuilder<'a, Yes, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> BlobNameSupport<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    type O = AbortCopyBlobBuilder<'a, ContainerNameSet, Yes, CopyIdSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            copy_id: self.copy_id,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> CopyIdSupport<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    type O = AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_copy_id(self, copy_id: &'a str) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: Some(copy_id),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> LeaseIdSupport<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    type O = AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> TimeoutSupport
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    type O = AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet> ClientRequestIdSupport<'a>
+    for AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+    type O = AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+    AbortCopyBlobBuilder<'a, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+{
+}
+
+impl<'a> AbortCopyBlobBuilder<'a, Yes, Yes, Yes> {
+    pub async fn finalize(self) -> Result<AbortCopyBlobResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, Some("comp=copy"));
+        uri = format!("{}&{}", uri, CopyIdRequired::to_uri_parameter(&self));
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                request.header_static(COPY_ACTION, "abort");
+                LeaseIdOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        AbortCopyBlobResponse::from_headers(&headers)
+    }
+}
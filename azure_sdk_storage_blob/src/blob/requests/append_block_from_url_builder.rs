@@ -0,0 +1,735 @@
+use crate::blob::responses::PutBlockResponse;
+use crate::blob::{generate_blob_uri, VERSION_2018_11_09};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::modify_conditions::{IfMatchCondition, IfSinceCondition};
+use azure_sdk_core::range::Range;
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    AppendPositionOption, AppendPositionSupport, BlobNameRequired, BlobNameSupport,
+    ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
+    CopySourceRequired, CopySourceSupport, IfMatchConditionOption, IfMatchConditionSupport,
+    IfSinceConditionOption, IfSinceConditionSupport, LeaseIdOption, LeaseIdSupport, No,
+    SourceContentMD5Option, SourceContentMD5Support, SourceIfMatchConditionOption,
+    SourceIfMatchConditionSupport, SourceIfSinceConditionOption, SourceIfSinceConditionSupport,
+    SourceRangeOption, SourceRangeSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_copy_source: PhantomData<CopySourceSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    copy_source: Option<&'a str>,
+    source_range: Option<&'a Range>,
+    source_content_md5: Option<&'a [u8]>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    source_if_since_condition: Option<IfSinceCondition>,
+    source_if_match_condition: Option<IfMatchCondition<'a>>,
+    append_position: Option<u32>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> AppendBlockFromUrlBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> AppendBlockFromUrlBuilder<'a, No, No, No> {
+        AppendBlockFromUrlBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_copy_source: PhantomData {},
+            copy_source: None,
+            source_range: None,
+            source_content_md5: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            source_if_since_condition: None,
+            source_if_match_condition: None,
+            append_position: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ClientRequired<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, CopySourceSet> ContainerNameRequired<'a>
+    for AppendBlockFromUrlBuilder<'a, Yes, BlobNameSet, CopySourceSet>
+where
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, CopySourceSet> BlobNameRequired<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, Yes, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> CopySourceRequired<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn copy_source(&self) -> &'a str {
+        self.copy_source.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceRangeOption<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn source_range(&self) -> Option<&'a Range> {
+        self.source_range
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceContentMD5Option<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn source_content_md5(&self) -> Option<&'a [u8]> {
+        self.source_content_md5
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfSinceConditionOption
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfMatchConditionOption<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfSinceConditionOption
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn source_if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.source_if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfMatchConditionOption<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn source_if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.source_if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> AppendPositionOption
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn append_position(&self) -> Option<u32> {
+        self.append_position
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> LeaseIdOption<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> TimeoutOption
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ClientRequestIdOption<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ContainerNameSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, Yes, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> BlobNameSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, Yes, CopySourceSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> CopySourceSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_copy_source(self, copy_source: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: Some(copy_source),
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceRangeSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_source_range(self, source_range: &'a Range) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: Some(source_range),
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceContentMD5Support<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_source_content_md5(self, source_content_md5: &'a [u8]) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: Some(source_content_md5),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfSinceConditionSupport
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfMatchConditionSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfSinceConditionSupport
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_source_if_since_condition(
+        self,
+        source_if_since_condition: IfSinceCondition,
+    ) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: Some(source_if_since_condition),
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfMatchConditionSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_source_if_match_condition(
+        self,
+        source_if_match_condition: IfMatchCondition<'a>,
+    ) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: Some(source_if_match_condition),
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> AppendPositionSupport
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_append_position(self, append_position: u32) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: Some(append_position),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> LeaseIdSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> TimeoutSupport
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ClientRequestIdSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            append_position: self.append_position,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+    AppendBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+}
+
+impl<'a> AppendBlockFromUrlBuilder<'a, Yes, Yes, Yes> {
+    /// Appends to the append blob a block read by the service from
+    /// `copy_source` (only `source_range` of it, if specified). The source
+    /// must be readable with the URL alone, i.e. public or carrying a SAS
+    /// token, and the block cannot exceed 4 MiB.
+    pub async fn finalize(self) -> Result<PutBlockResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, Some("comp=appendblock"));
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                request.header_static(VERSION, VERSION_2018_11_09);
+                CopySourceRequired::add_header(&self, request);
+                SourceRangeOption::add_header(&self, request);
+                SourceContentMD5Option::add_header(&self, request);
+                IfSinceConditionOption::add_header(&self, request);
+                IfMatchConditionOption::add_header(&self, request);
+                SourceIfSinceConditionOption::add_header(&self, request);
+                SourceIfMatchConditionOption::add_header(&self, request);
+                AppendPositionOption::add_header(&self, request);
+                LeaseIdOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        PutBlockResponse::from_headers(&headers)
+    }
+}
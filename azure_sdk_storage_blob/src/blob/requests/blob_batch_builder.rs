@@ -0,0 +1,169 @@
+use crate::blob::responses::BlobBatchResponse;
+use crate::blob::{BlobBatch, BlobBatchRequired, BlobBatchSupport, VERSION_2018_11_09};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::header::CONTENT_TYPE;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct BlobBatchBuilder<'a, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+{
+    client: &'a Client,
+    p_blob_batch: PhantomData<BlobBatchSet>,
+    blob_batch: Option<&'a BlobBatch<'a>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> BlobBatchBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> BlobBatchBuilder<'a, No> {
+        BlobBatchBuilder {
+            client,
+            p_blob_batch: PhantomData {},
+            blob_batch: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, BlobBatchSet> ClientRequired<'a> for BlobBatchBuilder<'a, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> BlobBatchRequired<'a> for BlobBatchBuilder<'a, Yes> {
+    #[inline]
+    fn blob_batch(&self) -> &'a BlobBatch<'a> {
+        self.blob_batch.unwrap()
+    }
+}
+
+impl<'a, BlobBatchSet> TimeoutOption for BlobBatchBuilder<'a, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, BlobBatchSet> ClientRequestIdOption<'a> for BlobBatchBuilder<'a, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, BlobBatchSet> BlobBatchSupport<'a> for BlobBatchBuilder<'a, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+{
+    type O = BlobBatchBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_blob_batch(self, blob_batch: &'a BlobBatch<'a>) -> Self::O {
+        BlobBatchBuilder {
+            client: self.client,
+            p_blob_batch: PhantomData {},
+            blob_batch: Some(blob_batch),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, BlobBatchSet> TimeoutSupport for BlobBatchBuilder<'a, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+{
+    type O = BlobBatchBuilder<'a, BlobBatchSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        BlobBatchBuilder {
+            client: self.client,
+            p_blob_batch: PhantomData {},
+            blob_batch: self.blob_batch,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, BlobBatchSet> ClientRequestIdSupport<'a> for BlobBatchBuilder<'a, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+{
+    type O = BlobBatchBuilder<'a, BlobBatchSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        BlobBatchBuilder {
+            client: self.client,
+            p_blob_batch: PhantomData {},
+            blob_batch: self.blob_batch,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, BlobBatchSet> BlobBatchBuilder<'a, BlobBatchSet> where BlobBatchSet: ToAssign {}
+
+impl<'a> BlobBatchBuilder<'a, Yes> {
+    /// Sends all the operations of `blob_batch` in a single request. The
+    /// request succeeds even if some operations fail: check the status of
+    /// each sub-response.
+    pub async fn finalize(self) -> Result<BlobBatchResponse, AzureError> {
+        self.blob_batch().validate()?;
+
+        let mut uri = format!("{}/?comp=batch", self.client().blob_uri());
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let boundary = format!("batch_{}", Uuid::new_v4());
+        let body = self.blob_batch().to_multipart(self.client(), &boundary)?;
+        let content_type = format!("multipart/mixed; boundary={}", boundary);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::POST,
+            |ref mut request| {
+                request.header_static(VERSION, VERSION_2018_11_09);
+                request.header(CONTENT_TYPE, &content_type as &str);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(body.as_bytes()),
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        BlobBatchResponse::from_response(self.blob_batch(), &headers, &body)
+    }
+}
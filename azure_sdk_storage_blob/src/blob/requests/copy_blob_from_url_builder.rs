@@ -0,0 +1,613 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::responses::CopyBlobFromUrlResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::REQUIRES_SYNC;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::modify_conditions::{IfMatchCondition, IfSinceCondition};
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, CopySourceRequired, CopySourceSupport,
+    IfMatchConditionOption, IfMatchConditionSupport, IfSinceConditionOption,
+    IfSinceConditionSupport, LeaseIdOption, LeaseIdSupport, MetadataOption, MetadataSupport, No,
+    SourceIfMatchConditionOption, SourceIfMatchConditionSupport, SourceIfSinceConditionOption,
+    SourceIfSinceConditionSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_copy_source: PhantomData<CopySourceSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    copy_source: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    source_if_since_condition: Option<IfSinceCondition>,
+    source_if_match_condition: Option<IfMatchCondition<'a>>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> CopyBlobFromUrlBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> CopyBlobFromUrlBuilder<'a, No, No, No> {
+        CopyBlobFromUrlBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_copy_source: PhantomData {},
+            copy_source: None,
+            metadata: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            source_if_since_condition: None,
+            source_if_match_condition: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ClientRequired<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, CopySourceSet> ContainerNameRequired<'a>
+    for CopyBlobFromUrlBuilder<'a, Yes, BlobNameSet, CopySourceSet>
+where
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, CopySourceSet> BlobNameRequired<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, Yes, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> CopySourceRequired<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn copy_source(&self) -> &'a str {
+        self.copy_source.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> MetadataOption<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfSinceConditionOption
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfMatchConditionOption<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfSinceConditionOption
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn source_if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.source_if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfMatchConditionOption<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn source_if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.source_if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> LeaseIdOption<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> TimeoutOption
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ClientRequestIdOption<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ContainerNameSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, Yes, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> BlobNameSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, Yes, CopySourceSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> CopySourceSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_copy_source(self, copy_source: &'a str) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: Some(copy_source),
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> MetadataSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: Some(metadata),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfSinceConditionSupport
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> IfMatchConditionSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfSinceConditionSupport
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_source_if_since_condition(
+        self,
+        source_if_since_condition: IfSinceCondition,
+    ) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: Some(source_if_since_condition),
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> SourceIfMatchConditionSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_source_if_match_condition(
+        self,
+        source_if_match_condition: IfMatchCondition<'a>,
+    ) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: Some(source_if_match_condition),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> LeaseIdSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> TimeoutSupport
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> ClientRequestIdSupport<'a>
+    for CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CopyBlobFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+    CopyBlobFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+}
+
+impl<'a> CopyBlobFromUrlBuilder<'a, Yes, Yes, Yes> {
+    /// Copies `copy_source` into the blob and returns only once the copy
+    /// is complete. The source must be a block blob of at most 256 MiB,
+    /// readable with the URL alone (public or carrying a SAS token).
+    pub async fn finalize(self) -> Result<CopyBlobFromUrlResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, None);
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                CopySourceRequired::add_header(&self, request);
+                request.header_static(REQUIRES_SYNC, "true");
+                MetadataOption::add_header(&self, request);
+                IfSinceConditionOption::add_header(&self, request);
+                IfMatchConditionOption::add_header(&self, request);
+                SourceIfSinceConditionOption::add_header(&self, request);
+                SourceIfMatchConditionOption::add_header(&self, request);
+                LeaseIdOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        CopyBlobFromUrlResponse::from_headers(&headers)
+    }
+}
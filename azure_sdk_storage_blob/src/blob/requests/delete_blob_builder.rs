@@ -1,11 +1,14 @@
-use crate::blob::generate_blob_uri;
 use crate::blob::responses::DeleteBlobResponse;
+use crate::blob::{generate_blob_uri, VERSION_2019_12_12};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
 use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::util::RequestBuilderExt;
 use azure_sdk_core::{
     BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
     ContainerNameRequired, ContainerNameSupport, DeleteSnapshotsMethodRequired,
-    DeleteSnapshotsMethodSupport, LeaseIdOption, LeaseIdSupport, TimeoutOption, TimeoutSupport,
+    DeleteSnapshotsMethodSupport, IfTagsOption, IfTagsSupport, LeaseIdOption, LeaseIdSupport,
+    TimeoutOption, TimeoutSupport,
 };
 use azure_sdk_core::{DeleteSnapshotsMethod, No, ToAssign, Yes};
 use azure_sdk_storage_core::client::Client;
@@ -30,6 +33,7 @@ where
     timeout: Option<u64>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
+    if_tags: Option<&'a str>,
 }
 
 impl<'a> DeleteBlobBuilder<'a, No, No, No> {
@@ -46,6 +50,7 @@ impl<'a> DeleteBlobBuilder<'a, No, No, No> {
             timeout: None,
             lease_id: None,
             client_request_id: None,
+            if_tags: None,
         }
     }
 }
@@ -138,6 +143,19 @@ where
     }
 }
 
+impl<'a, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> IfTagsOption<'a>
+    for DeleteBlobBuilder<'a, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    DeleteSnapshotMethodSet: ToAssign,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> ContainerNameSupport<'a>
     for DeleteBlobBuilder<'a, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
 where
@@ -160,6 +178,7 @@ where
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -186,6 +205,7 @@ where
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -215,6 +235,7 @@ where
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -241,6 +262,7 @@ where
             timeout: Some(timeout),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -267,6 +289,7 @@ where
             timeout: self.timeout,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -293,6 +316,34 @@ where
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> IfTagsSupport<'a>
+    for DeleteBlobBuilder<'a, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    DeleteSnapshotMethodSet: ToAssign,
+{
+    type O = DeleteBlobBuilder<'a, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        DeleteBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_delete_snapshots_method: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            delete_snapshots_method: self.delete_snapshots_method,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            if_tags: Some(if_tags),
         }
     }
 }
@@ -323,6 +374,10 @@ impl<'a> DeleteBlobBuilder<'a, Yes, Yes, Yes> {
             |ref mut request| {
                 DeleteSnapshotsMethodRequired::add_header(&self, request);
                 LeaseIdOption::add_header(&self, request);
+                if self.if_tags().is_some() {
+                    request.header_static(VERSION, VERSION_2019_12_12);
+                }
+                IfTagsOption::add_header(&self, request);
                 ClientRequestIdOption::add_header(&self, request);
             },
             None,
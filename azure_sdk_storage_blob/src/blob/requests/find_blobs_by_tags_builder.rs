@@ -0,0 +1,240 @@
+use crate::blob::responses::FindBlobsByTagsResponse;
+use crate::blob::{TagFilterRequired, TagFilterSupport, VERSION_2019_12_12};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, MaxResultsOption, MaxResultsSupport,
+    NextMarkerOption, NextMarkerSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    client: &'a Client,
+    p_tag_filter: PhantomData<TagFilterSet>,
+    tag_filter: Option<&'a str>,
+    next_marker: Option<&'a str>,
+    max_results: Option<u32>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> FindBlobsByTagsBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> FindBlobsByTagsBuilder<'a, No> {
+        FindBlobsByTagsBuilder {
+            client,
+            p_tag_filter: PhantomData {},
+            tag_filter: None,
+            next_marker: None,
+            max_results: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> ClientRequired<'a> for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> TagFilterRequired<'a> for FindBlobsByTagsBuilder<'a, Yes> {
+    #[inline]
+    fn tag_filter(&self) -> &'a str {
+        self.tag_filter.unwrap()
+    }
+}
+
+impl<'a, TagFilterSet> NextMarkerOption<'a> for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn next_marker(&self) -> Option<&'a str> {
+        self.next_marker
+    }
+}
+
+impl<'a, TagFilterSet> MaxResultsOption for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn max_results(&self) -> Option<u32> {
+        self.max_results
+    }
+}
+
+impl<'a, TagFilterSet> TimeoutOption for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, TagFilterSet> ClientRequestIdOption<'a> for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, TagFilterSet> TagFilterSupport<'a> for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_tag_filter(self, tag_filter: &'a str) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: Some(tag_filter),
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> NextMarkerSupport<'a> for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsBuilder<'a, TagFilterSet>;
+
+    #[inline]
+    fn with_next_marker(self, next_marker: &'a str) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: Some(next_marker),
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> MaxResultsSupport for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsBuilder<'a, TagFilterSet>;
+
+    #[inline]
+    fn with_max_results(self, max_results: u32) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: self.next_marker,
+            max_results: Some(max_results),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> TimeoutSupport for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsBuilder<'a, TagFilterSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, TagFilterSet> ClientRequestIdSupport<'a> for FindBlobsByTagsBuilder<'a, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+{
+    type O = FindBlobsByTagsBuilder<'a, TagFilterSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, TagFilterSet> FindBlobsByTagsBuilder<'a, TagFilterSet> where TagFilterSet: ToAssign {}
+
+impl<'a> FindBlobsByTagsBuilder<'a, Yes> {
+    /// Searches the blobs of every container of the account. The results
+    /// are eventually consistent with the tags set on the blobs.
+    pub async fn finalize(self) -> Result<FindBlobsByTagsResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?comp=blobs&{}",
+            self.client().blob_uri(),
+            TagFilterRequired::to_uri_parameter(&self)
+        );
+
+        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = MaxResultsOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                request.header_static(VERSION, VERSION_2019_12_12);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        FindBlobsByTagsResponse::from_response(&headers, &body)
+    }
+}
@@ -1,14 +1,15 @@
 use crate::blob::responses::GetBlobResponse;
-use crate::blob::{generate_blob_uri, Blob};
+use crate::blob::{generate_blob_uri, Blob, VERSION_2019_12_12};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
-use azure_sdk_core::headers::RANGE_GET_CONTENT_MD5;
+use azure_sdk_core::headers::{RANGE_GET_CONTENT_MD5, VERSION};
 use azure_sdk_core::lease::LeaseId;
 use azure_sdk_core::range::Range;
 use azure_sdk_core::util::RequestBuilderExt;
 use azure_sdk_core::{
     BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
-    ContainerNameRequired, ContainerNameSupport, LeaseIdOption, LeaseIdSupport, No, RangeOption,
-    RangeSupport, SnapshotOption, SnapshotSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+    ContainerNameRequired, ContainerNameSupport, IfTagsOption, IfTagsSupport, LeaseIdOption,
+    LeaseIdSupport, No, RangeOption, RangeSupport, SnapshotOption, SnapshotSupport, TimeoutOption,
+    TimeoutSupport, ToAssign, Yes,
 };
 use azure_sdk_storage_core::client::Client;
 use azure_sdk_storage_core::ClientRequired;
@@ -32,6 +33,7 @@ where
     range: Option<&'a Range>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
+    if_tags: Option<&'a str>,
 }
 
 impl<'a> GetBlobBuilder<'a, No, No> {
@@ -48,6 +50,7 @@ impl<'a> GetBlobBuilder<'a, No, No> {
             range: None,
             lease_id: None,
             client_request_id: None,
+            if_tags: None,
         }
     }
 }
@@ -144,6 +147,18 @@ where
     }
 }
 
+impl<'a, ContainerNameSet, BlobNameSet> IfTagsOption<'a>
+    for GetBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, ContainerNameSet, BlobNameSet> ContainerNameSupport<'a>
     for GetBlobBuilder<'a, ContainerNameSet, BlobNameSet>
 where
@@ -165,6 +180,7 @@ where
             range: self.range,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -190,6 +206,7 @@ where
             range: self.range,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -215,6 +232,7 @@ where
             range: self.range,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -240,6 +258,7 @@ where
             range: self.range,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -265,6 +284,7 @@ where
             range: Some(range),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -290,6 +310,7 @@ where
             range: self.range,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -315,6 +336,33 @@ where
             range: self.range,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfTagsSupport<'a>
+    for GetBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        GetBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            range: self.range,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            if_tags: Some(if_tags),
         }
     }
 }
@@ -351,6 +399,11 @@ impl<'a> GetBlobBuilder<'a, Yes, Yes> {
             &uri,
             &Method::GET,
             |ref mut request| {
+                if self.if_tags().is_some() {
+                    request.header_static(VERSION, VERSION_2019_12_12);
+                }
+                IfTagsOption::add_header(&self, request);
+
                 if let Some(r) = self.range() {
                     LeaseIdOption::add_header(&self, request);
                     RangeOption::add_header(&self, request);
@@ -0,0 +1,470 @@
+use crate::blob::responses::GetBlobPropertiesResponse;
+use crate::blob::{generate_blob_uri, Blob, VERSION_2019_12_12};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::modify_conditions::{IfMatchCondition, IfSinceCondition};
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, IfMatchConditionOption, IfMatchConditionSupport,
+    IfSinceConditionOption, IfSinceConditionSupport, IfTagsOption, IfTagsSupport, LeaseIdOption,
+    LeaseIdSupport, No, SnapshotOption, SnapshotSupport, TimeoutOption, TimeoutSupport, ToAssign,
+    Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Utc};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    snapshot: Option<DateTime<Utc>>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+    if_tags: Option<&'a str>,
+}
+
+impl<'a> GetBlobPropertiesBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetBlobPropertiesBuilder<'a, No, No> {
+        GetBlobPropertiesBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            snapshot: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            timeout: None,
+            client_request_id: None,
+            if_tags: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequired<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet> ContainerNameRequired<'a> for GetBlobPropertiesBuilder<'a, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> BlobNameRequired<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> SnapshotOption
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn snapshot(&self) -> Option<DateTime<Utc>> {
+        self.snapshot
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfSinceConditionOption
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfMatchConditionOption<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutOption
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfTagsOption<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContainerNameSupport<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlobNameSupport<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            snapshot: self.snapshot,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> SnapshotSupport
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_snapshot(self, snapshot: DateTime<Utc>) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: Some(snapshot),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfSinceConditionSupport
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfMatchConditionSupport<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfTagsSupport<'a>
+    for GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        GetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+            if_tags: Some(if_tags),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet> GetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+}
+
+impl<'a> GetBlobPropertiesBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<GetBlobPropertiesResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, None);
+
+        let mut f_first = true;
+        if let Some(snapshot) = SnapshotOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, snapshot);
+            f_first = false;
+        }
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}{}{}", uri, if f_first { "?" } else { "&" }, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::HEAD,
+            |ref mut request| {
+                LeaseIdOption::add_header(&self, request);
+                IfSinceConditionOption::add_header(&self, request);
+                IfMatchConditionOption::add_header(&self, request);
+                if self.if_tags().is_some() {
+                    request.header_static(VERSION, VERSION_2019_12_12);
+                }
+                IfTagsOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        let blob = Blob::from_headers(
+            self.blob_name(),
+            self.container_name(),
+            self.snapshot(),
+            &headers,
+        )?;
+        GetBlobPropertiesResponse::from_headers(&headers, blob)
+    }
+}
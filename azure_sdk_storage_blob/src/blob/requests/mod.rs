@@ -1,19 +1,39 @@
+mod abort_copy_blob_builder;
+pub use self::abort_copy_blob_builder::AbortCopyBlobBuilder;
 mod acquire_blob_lease_builder;
 pub use self::acquire_blob_lease_builder::AcquireBlobLeaseBuilder;
+mod append_block_from_url_builder;
+pub use self::append_block_from_url_builder::AppendBlockFromUrlBuilder;
+mod blob_batch_builder;
+pub use self::blob_batch_builder::BlobBatchBuilder;
 mod break_blob_lease_builder;
 pub use self::break_blob_lease_builder::BreakBlobLeaseBuilder;
 mod change_blob_lease_builder;
 pub use self::change_blob_lease_builder::ChangeBlobLeaseBuilder;
 mod clear_page_builder;
 pub use self::clear_page_builder::ClearPageBuilder;
+mod copy_blob_builder;
+pub use self::copy_blob_builder::CopyBlobBuilder;
+mod copy_blob_from_url_builder;
+pub use self::copy_blob_from_url_builder::CopyBlobFromUrlBuilder;
 mod delete_blob_builder;
 pub use self::delete_blob_builder::DeleteBlobBuilder;
 mod delete_blob_snapshot_builder;
 pub use self::delete_blob_snapshot_builder::DeleteBlobSnapshotBuilder;
+mod find_blobs_by_tags_builder;
+pub use self::find_blobs_by_tags_builder::FindBlobsByTagsBuilder;
 mod get_blob_builder;
 pub use self::get_blob_builder::GetBlobBuilder;
+mod get_blob_metadata_builder;
+pub use self::get_blob_metadata_builder::GetBlobMetadataBuilder;
+mod get_blob_properties_builder;
+pub use self::get_blob_properties_builder::GetBlobPropertiesBuilder;
+mod get_blob_tags_builder;
+pub use self::get_blob_tags_builder::GetBlobTagsBuilder;
 mod get_block_list_builder;
 pub use self::get_block_list_builder::GetBlockListBuilder;
+mod get_page_ranges_builder;
+pub use self::get_page_ranges_builder::GetPageRangesBuilder;
 mod list_blobs_builder;
 pub use self::list_blobs_builder::ListBlobBuilder;
 mod put_append_blob_builder;
@@ -24,6 +44,8 @@ mod put_block_blob_builder;
 pub use self::put_block_blob_builder::PutBlockBlobBuilder;
 mod put_block_builder;
 pub use self::put_block_builder::PutBlockBuilder;
+mod put_block_from_url_builder;
+pub use self::put_block_from_url_builder::PutBlockFromUrlBuilder;
 mod put_block_list_builder;
 pub use self::put_block_list_builder::PutBlockListBuilder;
 mod put_page_blob_builder;
@@ -32,5 +54,17 @@ mod release_blob_lease_builder;
 pub use self::release_blob_lease_builder::ReleaseBlobLeaseBuilder;
 mod renew_blob_lease_builder;
 pub use self::renew_blob_lease_builder::RenewBlobLeaseBuilder;
+mod set_blob_metadata_builder;
+pub use self::set_blob_metadata_builder::SetBlobMetadataBuilder;
+mod set_blob_properties_builder;
+pub use self::set_blob_properties_builder::SetBlobPropertiesBuilder;
+mod set_blob_tags_builder;
+pub use self::set_blob_tags_builder::SetBlobTagsBuilder;
+mod set_blob_tier_builder;
+pub use self::set_blob_tier_builder::SetBlobTierBuilder;
+mod snapshot_blob_builder;
+pub use self::snapshot_blob_builder::SnapshotBlobBuilder;
+mod undelete_blob_builder;
+pub use self::undelete_blob_builder::UndeleteBlobBuilder;
 mod update_page_builder;
 pub use self::update_page_builder::UpdatePageBuilder;
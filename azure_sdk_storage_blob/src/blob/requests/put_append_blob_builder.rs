@@ -1,17 +1,19 @@
-use crate::blob::generate_blob_uri;
 use crate::blob::responses::PutBlobResponse;
+use crate::blob::{generate_blob_uri, VERSION_2019_12_12};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::headers::BLOB_TYPE;
+use azure_sdk_core::headers::VERSION;
 use azure_sdk_core::lease::LeaseId;
 use azure_sdk_core::modify_conditions::IfMatchCondition;
+use azure_sdk_core::util::RequestBuilderExt;
 use azure_sdk_core::{
     BlobNameRequired, BlobNameSupport, CacheControlOption, CacheControlSupport,
     ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
     ContentDispositionOption, ContentDispositionSupport, ContentEncodingOption,
     ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport, ContentTypeOption,
-    ContentTypeSupport, IfMatchConditionOption, IfMatchConditionSupport, LeaseIdOption,
-    LeaseIdSupport, MetadataOption, MetadataSupport, No, TimeoutOption, TimeoutSupport, ToAssign,
-    Yes,
+    ContentTypeSupport, IfMatchConditionOption, IfMatchConditionSupport, IfTagsOption,
+    IfTagsSupport, LeaseIdOption, LeaseIdSupport, MetadataOption, MetadataSupport, No, TagsOption,
+    TagsSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
 };
 use azure_sdk_storage_core::client::Client;
 use azure_sdk_storage_core::ClientRequired;
@@ -40,6 +42,8 @@ where
     lease_id: Option<&'a LeaseId>,
     if_match_condition: Option<IfMatchCondition<'a>>,
     client_request_id: Option<&'a str>,
+    tags: Option<&'a HashMap<&'a str, &'a str>>,
+    if_tags: Option<&'a str>,
 }
 
 impl<'a> PutAppendBlobBuilder<'a, No, No> {
@@ -61,6 +65,8 @@ impl<'a> PutAppendBlobBuilder<'a, No, No> {
             lease_id: None,
             if_match_condition: None,
             client_request_id: None,
+            tags: None,
+            if_tags: None,
         }
     }
 }
@@ -217,6 +223,30 @@ where
     }
 }
 
+impl<'a, ContainerNameSet, BlobNameSet> TagsOption<'a>
+    for PutAppendBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn tags(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.tags
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfTagsOption<'a>
+    for PutAppendBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, ContainerNameSet, BlobNameSet> ContainerNameSupport<'a>
     for PutAppendBlobBuilder<'a, ContainerNameSet, BlobNameSet>
 where
@@ -243,6 +273,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -273,6 +305,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -303,6 +337,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -333,6 +369,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -363,6 +401,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -393,6 +433,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -423,6 +465,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -453,6 +497,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -483,6 +529,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -513,6 +561,8 @@ where
             lease_id: Some(lease_id),
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -543,6 +593,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: Some(if_match_condition),
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -573,6 +625,72 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: Some(client_request_id),
+            tags: self.tags,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TagsSupport<'a>
+    for PutAppendBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = PutAppendBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_tags(self, tags: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        PutAppendBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            tags: Some(tags),
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfTagsSupport<'a>
+    for PutAppendBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = PutAppendBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        PutAppendBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: Some(if_tags),
         }
     }
 }
@@ -609,6 +727,11 @@ impl<'a> PutAppendBlobBuilder<'a, Yes, Yes> {
                 request.header(BLOB_TYPE, "AppendBlob");
                 LeaseIdOption::add_header(&self, request);
                 IfMatchConditionOption::add_header(&self, request);
+                if self.tags().is_some() || self.if_tags().is_some() {
+                    request.header_static(VERSION, VERSION_2019_12_12);
+                }
+                TagsOption::add_header(&self, request);
+                IfTagsOption::add_header(&self, request);
                 ClientRequestIdOption::add_header(&self, request);
             },
             None,
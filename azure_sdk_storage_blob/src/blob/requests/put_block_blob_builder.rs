@@ -1,17 +1,20 @@
-use crate::blob::generate_blob_uri;
 use crate::blob::responses::PutBlockBlobResponse;
+use crate::blob::{generate_blob_uri, VERSION_2019_12_12};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::headers::BLOB_TYPE;
+use azure_sdk_core::headers::VERSION;
 use azure_sdk_core::lease::LeaseId;
 use azure_sdk_core::modify_conditions::IfMatchCondition;
+use azure_sdk_core::util::RequestBuilderExt;
 use azure_sdk_core::{
     BlobNameRequired, BlobNameSupport, BodyRequired, BodySupport, CacheControlOption,
     CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired,
     ContainerNameSupport, ContentDispositionOption, ContentDispositionSupport,
     ContentEncodingOption, ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport,
     ContentMD5Option, ContentMD5Support, ContentTypeOption, ContentTypeSupport,
-    IfMatchConditionOption, IfMatchConditionSupport, LeaseIdOption, LeaseIdSupport, MetadataOption,
-    MetadataSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+    IfMatchConditionOption, IfMatchConditionSupport, IfTagsOption, IfTagsSupport, LeaseIdOption,
+    LeaseIdSupport, MetadataOption, MetadataSupport, No, TagsOption, TagsSupport, TimeoutOption,
+    TimeoutSupport, ToAssign, Yes,
 };
 use azure_sdk_storage_core::client::Client;
 use azure_sdk_storage_core::ClientRequired;
@@ -44,6 +47,8 @@ where
     lease_id: Option<&'a LeaseId>,
     if_match_condition: Option<IfMatchCondition<'a>>,
     client_request_id: Option<&'a str>,
+    tags: Option<&'a HashMap<&'a str, &'a str>>,
+    if_tags: Option<&'a str>,
 }
 
 impl<'a> PutBlockBlobBuilder<'a, No, No, No> {
@@ -68,6 +73,8 @@ impl<'a> PutBlockBlobBuilder<'a, No, No, No> {
             lease_id: None,
             if_match_condition: None,
             client_request_id: None,
+            tags: None,
+            if_tags: None,
         }
     }
 }
@@ -264,6 +271,32 @@ where
     }
 }
 
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> TagsOption<'a>
+    for PutBlockBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn tags(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.tags
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> IfTagsOption<'a>
+    for PutBlockBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContainerNameSupport<'a>
     for PutBlockBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
 where
@@ -294,6 +327,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -328,6 +363,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -362,6 +399,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -396,6 +435,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -430,6 +471,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -464,6 +507,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -498,6 +543,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -532,6 +579,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -566,6 +615,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -600,6 +651,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -634,6 +687,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -668,6 +723,8 @@ where
             lease_id: Some(lease_id),
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -702,6 +759,8 @@ where
             lease_id: self.lease_id,
             if_match_condition: Some(if_match_condition),
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -736,6 +795,80 @@ where
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: Some(client_request_id),
+            tags: self.tags,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> TagsSupport<'a>
+    for PutBlockBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlockBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_tags(self, tags: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        PutBlockBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_body: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            body: self.body,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_md5: self.content_md5,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            tags: Some(tags),
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> IfTagsSupport<'a>
+    for PutBlockBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlockBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        PutBlockBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_body: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            body: self.body,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_md5: self.content_md5,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: Some(if_tags),
         }
     }
 }
@@ -775,6 +908,11 @@ impl<'a> PutBlockBlobBuilder<'a, Yes, Yes, Yes> {
                 request.header(BLOB_TYPE, "BlockBlob");
                 LeaseIdOption::add_header(&self, request);
                 IfMatchConditionOption::add_header(&self, request);
+                if self.tags().is_some() || self.if_tags().is_some() {
+                    request.header_static(VERSION, VERSION_2019_12_12);
+                }
+                TagsOption::add_header(&self, request);
+                IfTagsOption::add_header(&self, request);
                 ClientRequestIdOption::add_header(&self, request);
             },
             Some(self.body()),
@@ -0,0 +1,648 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::responses::PutBlockResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::modify_conditions::{IfMatchCondition, IfSinceCondition};
+use azure_sdk_core::range::Range;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, BlockIdRequired, BlockIdSupport, ClientRequestIdOption,
+    ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport, CopySourceRequired,
+    CopySourceSupport, LeaseIdOption, LeaseIdSupport, No, SourceContentMD5Option,
+    SourceContentMD5Support, SourceIfMatchConditionOption, SourceIfMatchConditionSupport,
+    SourceIfSinceConditionOption, SourceIfSinceConditionSupport, SourceRangeOption,
+    SourceRangeSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_copy_source: PhantomData<CopySourceSet>,
+    p_block_id: PhantomData<BlockIdSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    copy_source: Option<&'a str>,
+    block_id: Option<&'a [u8]>,
+    source_range: Option<&'a Range>,
+    source_content_md5: Option<&'a [u8]>,
+    source_if_since_condition: Option<IfSinceCondition>,
+    source_if_match_condition: Option<IfMatchCondition<'a>>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> PutBlockFromUrlBuilder<'a, No, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> PutBlockFromUrlBuilder<'a, No, No, No, No> {
+        PutBlockFromUrlBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_copy_source: PhantomData {},
+            copy_source: None,
+            p_block_id: PhantomData {},
+            block_id: None,
+            source_range: None,
+            source_content_md5: None,
+            source_if_since_condition: None,
+            source_if_match_condition: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> ClientRequired<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, CopySourceSet, BlockIdSet> ContainerNameRequired<'a>
+    for PutBlockFromUrlBuilder<'a, Yes, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, CopySourceSet, BlockIdSet> BlobNameRequired<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, Yes, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BlockIdSet> CopySourceRequired<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, Yes, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn copy_source(&self) -> &'a str {
+        self.copy_source.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet> BlockIdRequired<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn block_id(&self) -> &'a [u8] {
+        self.block_id.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceRangeOption<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn source_range(&self) -> Option<&'a Range> {
+        self.source_range
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceContentMD5Option<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn source_content_md5(&self) -> Option<&'a [u8]> {
+        self.source_content_md5
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceIfSinceConditionOption
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn source_if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.source_if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceIfMatchConditionOption<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn source_if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.source_if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> LeaseIdOption<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> TimeoutOption
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> ClientRequestIdOption<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> ContainerNameSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, Yes, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> BlobNameSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, Yes, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> CopySourceSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, Yes, BlockIdSet>;
+
+    #[inline]
+    fn with_copy_source(self, copy_source: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: Some(copy_source),
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> BlockIdSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, Yes>;
+
+    #[inline]
+    fn with_block_id(self, block_id: &'a [u8]) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: Some(block_id),
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceRangeSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_source_range(self, source_range: &'a Range) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: Some(source_range),
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceContentMD5Support<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_source_content_md5(self, source_content_md5: &'a [u8]) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: Some(source_content_md5),
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceIfSinceConditionSupport
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_source_if_since_condition(
+        self,
+        source_if_since_condition: IfSinceCondition,
+    ) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: Some(source_if_since_condition),
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> SourceIfMatchConditionSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_source_if_match_condition(
+        self,
+        source_if_match_condition: IfMatchCondition<'a>,
+    ) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: Some(source_if_match_condition),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> LeaseIdSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> TimeoutSupport
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet> ClientRequestIdSupport<'a>
+    for PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+    type O = PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_source: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_source: self.copy_source,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            source_if_since_condition: self.source_if_since_condition,
+            source_if_match_condition: self.source_if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+    PutBlockFromUrlBuilder<'a, ContainerNameSet, BlobNameSet, CopySourceSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+    BlockIdSet: ToAssign,
+{
+}
+
+impl<'a> PutBlockFromUrlBuilder<'a, Yes, Yes, Yes, Yes> {
+    /// Stages a block whose content is read by the service from
+    /// `copy_source` (only `source_range` of it, if specified). The source
+    /// must be readable with the URL alone, i.e. public or carrying a SAS
+    /// token. The block must then be committed with Put Block List.
+    pub async fn finalize(self) -> Result<PutBlockResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, Some("comp=block"));
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+        uri = format!("{}&{}", uri, BlockIdRequired::to_uri_parameter(&self));
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                CopySourceRequired::add_header(&self, request);
+                SourceRangeOption::add_header(&self, request);
+                SourceContentMD5Option::add_header(&self, request);
+                SourceIfSinceConditionOption::add_header(&self, request);
+                SourceIfMatchConditionOption::add_header(&self, request);
+                LeaseIdOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        PutBlockResponse::from_headers(&headers)
+    }
+}
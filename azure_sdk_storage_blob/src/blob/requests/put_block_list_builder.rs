@@ -1,16 +1,19 @@
-use crate::blob::generate_blob_uri;
 use crate::blob::responses::PutBlockListResponse;
 use crate::blob::BlockList;
+use crate::blob::{generate_blob_uri, VERSION_2019_12_12};
 use crate::blob::{BlockListRequired, BlockListSupport};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
 use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::util::RequestBuilderExt;
 use azure_sdk_core::{
     add_content_md5_header, BlobNameRequired, BlobNameSupport, CacheControlOption,
     CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired,
     ContainerNameSupport, ContentDispositionOption, ContentDispositionSupport,
     ContentEncodingOption, ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport,
-    ContentTypeOption, ContentTypeSupport, LeaseIdOption, LeaseIdSupport, MetadataOption,
-    MetadataSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+    ContentTypeOption, ContentTypeSupport, IfTagsOption, IfTagsSupport, LeaseIdOption,
+    LeaseIdSupport, MetadataOption, MetadataSupport, No, TagsOption, TagsSupport, TimeoutOption,
+    TimeoutSupport, ToAssign, Yes,
 };
 use azure_sdk_storage_core::client::Client;
 use azure_sdk_storage_core::ClientRequired;
@@ -44,6 +47,8 @@ where
     metadata: Option<&'a HashMap<&'a str, &'a str>>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
+    tags: Option<&'a HashMap<&'a str, &'a str>>,
+    if_tags: Option<&'a str>,
 }
 
 impl<'a, T> PutBlockListBuilder<'a, T, No, No, No>
@@ -69,6 +74,8 @@ where
             metadata: None,
             lease_id: None,
             client_request_id: None,
+            tags: None,
+            if_tags: None,
         }
     }
 }
@@ -252,6 +259,34 @@ where
     }
 }
 
+impl<'a, T, ContainerNameSet, BlobNameSet, BlockListSet> TagsOption<'a>
+    for PutBlockListBuilder<'a, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    T: Borrow<[u8]> + 'a,
+{
+    #[inline]
+    fn tags(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.tags
+    }
+}
+
+impl<'a, T, ContainerNameSet, BlobNameSet, BlockListSet> IfTagsOption<'a>
+    for PutBlockListBuilder<'a, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    T: Borrow<[u8]> + 'a,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, T, ContainerNameSet, BlobNameSet, BlockListSet> ContainerNameSupport<'a>
     for PutBlockListBuilder<'a, T, ContainerNameSet, BlobNameSet, BlockListSet>
 where
@@ -281,6 +316,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -314,6 +351,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -347,6 +386,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -380,6 +421,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -413,6 +456,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -446,6 +491,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -479,6 +526,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -512,6 +561,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -545,6 +596,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -578,6 +631,8 @@ where
             metadata: Some(metadata),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -611,6 +666,8 @@ where
             metadata: self.metadata,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -644,6 +701,78 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
+            tags: self.tags,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, T, ContainerNameSet, BlobNameSet, BlockListSet> TagsSupport<'a>
+    for PutBlockListBuilder<'a, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    T: Borrow<[u8]> + 'a,
+{
+    type O = PutBlockListBuilder<'a, T, ContainerNameSet, BlobNameSet, BlockListSet>;
+
+    #[inline]
+    fn with_tags(self, tags: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        PutBlockListBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_block_list: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_list: self.block_list,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            tags: Some(tags),
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, T, ContainerNameSet, BlobNameSet, BlockListSet> IfTagsSupport<'a>
+    for PutBlockListBuilder<'a, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    T: Borrow<[u8]> + 'a,
+{
+    type O = PutBlockListBuilder<'a, T, ContainerNameSet, BlobNameSet, BlockListSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        PutBlockListBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_block_list: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_list: self.block_list,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: Some(if_tags),
         }
     }
 }
@@ -697,6 +826,11 @@ where
                 ContentDispositionOption::add_header(&self, request);
                 MetadataOption::add_header(&self, request);
                 LeaseIdOption::add_header(&self, request);
+                if self.tags().is_some() || self.if_tags().is_some() {
+                    request.header_static(VERSION, VERSION_2019_12_12);
+                }
+                TagsOption::add_header(&self, request);
+                IfTagsOption::add_header(&self, request);
                 ClientRequestIdOption::add_header(&self, request);
             },
             Some(body_bytes),
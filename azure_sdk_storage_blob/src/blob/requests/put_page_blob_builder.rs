@@ -1,16 +1,18 @@
-use crate::blob::generate_blob_uri;
 use crate::blob::responses::PutBlobResponse;
+use crate::blob::{generate_blob_uri, VERSION_2019_12_12};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
-use azure_sdk_core::headers::BLOB_TYPE;
+use azure_sdk_core::headers::{BLOB_TYPE, VERSION};
 use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::util::RequestBuilderExt;
 use azure_sdk_core::{
-    AccessTierOption, AccessTierSupport, BlobNameRequired, BlobNameSupport, CacheControlOption,
-    CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired,
-    ContainerNameSupport, ContentDispositionOption, ContentDispositionSupport,
-    ContentEncodingOption, ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport,
-    ContentTypeOption, ContentTypeSupport, LeaseIdOption, LeaseIdSupport, MetadataOption,
-    MetadataSupport, No, PageBlobLengthRequired, PageBlobLengthSupport, SequenceNumberOption,
-    SequenceNumberSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+    AccessTier, AccessTierOption, AccessTierSupport, BlobNameRequired, BlobNameSupport,
+    CacheControlOption, CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, ContentDispositionOption,
+    ContentDispositionSupport, ContentEncodingOption, ContentEncodingSupport,
+    ContentLanguageOption, ContentLanguageSupport, ContentTypeOption, ContentTypeSupport,
+    IfTagsOption, IfTagsSupport, LeaseIdOption, LeaseIdSupport, MetadataOption, MetadataSupport,
+    No, PageBlobLengthRequired, PageBlobLengthSupport, SequenceNumberOption, SequenceNumberSupport,
+    TagsOption, TagsSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
 };
 use azure_sdk_storage_core::client::Client;
 use azure_sdk_storage_core::ClientRequired;
@@ -33,7 +35,7 @@ where
     blob_name: Option<&'a str>,
     content_length: u64,
     sequence_number: u64,
-    access_tier: Option<&'a str>,
+    access_tier: Option<AccessTier>,
     timeout: Option<u64>,
     content_type: Option<&'a str>,
     content_encoding: Option<&'a str>,
@@ -43,6 +45,8 @@ where
     metadata: Option<&'a HashMap<&'a str, &'a str>>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
+    tags: Option<&'a HashMap<&'a str, &'a str>>,
+    if_tags: Option<&'a str>,
 }
 
 impl<'a> PutPageBlobBuilder<'a, No, No, No> {
@@ -67,6 +71,8 @@ impl<'a> PutPageBlobBuilder<'a, No, No, No> {
             metadata: None,
             lease_id: None,
             client_request_id: None,
+            tags: None,
+            if_tags: None,
         }
     }
 }
@@ -133,7 +139,7 @@ where
     }
 }
 
-impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> AccessTierOption<'a>
+impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> AccessTierOption
     for PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>
 where
     ContainerNameSet: ToAssign,
@@ -141,7 +147,7 @@ where
     ContentLengthSet: ToAssign,
 {
     #[inline]
-    fn access_tier(&self) -> Option<&'a str> {
+    fn access_tier(&self) -> Option<AccessTier> {
         self.access_tier
     }
 }
@@ -263,6 +269,32 @@ where
     }
 }
 
+impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> TagsOption<'a>
+    for PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    ContentLengthSet: ToAssign,
+{
+    #[inline]
+    fn tags(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.tags
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> IfTagsOption<'a>
+    for PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    ContentLengthSet: ToAssign,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> ContainerNameSupport<'a>
     for PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>
 where
@@ -293,6 +325,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -327,6 +361,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -365,6 +401,8 @@ where
                 metadata: self.metadata,
                 lease_id: self.lease_id,
                 client_request_id: self.client_request_id,
+                tags: self.tags,
+                if_tags: self.if_tags,
             })
         }
     }
@@ -400,11 +438,13 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
 
-impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> AccessTierSupport<'a>
+impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> AccessTierSupport
     for PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>
 where
     ContainerNameSet: ToAssign,
@@ -414,7 +454,7 @@ where
     type O = PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>;
 
     #[inline]
-    fn with_access_tier(self, access_tier: &'a str) -> Self::O {
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
         PutPageBlobBuilder {
             client: self.client,
             p_container_name: PhantomData {},
@@ -434,6 +474,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -468,6 +510,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -502,6 +546,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -536,6 +582,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -570,6 +618,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -604,6 +654,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -638,6 +690,8 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -672,6 +726,8 @@ where
             metadata: Some(metadata),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -706,6 +762,8 @@ where
             metadata: self.metadata,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: self.if_tags,
         }
     }
 }
@@ -740,6 +798,80 @@ where
             metadata: self.metadata,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
+            tags: self.tags,
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> TagsSupport<'a>
+    for PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    ContentLengthSet: ToAssign,
+{
+    type O = PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>;
+
+    #[inline]
+    fn with_tags(self, tags: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        PutPageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            sequence_number: self.sequence_number,
+            access_tier: self.access_tier,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            tags: Some(tags),
+            if_tags: self.if_tags,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, ContentLengthSet> IfTagsSupport<'a>
+    for PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    ContentLengthSet: ToAssign,
+{
+    type O = PutPageBlobBuilder<'a, ContainerNameSet, BlobNameSet, ContentLengthSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        PutPageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            sequence_number: self.sequence_number,
+            access_tier: self.access_tier,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            tags: self.tags,
+            if_tags: Some(if_tags),
         }
     }
 }
@@ -780,6 +912,11 @@ impl<'a> PutPageBlobBuilder<'a, Yes, Yes, Yes> {
                 MetadataOption::add_header(&self, request);
                 request.header(BLOB_TYPE, "PageBlob");
                 LeaseIdOption::add_header(&self, request);
+                if self.tags().is_some() || self.if_tags().is_some() {
+                    request.header_static(VERSION, VERSION_2019_12_12);
+                }
+                TagsOption::add_header(&self, request);
+                IfTagsOption::add_header(&self, request);
                 ClientRequestIdOption::add_header(&self, request);
             },
             None,
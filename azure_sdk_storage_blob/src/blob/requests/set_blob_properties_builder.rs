@@ -0,0 +1,801 @@
+use crate::blob::responses::SetBlobPropertiesResponse;
+use crate::blob::{
+    add_blob_content_headers, generate_blob_uri, SequenceNumberAction, SequenceNumberActionOption,
+    SequenceNumberActionSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::modify_conditions::{IfMatchCondition, IfSinceCondition};
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, CacheControlOption, CacheControlSupport,
+    ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
+    ContentDispositionOption, ContentDispositionSupport, ContentEncodingOption,
+    ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport, ContentMD5Option,
+    ContentMD5Support, ContentTypeOption, ContentTypeSupport, IfMatchConditionOption,
+    IfMatchConditionSupport, IfSinceConditionOption, IfSinceConditionSupport, LeaseIdOption,
+    LeaseIdSupport, No, PageBlobLengthOption, PageBlobLengthSupport, TimeoutOption, TimeoutSupport,
+    ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    content_type: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    content_md5: Option<&'a [u8]>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    content_length: Option<u64>,
+    sequence_number_action: Option<SequenceNumberAction>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetBlobPropertiesBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetBlobPropertiesBuilder<'a, No, No> {
+        SetBlobPropertiesBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            content_type: None,
+            content_encoding: None,
+            content_language: None,
+            content_md5: None,
+            cache_control: None,
+            content_disposition: None,
+            content_length: None,
+            sequence_number_action: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequired<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet> ContainerNameRequired<'a> for SetBlobPropertiesBuilder<'a, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> BlobNameRequired<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentTypeOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentEncodingOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentLanguageOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentMD5Option<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_md5(&self) -> Option<&'a [u8]> {
+        self.content_md5
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> CacheControlOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentDispositionOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> PageBlobLengthOption
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> SequenceNumberActionOption
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn sequence_number_action(&self) -> Option<SequenceNumberAction> {
+        self.sequence_number_action
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfSinceConditionOption
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfMatchConditionOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutOption
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContainerNameSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlobNameSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentTypeSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_type(self, content_type: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: Some(content_type),
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentEncodingSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_encoding(self, content_encoding: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: Some(content_encoding),
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentLanguageSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_language(self, content_language: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: Some(content_language),
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentMD5Support<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_md5(self, content_md5: &'a [u8]) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: Some(content_md5),
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> CacheControlSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_cache_control(self, cache_control: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: Some(cache_control),
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentDispositionSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_disposition(self, content_disposition: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: Some(content_disposition),
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> PageBlobLengthSupport
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_length(self, content_length: u64) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: Some(content_length),
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> SequenceNumberActionSupport
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_sequence_number_action(self, sequence_number_action: SequenceNumberAction) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: Some(sequence_number_action),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfSinceConditionSupport
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> IfMatchConditionSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobPropertiesBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_length: self.content_length,
+            sequence_number_action: self.sequence_number_action,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet> SetBlobPropertiesBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+}
+
+impl<'a> SetBlobPropertiesBuilder<'a, Yes, Yes> {
+    /// Content type, encoding, language, MD5, cache control and
+    /// disposition are set together: the ones left unspecified are cleared.
+    /// `content_length` (resize) and `sequence_number_action` apply to page
+    /// blobs only.
+    pub async fn finalize(self) -> Result<SetBlobPropertiesResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, Some("comp=properties"));
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                add_blob_content_headers(&self, request);
+                PageBlobLengthOption::add_header(&self, request);
+                SequenceNumberActionOption::add_header(&self, request);
+                LeaseIdOption::add_header(&self, request);
+                IfSinceConditionOption::add_header(&self, request);
+                IfMatchConditionOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        SetBlobPropertiesResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,337 @@
+use crate::blob::responses::SetBlobTagsResponse;
+use crate::blob::{generate_blob_uri, tags_to_xml, VERSION_2019_12_12};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::VERSION;
+use azure_sdk_core::util::RequestBuilderExt;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, IfTagsOption, IfTagsSupport, No, TagsRequired,
+    TagsSupport, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_tags: PhantomData<TagsSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    tags: Option<&'a HashMap<&'a str, &'a str>>,
+    if_tags: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetBlobTagsBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetBlobTagsBuilder<'a, No, No, No> {
+        SetBlobTagsBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_tags: PhantomData {},
+            tags: None,
+            if_tags: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> ClientRequired<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, TagsSet> ContainerNameRequired<'a>
+    for SetBlobTagsBuilder<'a, Yes, BlobNameSet, TagsSet>
+where
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, TagsSet> BlobNameRequired<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, Yes, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TagsRequired<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn tags(&self) -> &'a HashMap<&'a str, &'a str> {
+        self.tags.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> IfTagsOption<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> TimeoutOption
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> ClientRequestIdOption<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> ContainerNameSupport<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    type O = SetBlobTagsBuilder<'a, Yes, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            tags: self.tags,
+            if_tags: self.if_tags,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> BlobNameSupport<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    type O = SetBlobTagsBuilder<'a, ContainerNameSet, Yes, TagsSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            tags: self.tags,
+            if_tags: self.if_tags,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> TagsSupport<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    type O = SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_tags(self, tags: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: Some(tags),
+            if_tags: self.if_tags,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> IfTagsSupport<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    type O = SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: self.tags,
+            if_tags: Some(if_tags),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> TimeoutSupport
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    type O = SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: self.tags,
+            if_tags: self.if_tags,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet> ClientRequestIdSupport<'a>
+    for SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+    type O = SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: self.tags,
+            if_tags: self.if_tags,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, TagsSet>
+    SetBlobTagsBuilder<'a, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+{
+}
+
+impl<'a> SetBlobTagsBuilder<'a, Yes, Yes, Yes> {
+    /// Replaces all the existing tags of the blob: an empty map removes
+    /// them.
+    pub async fn finalize(self) -> Result<SetBlobTagsResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, Some("comp=tags"));
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let body = tags_to_xml(self.tags());
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                request.header_static(VERSION, VERSION_2019_12_12);
+                IfTagsOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(body.as_bytes()),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        SetBlobTagsResponse::from_headers(&headers)
+    }
+}
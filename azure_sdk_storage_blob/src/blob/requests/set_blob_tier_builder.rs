@@ -0,0 +1,397 @@
+use crate::blob::responses::SetBlobTierResponse;
+use crate::blob::{
+    generate_blob_uri, RehydratePriority, RehydratePriorityOption, RehydratePrioritySupport,
+};
+use azure_sdk_core::errors::{
+    extract_status_headers_and_body, unexpected_status_error, AzureError,
+};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::{
+    AccessTier, AccessTierRequired, AccessTierSupport, BlobNameRequired, BlobNameSupport,
+    ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
+    LeaseIdOption, LeaseIdSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_access_tier: PhantomData<AccessTierSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    access_tier: Option<AccessTier>,
+    rehydrate_priority: Option<RehydratePriority>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetBlobTierBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetBlobTierBuilder<'a, No, No, No> {
+        SetBlobTierBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_access_tier: PhantomData {},
+            access_tier: None,
+            rehydrate_priority: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequired<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, AccessTierSet> ContainerNameRequired<'a>
+    for SetBlobTierBuilder<'a, Yes, BlobNameSet, AccessTierSet>
+where
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, AccessTierSet> BlobNameRequired<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, Yes, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> AccessTierRequired
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn access_tier(&self) -> AccessTier {
+        self.access_tier.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> RehydratePriorityOption
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn rehydrate_priority(&self) -> Option<RehydratePriority> {
+        self.rehydrate_priority
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> LeaseIdOption<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> TimeoutOption
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequestIdOption<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> ContainerNameSupport<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    type O = SetBlobTierBuilder<'a, Yes, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> BlobNameSupport<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    type O = SetBlobTierBuilder<'a, ContainerNameSet, Yes, AccessTierSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> AccessTierSupport
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    type O = SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: Some(access_tier),
+            rehydrate_priority: self.rehydrate_priority,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> RehydratePrioritySupport
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    type O = SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_rehydrate_priority(self, rehydrate_priority: RehydratePriority) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: Some(rehydrate_priority),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> LeaseIdSupport<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    type O = SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> TimeoutSupport
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    type O = SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequestIdSupport<'a>
+    for SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+    type O = SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+    SetBlobTierBuilder<'a, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+{
+}
+
+impl<'a> SetBlobTierBuilder<'a, Yes, Yes, Yes> {
+    /// Moving a blob out of the archive tier is not immediate: the blob
+    /// stays archived until the rehydration completes, which can take
+    /// hours. Follow it with the `archive_status` returned by
+    /// `get_blob_properties`.
+    pub async fn finalize(self) -> Result<SetBlobTierResponse, AzureError> {
+        let mut uri = generate_blob_uri(&self, Some("comp=tier"));
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                AccessTierRequired::add_header(&self, request);
+                RehydratePriorityOption::add_header(&self, request);
+                LeaseIdOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        // the service answers 202 when the blob has to be rehydrated first
+        let (status, headers, body) = extract_status_headers_and_body(future_response).await?;
+        if status != StatusCode::OK && status != StatusCode::ACCEPTED {
+            return Err(unexpected_status_error(
+                StatusCode::OK,
+                status,
+                &headers,
+                std::str::from_utf8(&body)?,
+            ));
+        }
+
+        SetBlobTierResponse::from_headers(&headers, status == StatusCode::ACCEPTED)
+    }
+}
@@ -0,0 +1,19 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbortCopyBlobResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl AbortCopyBlobResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<AbortCopyBlobResponse, AzureError> {
+        Ok(AbortCopyBlobResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use crate::blob::BlobBatch;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::{ERROR_CODE, REQUEST_ID};
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use hyper::header::CONTENT_TYPE;
+use hyper::StatusCode;
+use std::str::from_utf8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobBatchSubResponse {
+    pub container_name: String,
+    pub blob_name: String,
+    pub status: StatusCode,
+    pub request_id: Option<RequestId>,
+    pub error_code: Option<String>,
+}
+
+impl BlobBatchSubResponse {
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobBatchResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    /// One entry per operation of the batch, in the same order.
+    pub sub_responses: Vec<BlobBatchSubResponse>,
+}
+
+impl BlobBatchResponse {
+    pub(crate) fn from_response(
+        blob_batch: &BlobBatch,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<BlobBatchResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        let content_type = headers
+            .get(CONTENT_TYPE)
+            .ok_or_else(|| AzureError::HeaderNotFound(CONTENT_TYPE.as_str().to_owned()))?
+            .to_str()?;
+        let boundary = boundary_from_content_type(content_type)?;
+
+        let mut parts = parse_parts(from_utf8(body)?, boundary)?;
+        if parts.len() != blob_batch.operations.len() {
+            return Err(AzureError::GenericErrorWithText(format!(
+                "blob batch returned {} sub-responses for {} operations",
+                parts.len(),
+                blob_batch.operations.len()
+            )));
+        }
+        parts.sort_by_key(|part| part.content_id);
+
+        let mut sub_responses = Vec::with_capacity(parts.len());
+        for (index, part) in parts.into_iter().enumerate() {
+            if part.content_id != index {
+                return Err(AzureError::GenericErrorWithText(format!(
+                    "unexpected blob batch sub-response Content-ID {}",
+                    part.content_id
+                )));
+            }
+            let operation = &blob_batch.operations[index];
+            sub_responses.push(BlobBatchSubResponse {
+                container_name: operation.container_name().to_owned(),
+                blob_name: operation.blob_name().to_owned(),
+                status: part.status,
+                request_id: part.request_id,
+                error_code: part.error_code,
+            });
+        }
+
+        Ok(BlobBatchResponse {
+            request_id,
+            date,
+            sub_responses,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct BatchPart {
+    content_id: usize,
+    status: StatusCode,
+    request_id: Option<RequestId>,
+    error_code: Option<String>,
+}
+
+fn boundary_from_content_type(content_type: &str) -> Result<&str, AzureError> {
+    content_type
+        .split(';')
+        .map(|param| param.trim())
+        .find(|param| param.starts_with("boundary="))
+        .map(|param| param["boundary=".len()..].trim_matches('"'))
+        .ok_or_else(|| {
+            AzureError::GenericErrorWithText(format!(
+                "no boundary in blob batch Content-Type {}",
+                content_type
+            ))
+        })
+}
+
+fn split_header(line: &str) -> Option<(&str, &str)> {
+    let mut tokens = line.splitn(2, ':');
+    match (tokens.next(), tokens.next()) {
+        (Some(name), Some(value)) => Some((name.trim(), value.trim())),
+        _ => None,
+    }
+}
+
+// Each part holds its own MIME headers, a blank line and then the HTTP
+// response of the sub-request. Bodies are only present on errors and the
+// error code is already in x-ms-error-code, so they are skipped.
+fn parse_parts(body: &str, boundary: &str) -> Result<Vec<BatchPart>, AzureError> {
+    let delimiter = format!("--{}", boundary);
+    let unexpected = |part: &str| {
+        AzureError::GenericErrorWithText(format!("unexpected blob batch sub-response: {}", part))
+    };
+
+    let mut parts = Vec::new();
+    for part in body.split(delimiter.as_str()).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+
+        let mut lines = part.lines().skip_while(|line| line.is_empty());
+
+        let mut content_id = None;
+        for line in &mut lines {
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = split_header(line) {
+                if name.eq_ignore_ascii_case("Content-ID") {
+                    content_id = Some(value.parse::<usize>()?);
+                }
+            }
+        }
+        let content_id = content_id.ok_or_else(|| unexpected(part))?;
+
+        let status = lines
+            .next()
+            .and_then(|status_line| status_line.split(' ').nth(1))
+            .and_then(|code| code.parse::<u16>().ok())
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .ok_or_else(|| unexpected(part))?;
+
+        let mut request_id = None;
+        let mut error_code = None;
+        for line in &mut lines {
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = split_header(line) {
+                if name.eq_ignore_ascii_case(REQUEST_ID) {
+                    request_id = Some(value.parse::<RequestId>()?);
+                } else if name.eq_ignore_ascii_case(ERROR_CODE) {
+                    error_code = Some(value.to_owned());
+                }
+            }
+        }
+
+        parts.push(BatchPart {
+            content_id,
+            status,
+            request_id,
+            error_code,
+        });
+    }
+
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BODY: &str = "--batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed\r
+Content-Type: application/http\r
+Content-ID: 1\r
+\r
+HTTP/1.1 403 Forbidden\r
+x-ms-error-code: AuthorizationPermissionMismatch\r
+x-ms-request-id: 778fdc83-801e-0000-62ff-0334671e2852\r
+x-ms-version: 2018-11-09\r
+Content-Length: 216\r
+Content-Type: application/xml\r
+\r
+<?xml version=\"1.0\" encoding=\"utf-8\"?>\r
+<Error><Code>AuthorizationPermissionMismatch</Code></Error>\r
+--batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed\r
+Content-Type: application/http\r
+Content-ID: 0\r
+\r
+HTTP/1.1 202 Accepted\r
+x-ms-delete-type-permanent: true\r
+x-ms-request-id: 778fdc83-801e-0000-62ff-0334671e284f\r
+x-ms-version: 2018-11-09\r
+\r
+--batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed--\r
+";
+
+    #[test]
+    fn parse_sub_responses() {
+        let mut parts =
+            parse_parts(BODY, "batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed").unwrap();
+        parts.sort_by_key(|part| part.content_id);
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].status, StatusCode::ACCEPTED);
+        assert_eq!(
+            parts[0].request_id,
+            Some("778fdc83-801e-0000-62ff-0334671e284f".parse().unwrap())
+        );
+        assert_eq!(parts[0].error_code, None);
+        assert_eq!(parts[1].status, StatusCode::FORBIDDEN);
+        assert_eq!(
+            parts[1].error_code,
+            Some("AuthorizationPermissionMismatch".to_owned())
+        );
+    }
+
+    #[test]
+    fn boundary() {
+        assert_eq!(
+            boundary_from_content_type("multipart/mixed; boundary=batchresponse_1234").unwrap(),
+            "batchresponse_1234"
+        );
+        assert!(boundary_from_content_type("application/xml").is_err());
+    }
+}
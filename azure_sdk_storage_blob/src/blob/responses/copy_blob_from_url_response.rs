@@ -0,0 +1,37 @@
+use crate::blob::CopyStatus;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::COPY_STATUS;
+use azure_sdk_core::util::HeaderMapExt;
+use azure_sdk_core::{
+    copy_id_from_headers, date_from_headers, etag_from_headers, last_modified_from_headers,
+    request_id_from_headers, RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyBlobFromUrlResponse {
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub copy_id: String,
+    pub copy_status: CopyStatus,
+}
+
+impl CopyBlobFromUrlResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<CopyBlobFromUrlResponse, AzureError> {
+        let copy_status = headers
+            .get_as_enum(COPY_STATUS)?
+            .ok_or_else(|| AzureError::HeaderNotFound(COPY_STATUS.to_owned()))?;
+
+        Ok(CopyBlobFromUrlResponse {
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            copy_id: copy_id_from_headers(headers)?,
+            copy_status,
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use crate::blob::responses::GetBlobPropertiesResponse;
+use crate::blob::Blob;
+use azure_sdk_core::RequestId;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone)]
+pub struct DownloadBlobResponse {
+    /// Properties of the blob as they were when the download started.
+    pub blob: Blob,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub bytes_written: u64,
+}
+
+impl DownloadBlobResponse {
+    pub(crate) fn from_get_blob_properties(
+        response: GetBlobPropertiesResponse,
+    ) -> DownloadBlobResponse {
+        DownloadBlobResponse {
+            blob: response.blob,
+            request_id: response.request_id,
+            date: response.date,
+            bytes_written: 0,
+        }
+    }
+
+    pub(crate) fn with_bytes_written(self, bytes_written: u64) -> DownloadBlobResponse {
+        DownloadBlobResponse {
+            bytes_written,
+            ..self
+        }
+    }
+}
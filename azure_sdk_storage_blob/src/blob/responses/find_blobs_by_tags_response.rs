@@ -0,0 +1,33 @@
+use crate::blob::{tagged_blobs_from_response, TaggedBlob};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::str::from_utf8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindBlobsByTagsResponse {
+    pub incomplete_vector: IncompleteVector<TaggedBlob>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl FindBlobsByTagsResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<FindBlobsByTagsResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        let body = from_utf8(body)?;
+        let incomplete_vector = tagged_blobs_from_response(body)?;
+
+        Ok(FindBlobsByTagsResponse {
+            incomplete_vector,
+            request_id,
+            date,
+        })
+    }
+}
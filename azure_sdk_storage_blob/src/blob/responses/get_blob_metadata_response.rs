@@ -0,0 +1,41 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::META_PREFIX;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBlobMetadataResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    /// The metadata keys, without the `x-ms-meta-` prefix.
+    pub metadata: HashMap<String, String>,
+}
+
+impl GetBlobMetadataResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<GetBlobMetadataResponse, AzureError> {
+        let mut metadata = HashMap::new();
+        for (key, value) in headers {
+            if key.as_str().starts_with(META_PREFIX) {
+                metadata.insert(
+                    key.as_str()[META_PREFIX.len()..].to_owned(),
+                    value.to_str()?.to_owned(),
+                );
+            }
+        }
+
+        Ok(GetBlobMetadataResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            metadata,
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use crate::blob::Blob;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct GetBlobPropertiesResponse {
+    pub blob: Blob,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl GetBlobPropertiesResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+        blob: Blob,
+    ) -> Result<GetBlobPropertiesResponse, AzureError> {
+        Ok(GetBlobPropertiesResponse {
+            blob,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
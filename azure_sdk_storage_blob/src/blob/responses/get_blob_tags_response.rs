@@ -0,0 +1,33 @@
+use crate::blob::tags_from_xml;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::collections::HashMap;
+use std::str::from_utf8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBlobTagsResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub tags: HashMap<String, String>,
+}
+
+impl GetBlobTagsResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetBlobTagsResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        let body = from_utf8(body)?;
+        let tags = tags_from_xml(body)?;
+
+        Ok(GetBlobTagsResponse {
+            request_id,
+            date,
+            tags,
+        })
+    }
+}
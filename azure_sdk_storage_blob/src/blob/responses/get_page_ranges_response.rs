@@ -0,0 +1,44 @@
+use crate::blob::PageRangeList;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    blob_content_length_from_headers, date_from_headers, etag_from_headers,
+    last_modified_from_headers, request_id_from_headers, RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::str::from_utf8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetPageRangesResponse {
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub blob_content_length: u64,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub page_range_list: PageRangeList,
+}
+
+impl GetPageRangesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetPageRangesResponse, AzureError> {
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let blob_content_length = blob_content_length_from_headers(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        let body = from_utf8(body)?;
+        let page_range_list = PageRangeList::try_from(body)?;
+
+        Ok(GetPageRangesResponse {
+            etag,
+            last_modified,
+            blob_content_length,
+            request_id,
+            date,
+            page_range_list,
+        })
+    }
+}
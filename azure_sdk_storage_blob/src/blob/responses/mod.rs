@@ -28,3 +28,37 @@ mod update_page_response;
 pub use self::update_page_response::UpdatePageResponse;
 mod break_blob_lease_response;
 pub use self::break_blob_lease_response::BreakBlobLeaseResponse;
+mod upload_blob_response;
+pub use self::upload_blob_response::UploadBlobResponse;
+mod download_blob_response;
+pub use self::download_blob_response::DownloadBlobResponse;
+mod copy_blob_response;
+pub use self::copy_blob_response::CopyBlobResponse;
+mod copy_blob_from_url_response;
+pub use self::copy_blob_from_url_response::CopyBlobFromUrlResponse;
+mod abort_copy_blob_response;
+pub use self::abort_copy_blob_response::AbortCopyBlobResponse;
+mod get_blob_properties_response;
+pub use self::get_blob_properties_response::GetBlobPropertiesResponse;
+mod set_blob_properties_response;
+pub use self::set_blob_properties_response::SetBlobPropertiesResponse;
+mod get_blob_metadata_response;
+pub use self::get_blob_metadata_response::GetBlobMetadataResponse;
+mod set_blob_metadata_response;
+pub use self::set_blob_metadata_response::SetBlobMetadataResponse;
+mod snapshot_blob_response;
+pub use self::snapshot_blob_response::SnapshotBlobResponse;
+mod set_blob_tier_response;
+pub use self::set_blob_tier_response::SetBlobTierResponse;
+mod undelete_blob_response;
+pub use self::undelete_blob_response::UndeleteBlobResponse;
+mod get_page_ranges_response;
+pub use self::get_page_ranges_response::GetPageRangesResponse;
+mod blob_batch_response;
+pub use self::blob_batch_response::{BlobBatchResponse, BlobBatchSubResponse};
+mod set_blob_tags_response;
+pub use self::set_blob_tags_response::SetBlobTagsResponse;
+mod get_blob_tags_response;
+pub use self::get_blob_tags_response::GetBlobTagsResponse;
+mod find_blobs_by_tags_response;
+pub use self::find_blobs_by_tags_response::FindBlobsByTagsResponse;
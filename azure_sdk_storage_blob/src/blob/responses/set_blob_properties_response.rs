@@ -0,0 +1,33 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::BLOB_SEQUENCE_NUMBER;
+use azure_sdk_core::util::HeaderMapExt;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetBlobPropertiesResponse {
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    /// The sequence number of the blob, for page blobs only.
+    pub blob_sequence_number: Option<u64>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl SetBlobPropertiesResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+    ) -> Result<SetBlobPropertiesResponse, AzureError> {
+        Ok(SetBlobPropertiesResponse {
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            blob_sequence_number: headers.get_as_u64(BLOB_SEQUENCE_NUMBER),
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetBlobTierResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    /// `true` if the blob is being rehydrated out of the archive tier.
+    pub rehydration_pending: bool,
+}
+
+impl SetBlobTierResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+        rehydration_pending: bool,
+    ) -> Result<SetBlobTierResponse, AzureError> {
+        Ok(SetBlobTierResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            rehydration_pending,
+        })
+    }
+}
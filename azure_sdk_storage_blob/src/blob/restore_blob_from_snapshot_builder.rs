@@ -0,0 +1,439 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::requests::CopyBlobBuilder;
+use crate::blob::responses::CopyBlobResponse;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::modify_conditions::{IfMatchCondition, IfSinceCondition};
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, CopySourceSupport, IfMatchConditionOption,
+    IfMatchConditionSupport, IfSinceConditionOption, IfSinceConditionSupport, LeaseIdOption,
+    LeaseIdSupport, No, SnapshotRequired, SnapshotSupport, TimeoutOption, TimeoutSupport, ToAssign,
+    Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Utc};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_snapshot: PhantomData<SnapshotSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    snapshot: Option<DateTime<Utc>>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> RestoreBlobFromSnapshotBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> RestoreBlobFromSnapshotBuilder<'a, No, No, No> {
+        RestoreBlobFromSnapshotBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_snapshot: PhantomData {},
+            snapshot: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> ClientRequired<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, SnapshotSet> ContainerNameRequired<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, Yes, BlobNameSet, SnapshotSet>
+where
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, SnapshotSet> BlobNameRequired<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, Yes, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> SnapshotRequired
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn snapshot(&self) -> DateTime<Utc> {
+        self.snapshot.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> IfSinceConditionOption
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> IfMatchConditionOption<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> LeaseIdOption<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> TimeoutOption
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> ClientRequestIdOption<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> ContainerNameSupport<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, Yes, BlobNameSet, SnapshotSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> BlobNameSupport<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, Yes, SnapshotSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            snapshot: self.snapshot,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> SnapshotSupport
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_snapshot(self, snapshot: DateTime<Utc>) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: Some(snapshot),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> IfSinceConditionSupport
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> IfMatchConditionSupport<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> LeaseIdSupport<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> TimeoutSupport
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet> ClientRequestIdSupport<'a>
+    for RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+    type O = RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        RestoreBlobFromSnapshotBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_snapshot: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+    RestoreBlobFromSnapshotBuilder<'a, ContainerNameSet, BlobNameSet, SnapshotSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SnapshotSet: ToAssign,
+{
+}
+
+impl<'a> RestoreBlobFromSnapshotBuilder<'a, Yes, Yes, Yes> {
+    /// Overwrites the base blob with the content, properties and metadata
+    /// of its `snapshot`, with a server-side copy. The access conditions
+    /// apply to the base blob. The copy is usually complete when this
+    /// returns; if `copy_status` is still pending, use `wait_for_blob_copy`.
+    pub async fn finalize(self) -> Result<CopyBlobResponse, AzureError> {
+        let source = generate_blob_uri(&self, Some(&SnapshotRequired::to_uri_parameter(&self)));
+
+        let mut request = CopyBlobBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name())
+            .with_copy_source(&source);
+
+        if let Some(if_since_condition) = self.if_since_condition() {
+            request = request.with_if_since_condition(if_since_condition);
+        }
+        if let Some(if_match_condition) = self.if_match_condition() {
+            request = request.with_if_match_condition(if_match_condition);
+        }
+        if let Some(lease_id) = self.lease_id() {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+
+        request.finalize().await
+    }
+}
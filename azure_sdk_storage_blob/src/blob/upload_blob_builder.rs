@@ -0,0 +1,941 @@
+use crate::blob::requests::{PutBlockBlobBuilder, PutBlockBuilder, PutBlockListBuilder};
+use crate::blob::responses::UploadBlobResponse;
+use crate::blob::{
+    BlobBlockType, BlockList, BlockListSupport, BlockSizeOption, BlockSizeSupport,
+    MaxConcurrencyOption, MaxConcurrencySupport,
+};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, BlockIdSupport, BodySupport, CacheControlOption,
+    CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired,
+    ContainerNameSupport, ContentDispositionOption, ContentDispositionSupport,
+    ContentEncodingOption, ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport,
+    ContentMD5Support, ContentTypeOption, ContentTypeSupport, LeaseIdOption, LeaseIdSupport,
+    MetadataOption, MetadataSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use futures::io::{AsyncRead, AsyncReadExt};
+use futures::stream::{Stream, StreamExt, TryStreamExt};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+// The maximum number of blocks a block blob can be made of.
+const MAX_BLOCKS: usize = 50_000;
+
+#[derive(Debug, Clone)]
+pub struct UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    block_size: u64,
+    max_concurrency: usize,
+    content_type: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> UploadBlobBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> UploadBlobBuilder<'a, No, No> {
+        UploadBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            block_size: 4 * 1024 * 1024,
+            max_concurrency: 4,
+            content_type: None,
+            content_encoding: None,
+            content_language: None,
+            cache_control: None,
+            content_disposition: None,
+            metadata: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequired<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet> ContainerNameRequired<'a> for UploadBlobBuilder<'a, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> BlobNameRequired<'a> for UploadBlobBuilder<'a, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlockSizeOption
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn block_size(&self) -> u64 {
+        self.block_size
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxConcurrencyOption
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentTypeOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentEncodingOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentLanguageOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> CacheControlOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentDispositionOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MetadataOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutOption
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContainerNameSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlobNameSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlockSizeSupport
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_block_size(self, block_size: u64) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxConcurrencySupport
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_concurrency(self, max_concurrency: usize) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentTypeSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_type(self, content_type: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: Some(content_type),
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentEncodingSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_encoding(self, content_encoding: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: Some(content_encoding),
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentLanguageSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_language(self, content_language: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: Some(content_language),
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> CacheControlSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_cache_control(self, cache_control: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: Some(cache_control),
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContentDispositionSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_disposition(self, content_disposition: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: Some(content_disposition),
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MetadataSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: Some(metadata),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            max_concurrency: self.max_concurrency,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet> UploadBlobBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+}
+
+impl<'a> UploadBlobBuilder<'a, Yes, Yes> {
+    /// Uploads everything `reader` yields as a block blob. The payload is
+    /// split in blocks of `block_size` bytes, uploaded with at most
+    /// `max_concurrency` Put Block in flight, and then committed with Put
+    /// Block List. A payload fitting in a single block is sent with a
+    /// single Put Blob instead.
+    ///
+    /// Fails without sending anything if the block size or the concurrency
+    /// is zero, and before committing if the payload needs more than 50,000
+    /// blocks.
+    pub async fn finalize<R>(self, reader: R) -> Result<UploadBlobResponse, AzureError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        check_upload_parameters(self.block_size(), self.max_concurrency())?;
+
+        let mut reader = reader;
+        let block_size = self.block_size() as usize;
+
+        let first = read_block(&mut reader, block_size).await?;
+        let second = if first.len() == block_size {
+            read_block(&mut reader, block_size).await?
+        } else {
+            Vec::new()
+        };
+
+        if second.is_empty() {
+            return self.put_single_blob(&first).await;
+        }
+
+        let blocks = read_blocks(vec![first, second], reader, block_size);
+
+        let builder = &self;
+        let block_count = blocks
+            .enumerate()
+            .map(|(index, block)| async move {
+                builder.put_block(index, &block?).await?;
+                Ok::<_, AzureError>(())
+            })
+            .buffer_unordered(self.max_concurrency())
+            .try_fold(0, |block_count, _| futures::future::ok(block_count + 1))
+            .await?;
+
+        self.put_block_list(block_count).await
+    }
+
+    /// Same as `finalize` for a stream of chunks of any size, for example
+    /// the body of another HTTP response.
+    pub async fn finalize_stream<S, B>(self, stream: S) -> Result<UploadBlobResponse, AzureError>
+    where
+        S: Stream<Item = Result<B, std::io::Error>> + Unpin,
+        B: AsRef<[u8]>,
+    {
+        self.finalize(stream.into_async_read()).await
+    }
+
+    async fn put_single_blob(&self, body: &[u8]) -> Result<UploadBlobResponse, AzureError> {
+        let md5 = md5::compute(body);
+
+        let mut request = PutBlockBlobBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name())
+            .with_body(body)
+            .with_content_md5(&md5[..]);
+
+        if let Some(content_type) = self.content_type() {
+            request = request.with_content_type(content_type);
+        }
+        if let Some(content_encoding) = self.content_encoding() {
+            request = request.with_content_encoding(content_encoding);
+        }
+        if let Some(content_language) = self.content_language() {
+            request = request.with_content_language(content_language);
+        }
+        if let Some(cache_control) = self.cache_control() {
+            request = request.with_cache_control(cache_control);
+        }
+        if let Some(content_disposition) = self.content_disposition() {
+            request = request.with_content_disposition(content_disposition);
+        }
+        if let Some(metadata) = self.metadata() {
+            request = request.with_metadata(metadata);
+        }
+        if let Some(lease_id) = self.lease_id() {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+
+        let response = request.finalize().await?;
+        Ok(UploadBlobResponse::from_put_block_blob(response))
+    }
+
+    async fn put_block(&self, index: usize, body: &[u8]) -> Result<(), AzureError> {
+        let block_id = block_id(index);
+        let md5 = md5::compute(body);
+
+        let mut request = PutBlockBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name())
+            .with_block_id(&block_id)
+            .with_body(body)
+            .with_content_md5(&md5[..]);
+
+        if let Some(lease_id) = self.lease_id() {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+
+        request.finalize().await?;
+        Ok(())
+    }
+
+    async fn put_block_list(&self, block_count: usize) -> Result<UploadBlobResponse, AzureError> {
+        let block_list = BlockList {
+            blocks: (0..block_count)
+                .map(|index| BlobBlockType::Uncommitted(block_id(index)))
+                .collect(),
+        };
+
+        let mut request = PutBlockListBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name())
+            .with_block_list(&block_list);
+
+        if let Some(content_type) = self.content_type() {
+            request = request.with_content_type(content_type);
+        }
+        if let Some(content_encoding) = self.content_encoding() {
+            request = request.with_content_encoding(content_encoding);
+        }
+        if let Some(content_language) = self.content_language() {
+            request = request.with_content_language(content_language);
+        }
+        if let Some(cache_control) = self.cache_control() {
+            request = request.with_cache_control(cache_control);
+        }
+        if let Some(content_disposition) = self.content_disposition() {
+            request = request.with_content_disposition(content_disposition);
+        }
+        if let Some(metadata) = self.metadata() {
+            request = request.with_metadata(metadata);
+        }
+        if let Some(lease_id) = self.lease_id() {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+
+        let response = request.finalize().await?;
+        Ok(UploadBlobResponse::from_put_block_list(
+            response,
+            block_count,
+        ))
+    }
+}
+
+// The block ids of a blob must all have the same length.
+#[inline]
+fn block_id(index: usize) -> Vec<u8> {
+    format!("{:016x}", index).into_bytes()
+}
+
+fn check_upload_parameters(block_size: u64, max_concurrency: usize) -> Result<(), AzureError> {
+    if block_size == 0 {
+        return Err(AzureError::InputParametersError(
+            "the block size must be greater than zero".to_owned(),
+        ));
+    }
+    if max_concurrency == 0 {
+        return Err(AzureError::InputParametersError(
+            "the maximum concurrency must be greater than zero".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+// The blocks already read followed by the rest of the payload. The blocks
+// are read lazily so at most max_concurrency blocks are kept in memory, and
+// reading fails as soon as the payload exceeds MAX_BLOCKS blocks.
+fn read_blocks<R>(
+    read: Vec<Vec<u8>>,
+    reader: R,
+    block_size: usize,
+) -> impl Stream<Item = Result<Vec<u8>, AzureError>>
+where
+    R: AsyncRead + Unpin,
+{
+    let already_read = read.len();
+    futures::stream::iter(read.into_iter().map(Ok)).chain(futures::stream::try_unfold(
+        (reader, already_read),
+        move |(mut reader, block_count)| async move {
+            let block = read_block(&mut reader, block_size).await?;
+            if block.is_empty() {
+                Ok(None)
+            } else if block_count == MAX_BLOCKS {
+                Err(AzureError::InputParametersError(format!(
+                    "the payload needs more than {} blocks of {} bytes",
+                    MAX_BLOCKS, block_size
+                )))
+            } else {
+                Ok(Some((block, (reader, block_count + 1))))
+            }
+        },
+    ))
+}
+
+// Fills a block reading as many times as needed: a short block is returned
+// only at the end of the payload.
+async fn read_block<R>(reader: &mut R, block_size: usize) -> Result<Vec<u8>, AzureError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut block = vec![0; block_size];
+    let mut filled = 0;
+
+    while filled < block_size {
+        let read = reader.read(&mut block[filled..]).await?;
+        if read == 0 {
+            break;
+        }
+        filled += read;
+    }
+
+    block.truncate(filled);
+    Ok(block)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_block_fills_across_chunks() {
+        let chunks: Vec<Result<&[u8], std::io::Error>> = vec![
+            Ok(&b"abc"[..]),
+            Ok(&b"de"[..]),
+            Ok(&b"fghij"[..]),
+            Ok(&b"k"[..]),
+        ];
+        let mut reader = futures::stream::iter(chunks).into_async_read();
+
+        futures::executor::block_on(async {
+            assert_eq!(read_block(&mut reader, 4).await.unwrap(), b"abcd");
+            assert_eq!(read_block(&mut reader, 4).await.unwrap(), b"efgh");
+            assert_eq!(read_block(&mut reader, 4).await.unwrap(), b"ijk");
+            assert!(read_block(&mut reader, 4).await.unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn upload_parameters_are_checked() {
+        assert!(check_upload_parameters(4 * 1024 * 1024, 4).is_ok());
+        assert!(check_upload_parameters(0, 4).is_err());
+        assert!(check_upload_parameters(4 * 1024 * 1024, 0).is_err());
+    }
+
+    #[test]
+    fn payload_fits_the_block_limit() {
+        let payload = vec![0u8; MAX_BLOCKS];
+        let blocks: Vec<_> =
+            futures::executor::block_on(read_blocks(Vec::new(), &payload[..], 1).collect());
+        assert_eq!(blocks.len(), MAX_BLOCKS);
+        assert!(blocks.iter().all(|block| block.is_ok()));
+
+        let payload = vec![0u8; MAX_BLOCKS + 1];
+        let result: Result<Vec<_>, _> =
+            futures::executor::block_on(read_blocks(Vec::new(), &payload[..], 1).try_collect());
+        match result {
+            Err(AzureError::InputParametersError(_)) => {}
+            r => panic!("unexpected result {:?}", r.map(|blocks| blocks.len())),
+        }
+    }
+
+    #[test]
+    fn block_ids_have_the_same_length() {
+        assert_eq!(block_id(0).len(), block_id(49_999).len());
+    }
+}
@@ -0,0 +1,326 @@
+use crate::blob::requests::GetBlobPropertiesBuilder;
+use crate::blob::{
+    Blob, CopyStatus, MaxPollsOption, MaxPollsSupport, PollIntervalOption, PollIntervalSupport,
+};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    BlobNameRequired, BlobNameSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, No, TimeoutOption, TimeoutSupport, ToAssign, Yes,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    poll_interval: Duration,
+    max_polls: u32,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> WaitForBlobCopyBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> WaitForBlobCopyBuilder<'a, No, No> {
+        WaitForBlobCopyBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            poll_interval: Duration::from_secs(1),
+            max_polls: 3600,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequired<'a>
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet> ContainerNameRequired<'a> for WaitForBlobCopyBuilder<'a, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> BlobNameRequired<'a>
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> PollIntervalOption
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxPollsOption
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn max_polls(&self) -> u32 {
+        self.max_polls
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutOption
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ContainerNameSupport<'a>
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = WaitForBlobCopyBuilder<'a, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        WaitForBlobCopyBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            poll_interval: self.poll_interval,
+            max_polls: self.max_polls,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BlobNameSupport<'a>
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = WaitForBlobCopyBuilder<'a, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        WaitForBlobCopyBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            poll_interval: self.poll_interval,
+            max_polls: self.max_polls,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> PollIntervalSupport
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_poll_interval(self, poll_interval: Duration) -> Self::O {
+        WaitForBlobCopyBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            poll_interval,
+            max_polls: self.max_polls,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> MaxPollsSupport
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_polls(self, max_polls: u32) -> Self::O {
+        WaitForBlobCopyBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            poll_interval: self.poll_interval,
+            max_polls,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        WaitForBlobCopyBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            poll_interval: self.poll_interval,
+            max_polls: self.max_polls,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    type O = WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        WaitForBlobCopyBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            poll_interval: self.poll_interval,
+            max_polls: self.max_polls,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet> WaitForBlobCopyBuilder<'a, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+}
+
+impl<'a> WaitForBlobCopyBuilder<'a, Yes, Yes> {
+    /// Polls the properties of the blob every `poll_interval` until its
+    /// copy status is no longer `CopyStatus::Pending` and returns them.
+    /// Check `copy_status` and `copy_status_description` of the returned
+    /// blob to know whether the copy succeeded.
+    ///
+    /// Gives up with an error if the copy is still pending after `max_polls`
+    /// polls (3600 by default, one hour with the default poll interval). The
+    /// copy itself goes on and can be aborted with Abort Copy Blob.
+    pub async fn finalize(self) -> Result<Blob, AzureError> {
+        let mut polls = 0;
+        loop {
+            let blob = self.get_properties().await?;
+            polls += 1;
+            match blob.copy_status {
+                Some(CopyStatus::Pending) if polls >= self.max_polls() => {
+                    return Err(AzureError::GenericErrorWithText(format!(
+                        "copy {:?} still pending after {} polls: {:?}",
+                        blob.copy_id, polls, blob.copy_progress
+                    )));
+                }
+                Some(CopyStatus::Pending) => {
+                    trace!("copy {:?} pending: {:?}", blob.copy_id, blob.copy_progress);
+                    tokio::timer::delay_for(self.poll_interval()).await;
+                }
+                _ => return Ok(blob),
+            }
+        }
+    }
+
+    async fn get_properties(&self) -> Result<Blob, AzureError> {
+        let mut request = GetBlobPropertiesBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name());
+
+        if let Some(timeout) = self.timeout() {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            request = request.with_client_request_id(client_request_id);
+        }
+
+        Ok(request.finalize().await?.blob)
+    }
+}
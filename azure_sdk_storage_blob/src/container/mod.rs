@@ -146,7 +146,10 @@ impl Container {
         let mut metadata: HashMap<String, String> = HashMap::new();
         for (key, value) in headers {
             if key.as_str().starts_with(META_PREFIX) {
-                metadata.insert(key.as_str().to_owned(), value.to_str()?.to_owned());
+                metadata.insert(
+                    key.as_str()[META_PREFIX.len()..].to_owned(),
+                    value.to_str()?.to_owned(),
+                );
             }
         }
 
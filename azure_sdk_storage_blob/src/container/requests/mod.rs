@@ -18,3 +18,5 @@ mod renew_lease_builder;
 pub use self::renew_lease_builder::RenewLeaseBuilder;
 mod set_acl_builder;
 pub use self::set_acl_builder::SetACLBuilder;
+mod set_metadata_builder;
+pub use self::set_metadata_builder::SetMetadataBuilder;
@@ -0,0 +1,275 @@
+use crate::container::responses::SetMetadataResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::modify_conditions::IfSinceCondition;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
+    IfSinceConditionOption, IfSinceConditionSupport, LeaseIdOption, LeaseIdSupport, MetadataOption,
+    MetadataSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    container_name: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    if_since_condition: Option<IfSinceCondition>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetMetadataBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetMetadataBuilder<'a, No> {
+        SetMetadataBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            metadata: None,
+            if_since_condition: None,
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequired<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ContainerNameRequired<'a> for SetMetadataBuilder<'a, Yes> {
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> MetadataOption<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ContainerNameSet> IfSinceConditionOption for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition.clone()
+    }
+}
+
+impl<'a, ContainerNameSet> LeaseIdOption<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutOption for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdOption<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet> ContainerNameSupport<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = SetMetadataBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        SetMetadataBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: Some(container_name),
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> MetadataSupport<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = SetMetadataBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        SetMetadataBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            metadata: Some(metadata),
+            if_since_condition: self.if_since_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> IfSinceConditionSupport for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = SetMetadataBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        SetMetadataBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            metadata: self.metadata,
+            if_since_condition: Some(if_since_condition),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> LeaseIdSupport<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = SetMetadataBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        SetMetadataBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutSupport for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = SetMetadataBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetMetadataBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdSupport<'a> for SetMetadataBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = SetMetadataBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetMetadataBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            metadata: self.metadata,
+            if_since_condition: self.if_since_condition,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet> SetMetadataBuilder<'a, ContainerNameSet> where ContainerNameSet: ToAssign {}
+
+impl<'a> SetMetadataBuilder<'a, Yes> {
+    /// Replaces all the metadata of the container: without `metadata` the
+    /// existing metadata is removed. The service only honors the
+    /// `IfSinceCondition::Modified` condition.
+    pub async fn finalize(self) -> Result<SetMetadataResponse, AzureError> {
+        let mut uri = format!(
+            "{}/{}?restype=container&comp=metadata",
+            self.client().blob_uri(),
+            self.container_name()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+                LeaseIdOption::add_header(&self, request);
+                IfSinceConditionOption::add_header(&self, request);
+                MetadataOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        SetMetadataResponse::from_headers(&headers)
+    }
+}
@@ -5,6 +5,7 @@ use crate::container::Container;
 use chrono::{DateTime, FixedOffset};
 use http::HeaderMap;
 use hyper::header;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -37,4 +38,17 @@ impl GetPropertiesResponse {
             date,
         })
     }
+
+    /// The user defined metadata of the container, without the `x-ms-meta-` prefix.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.container.metadata
+    }
+
+    pub fn has_immutability_policy(&self) -> bool {
+        self.container.has_immutability_policy
+    }
+
+    pub fn has_legal_hold(&self) -> bool {
+        self.container.has_legal_hold
+    }
 }
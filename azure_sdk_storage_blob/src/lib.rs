@@ -21,9 +21,16 @@ pub trait Blob {
     fn put_page_blob<'a>(&'a self) -> blob::requests::PutPageBlobBuilder<'a, No, No, No>;
     fn put_append_blob<'a>(&'a self) -> blob::requests::PutAppendBlobBuilder<'a, No, No>;
     fn put_append_block<'a>(&'a self) -> blob::requests::PutAppendBlockBuilder<'a, No, No, No>;
+    fn append_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::AppendBlockFromUrlBuilder<'a, No, No, No>;
     fn update_page<'a>(&'a self) -> blob::requests::UpdatePageBuilder<'a, No, No, No, No>;
     fn clear_page<'a>(&'a self) -> blob::requests::ClearPageBuilder<'a, No, No, No>;
+    fn get_page_ranges<'a>(&'a self) -> blob::requests::GetPageRangesBuilder<'a, No, No>;
     fn put_block<'a>(&'a self) -> blob::requests::PutBlockBuilder<'a, No, No, No, No>;
+    fn put_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::PutBlockFromUrlBuilder<'a, No, No, No, No>;
     fn get_block_list<'a>(&'a self) -> blob::requests::GetBlockListBuilder<'a, No, No, No>;
     fn put_block_list<'a, T: Borrow<[u8]> + 'a>(
         &'a self,
@@ -41,6 +48,28 @@ pub trait Blob {
     fn delete_blob<'a>(&'a self) -> blob::requests::DeleteBlobBuilder<'a, No, No, No>;
     fn stream_list_blobs<'a>(&'a self) -> blob::ListBlobStreamBuilder<'a, No>;
     fn stream_blob<'a>(&'a self) -> blob::BlobStreamBuilder<'a, No, No, No>;
+    fn upload_blob<'a>(&'a self) -> blob::UploadBlobBuilder<'a, No, No>;
+    fn download_blob<'a>(&'a self) -> blob::DownloadBlobBuilder<'a, No, No>;
+    fn copy_blob<'a>(&'a self) -> blob::requests::CopyBlobBuilder<'a, No, No, No>;
+    fn copy_blob_from_url<'a>(&'a self) -> blob::requests::CopyBlobFromUrlBuilder<'a, No, No, No>;
+    fn abort_copy_blob<'a>(&'a self) -> blob::requests::AbortCopyBlobBuilder<'a, No, No, No>;
+    fn wait_for_blob_copy<'a>(&'a self) -> blob::WaitForBlobCopyBuilder<'a, No, No>;
+    fn get_blob_properties<'a>(&'a self) -> blob::requests::GetBlobPropertiesBuilder<'a, No, No>;
+    fn set_blob_properties<'a>(&'a self) -> blob::requests::SetBlobPropertiesBuilder<'a, No, No>;
+    fn get_blob_metadata<'a>(&'a self) -> blob::requests::GetBlobMetadataBuilder<'a, No, No>;
+    fn set_blob_metadata<'a>(&'a self) -> blob::requests::SetBlobMetadataBuilder<'a, No, No>;
+    fn snapshot_blob<'a>(&'a self) -> blob::requests::SnapshotBlobBuilder<'a, No, No>;
+    fn restore_blob_from_snapshot<'a>(
+        &'a self,
+    ) -> blob::RestoreBlobFromSnapshotBuilder<'a, No, No, No>;
+    fn set_blob_tier<'a>(&'a self) -> blob::requests::SetBlobTierBuilder<'a, No, No, No>;
+    fn move_old_blobs_to_tier<'a>(&'a self) -> blob::MoveOldBlobsToTierBuilder<'a, No, No, No>;
+    fn undelete_blob<'a>(&'a self) -> blob::requests::UndeleteBlobBuilder<'a, No, No>;
+    fn recover_deleted_blobs<'a>(&'a self) -> blob::RecoverDeletedBlobsBuilder<'a, No>;
+    fn blob_batch<'a>(&'a self) -> blob::requests::BlobBatchBuilder<'a, No>;
+    fn set_blob_tags<'a>(&'a self) -> blob::requests::SetBlobTagsBuilder<'a, No, No, No>;
+    fn get_blob_tags<'a>(&'a self) -> blob::requests::GetBlobTagsBuilder<'a, No, No>;
+    fn find_blobs_by_tags<'a>(&'a self) -> blob::FindBlobsByTagsStreamBuilder<'a, No>;
     fn generate_signed_blob_url<'a>(&'a self) -> blob::SignedUrlBuilder<'a, No, No, No>;
 }
 
@@ -51,6 +80,7 @@ pub trait Container {
     fn get_container_acl<'a>(&'a self) -> container::requests::GetACLBuilder<'a, No>;
     fn set_container_acl<'a>(&'a self) -> container::requests::SetACLBuilder<'a, No, No>;
     fn get_container_properties<'a>(&'a self) -> container::requests::GetPropertiesBuilder<'a, No>;
+    fn set_container_metadata<'a>(&'a self) -> container::requests::SetMetadataBuilder<'a, No>;
     fn acquire_container_lease<'a>(
         &'a self,
     ) -> container::requests::AcquireLeaseBuilder<'a, No, No>;
@@ -86,6 +116,12 @@ impl Blob for Client {
         blob::requests::PutAppendBlockBuilder::new(self)
     }
 
+    fn append_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::AppendBlockFromUrlBuilder<'a, No, No, No> {
+        blob::requests::AppendBlockFromUrlBuilder::new(self)
+    }
+
     fn update_page<'a>(&'a self) -> blob::requests::UpdatePageBuilder<'a, No, No, No, No> {
         blob::requests::UpdatePageBuilder::new(self)
     }
@@ -94,10 +130,20 @@ impl Blob for Client {
         blob::requests::ClearPageBuilder::new(self)
     }
 
+    fn get_page_ranges<'a>(&'a self) -> blob::requests::GetPageRangesBuilder<'a, No, No> {
+        blob::requests::GetPageRangesBuilder::new(self)
+    }
+
     fn put_block<'a>(&'a self) -> blob::requests::PutBlockBuilder<'a, No, No, No, No> {
         blob::requests::PutBlockBuilder::new(self)
     }
 
+    fn put_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::PutBlockFromUrlBuilder<'a, No, No, No, No> {
+        blob::requests::PutBlockFromUrlBuilder::new(self)
+    }
+
     fn get_block_list<'a>(&'a self) -> blob::requests::GetBlockListBuilder<'a, No, No, No> {
         blob::requests::GetBlockListBuilder::new(self)
     }
@@ -147,6 +193,88 @@ impl Blob for Client {
     fn stream_blob<'a>(&'a self) -> blob::BlobStreamBuilder<'a, No, No, No> {
         blob::BlobStreamBuilder::new(self)
     }
+
+    fn upload_blob<'a>(&'a self) -> blob::UploadBlobBuilder<'a, No, No> {
+        blob::UploadBlobBuilder::new(self)
+    }
+
+    fn download_blob<'a>(&'a self) -> blob::DownloadBlobBuilder<'a, No, No> {
+        blob::DownloadBlobBuilder::new(self)
+    }
+
+    fn copy_blob<'a>(&'a self) -> blob::requests::CopyBlobBuilder<'a, No, No, No> {
+        blob::requests::CopyBlobBuilder::new(self)
+    }
+
+    fn copy_blob_from_url<'a>(&'a self) -> blob::requests::CopyBlobFromUrlBuilder<'a, No, No, No> {
+        blob::requests::CopyBlobFromUrlBuilder::new(self)
+    }
+
+    fn abort_copy_blob<'a>(&'a self) -> blob::requests::AbortCopyBlobBuilder<'a, No, No, No> {
+        blob::requests::AbortCopyBlobBuilder::new(self)
+    }
+
+    fn wait_for_blob_copy<'a>(&'a self) -> blob::WaitForBlobCopyBuilder<'a, No, No> {
+        blob::WaitForBlobCopyBuilder::new(self)
+    }
+
+    fn get_blob_properties<'a>(&'a self) -> blob::requests::GetBlobPropertiesBuilder<'a, No, No> {
+        blob::requests::GetBlobPropertiesBuilder::new(self)
+    }
+
+    fn set_blob_properties<'a>(&'a self) -> blob::requests::SetBlobPropertiesBuilder<'a, No, No> {
+        blob::requests::SetBlobPropertiesBuilder::new(self)
+    }
+
+    fn get_blob_metadata<'a>(&'a self) -> blob::requests::GetBlobMetadataBuilder<'a, No, No> {
+        blob::requests::GetBlobMetadataBuilder::new(self)
+    }
+
+    fn set_blob_metadata<'a>(&'a self) -> blob::requests::SetBlobMetadataBuilder<'a, No, No> {
+        blob::requests::SetBlobMetadataBuilder::new(self)
+    }
+
+    fn snapshot_blob<'a>(&'a self) -> blob::requests::SnapshotBlobBuilder<'a, No, No> {
+        blob::requests::SnapshotBlobBuilder::new(self)
+    }
+
+    fn restore_blob_from_snapshot<'a>(
+        &'a self,
+    ) -> blob::RestoreBlobFromSnapshotBuilder<'a, No, No, No> {
+        blob::RestoreBlobFromSnapshotBuilder::new(self)
+    }
+
+    fn set_blob_tier<'a>(&'a self) -> blob::requests::SetBlobTierBuilder<'a, No, No, No> {
+        blob::requests::SetBlobTierBuilder::new(self)
+    }
+
+    fn move_old_blobs_to_tier<'a>(&'a self) -> blob::MoveOldBlobsToTierBuilder<'a, No, No, No> {
+        blob::MoveOldBlobsToTierBuilder::new(self)
+    }
+
+    fn undelete_blob<'a>(&'a self) -> blob::requests::UndeleteBlobBuilder<'a, No, No> {
+        blob::requests::UndeleteBlobBuilder::new(self)
+    }
+
+    fn recover_deleted_blobs<'a>(&'a self) -> blob::RecoverDeletedBlobsBuilder<'a, No> {
+        blob::RecoverDeletedBlobsBuilder::new(self)
+    }
+
+    fn blob_batch<'a>(&'a self) -> blob::requests::BlobBatchBuilder<'a, No> {
+        blob::requests::BlobBatchBuilder::new(self)
+    }
+
+    fn set_blob_tags<'a>(&'a self) -> blob::requests::SetBlobTagsBuilder<'a, No, No, No> {
+        blob::requests::SetBlobTagsBuilder::new(self)
+    }
+
+    fn get_blob_tags<'a>(&'a self) -> blob::requests::GetBlobTagsBuilder<'a, No, No> {
+        blob::requests::GetBlobTagsBuilder::new(self)
+    }
+
+    fn find_blobs_by_tags<'a>(&'a self) -> blob::FindBlobsByTagsStreamBuilder<'a, No> {
+        blob::FindBlobsByTagsStreamBuilder::new(self)
+    }
     fn generate_signed_blob_url<'a>(&'a self) -> blob::SignedUrlBuilder<'a, No, No, No> {
         blob::SignedUrlBuilder::new(self)
     }
@@ -177,6 +305,10 @@ impl Container for Client {
         container::requests::GetPropertiesBuilder::new(self)
     }
 
+    fn set_container_metadata<'a>(&'a self) -> container::requests::SetMetadataBuilder<'a, No> {
+        container::requests::SetMetadataBuilder::new(self)
+    }
+
     fn acquire_container_lease<'a>(
         &'a self,
     ) -> container::requests::AcquireLeaseBuilder<'a, No, No> {
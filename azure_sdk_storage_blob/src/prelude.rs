@@ -1,5 +1,9 @@
 pub use crate::blob::{
-    BlobBlockType, BlockList, BlockListRequired, BlockListSupport, BlockListType, BlockListTypeRequired, BlockListTypeSupport,
+    ArchiveStatus, BlobBatch, BlobBatchSupport, BlobBlockType, BlockList, BlockListRequired, BlockListSupport,
+    BlockListType, BlockListTypeRequired, BlockListTypeSupport, BlockSizeSupport, MaxConcurrencySupport,
+    MaxPollsSupport, MaxRetriesSupport, OlderThanSupport, PollIntervalSupport, RehydratePriority,
+    RehydratePrioritySupport, SequenceNumberAction, SequenceNumberActionSupport, TagFilterSupport, TaggedBlob,
+    ValidateMD5Support,
 };
 pub use crate::container::{PublicAccess, PublicAccessSupport};
 pub use crate::{Blob as BlobTrait, Container as ContainerTrait};
@@ -6,7 +6,7 @@ use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::prelude::*;
 use azure_sdk_core::DeleteSnapshotsMethod;
 use azure_sdk_storage_blob::{
-    blob::BlockListType,
+    blob::{BlockListType, CopyStatus},
     container::{Container, PublicAccess, PublicAccessSupport},
     prelude::*,
 };
@@ -328,6 +328,665 @@ async fn put_block_blob() {
     trace!("created {:?}", blob_name);
 }
 
+#[tokio::test]
+async fn upload_blob() {
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+    let data: Vec<u8> = (0..2600).map(|i| (i % 251) as u8).collect();
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    // 2600 bytes in blocks of 1024 bytes gives three blocks
+    let res = client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("chunked")
+        .with_block_size(1024)
+        .with_max_concurrency(2)
+        .with_content_type("application/octet-stream")
+        .finalize(futures::io::Cursor::new(data.clone()))
+        .await
+        .unwrap();
+    assert_eq!(res.block_count, 3);
+
+    let res = client
+        .get_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("chunked")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.data, data);
+
+    // a payload smaller than a block is sent with a single put
+    let res = client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("single")
+        .finalize(futures::io::Cursor::new(b"abcdef".to_vec()))
+        .await
+        .unwrap();
+    assert_eq!(res.block_count, 0);
+}
+
+#[tokio::test]
+async fn download_blob() {
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+    let data: Vec<u8> = (0..2600).map(|i| (i % 251) as u8).collect();
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("download")
+        .with_block_size(1024)
+        .finalize(futures::io::Cursor::new(data.clone()))
+        .await
+        .unwrap();
+
+    let mut downloaded = futures::io::Cursor::new(Vec::new());
+    let res = client
+        .download_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("download")
+        .with_block_size(1000)
+        .with_max_concurrency(2)
+        .with_validate_md5()
+        .finalize(&mut downloaded)
+        .await
+        .unwrap();
+    assert_eq!(res.bytes_written, 2600);
+    assert_eq!(downloaded.into_inner(), data);
+
+    let path = std::env::temp_dir().join(format!("{}.bin", Uuid::new_v4()));
+    let file = std::fs::File::create(&path).unwrap();
+    let res = client
+        .download_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("download")
+        .with_block_size(1000)
+        .finalize_to_file(&file)
+        .await
+        .unwrap();
+    assert_eq!(res.bytes_written, 2600);
+    assert_eq!(std::fs::read(&path).unwrap(), data);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn copy_blob() {
+    let client = initialize().unwrap();
+
+    // the container allows public read access so the source URL needs no SAS
+    let container_name: &'static str = "rust-upload-test";
+    let data = b"copy me".to_vec();
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("copy_source")
+        .finalize(futures::io::Cursor::new(data.clone()))
+        .await
+        .unwrap();
+
+    let source = format!("{}/{}/copy_source", client.blob_uri(), container_name);
+
+    let res = client
+        .copy_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("copy_destination")
+        .with_copy_source(&source)
+        .finalize()
+        .await
+        .unwrap();
+    trace!("copy_blob == {:?}", res);
+
+    let blob = client
+        .wait_for_blob_copy()
+        .with_container_name(&container_name)
+        .with_blob_name("copy_destination")
+        .with_poll_interval(std::time::Duration::from_millis(500))
+        .with_max_polls(120)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(blob.copy_status, Some(CopyStatus::Success));
+    assert_eq!(blob.copy_id, Some(res.copy_id));
+
+    let res = client
+        .copy_blob_from_url()
+        .with_container_name(&container_name)
+        .with_blob_name("copy_from_url_destination")
+        .with_copy_source(&source)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.copy_status, CopyStatus::Success);
+
+    let res = client
+        .get_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("copy_from_url_destination")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.data, data);
+}
+
+#[tokio::test]
+async fn blob_properties_and_metadata() {
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("properties")
+        .with_content_type("text/plain")
+        .finalize(futures::io::Cursor::new(b"some text".to_vec()))
+        .await
+        .unwrap();
+
+    client
+        .set_blob_properties()
+        .with_container_name(&container_name)
+        .with_blob_name("properties")
+        .with_content_type("application/json")
+        .with_cache_control("no-cache")
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("origin", "test");
+    client
+        .set_blob_metadata()
+        .with_container_name(&container_name)
+        .with_blob_name("properties")
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob_properties()
+        .with_container_name(&container_name)
+        .with_blob_name("properties")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.blob.content_length, 9);
+    assert_eq!(res.blob.content_type, Some("application/json".to_owned()));
+    assert_eq!(res.blob.cache_control, Some("no-cache".to_owned()));
+    assert_eq!(res.blob.metadata.get("origin"), Some(&"test".to_owned()));
+
+    let res = client
+        .get_blob_metadata()
+        .with_container_name(&container_name)
+        .with_blob_name("properties")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.metadata.len(), 1);
+    assert_eq!(res.metadata["origin"], "test");
+}
+
+#[tokio::test]
+async fn snapshot_and_restore_blob() {
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("snapshotted")
+        .finalize(futures::io::Cursor::new(b"version 1".to_vec()))
+        .await
+        .unwrap();
+
+    let snapshot = client
+        .snapshot_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("snapshotted")
+        .finalize()
+        .await
+        .unwrap()
+        .snapshot;
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("snapshotted")
+        .finalize(futures::io::Cursor::new(b"version 2".to_vec()))
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("snapshotted")
+        .with_snapshot(snapshot)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.data, b"version 1");
+
+    let res = client
+        .restore_blob_from_snapshot()
+        .with_container_name(&container_name)
+        .with_blob_name("snapshotted")
+        .with_snapshot(snapshot)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.copy_status, CopyStatus::Success);
+
+    let res = client
+        .get_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("snapshotted")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.data, b"version 1");
+
+    client
+        .delete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("snapshotted")
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn set_blob_tier() {
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("tiered")
+        .finalize(futures::io::Cursor::new(b"tiered".to_vec()))
+        .await
+        .unwrap();
+
+    client
+        .set_blob_tier()
+        .with_container_name(&container_name)
+        .with_blob_name("tiered")
+        .with_access_tier(AccessTier::Cool)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob_properties()
+        .with_container_name(&container_name)
+        .with_blob_name("tiered")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.blob.access_tier, Some(AccessTier::Cool));
+
+    let moved = client
+        .move_old_blobs_to_tier()
+        .with_container_name(&container_name)
+        .with_prefix("tiered")
+        .with_access_tier(AccessTier::Cool)
+        .with_older_than(Duration::zero())
+        .finalize()
+        .await
+        .unwrap();
+    assert!(moved.is_empty());
+
+    client
+        .delete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("tiered")
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+// Requires blob soft delete to be enabled on the storage account.
+#[tokio::test]
+async fn undelete_blob() {
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("undeleted")
+        .finalize(futures::io::Cursor::new(b"undeleted".to_vec()))
+        .await
+        .unwrap();
+
+    client
+        .delete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("undeleted")
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .undelete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("undeleted")
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("undeleted")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.data, b"undeleted");
+
+    client
+        .delete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("undeleted")
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+
+    let recovered = client
+        .recover_deleted_blobs()
+        .with_container_name(&container_name)
+        .with_prefix("undeleted")
+        .finalize()
+        .await
+        .unwrap();
+    assert!(recovered.iter().any(|blob| blob.name == "undeleted"));
+
+    client
+        .delete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("undeleted")
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn blocks_from_url() {
+    let client = initialize().unwrap();
+
+    // the container allows public read access so the source URL needs no SAS
+    let container_name: &'static str = "rust-upload-test";
+
+    client
+        .upload_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("block_source")
+        .finalize(futures::io::Cursor::new(b"0123456789".to_vec()))
+        .await
+        .unwrap();
+
+    let source = format!("{}/{}/block_source", client.blob_uri(), container_name);
+
+    client
+        .put_block_from_url()
+        .with_container_name(&container_name)
+        .with_blob_name("block_destination")
+        .with_copy_source(&source)
+        .with_block_id(b"block1")
+        .with_source_range(&Range::new(5, 9))
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .put_block_from_url()
+        .with_container_name(&container_name)
+        .with_blob_name("block_destination")
+        .with_copy_source(&source)
+        .with_block_id(b"block2")
+        .with_source_range(&Range::new(0, 4))
+        .finalize()
+        .await
+        .unwrap();
+
+    let received_block_list = client
+        .get_block_list()
+        .with_container_name(&container_name)
+        .with_blob_name("block_destination")
+        .with_block_list_type(BlockListType::All)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .put_block_list()
+        .with_container_name(&container_name)
+        .with_blob_name("block_destination")
+        .with_block_list(&received_block_list.block_with_size_list.into())
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("block_destination")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.data, b"5678901234");
+
+    client
+        .put_append_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("append_destination")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .append_block_from_url()
+        .with_container_name(&container_name)
+        .with_blob_name("append_destination")
+        .with_copy_source(&source)
+        .with_source_range(&Range::new(0, 4))
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob()
+        .with_container_name(&container_name)
+        .with_blob_name("append_destination")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.data, b"01234");
+
+    for blob_name in &["block_source", "block_destination", "append_destination"] {
+        client
+            .delete_blob()
+            .with_container_name(&container_name)
+            .with_blob_name(blob_name)
+            .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+            .finalize()
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn blob_batch() {
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+    let blob_names = (0..5).map(|i| format!("batch{}", i)).collect::<Vec<_>>();
+
+    for blob_name in &blob_names {
+        client
+            .upload_blob()
+            .with_container_name(&container_name)
+            .with_blob_name(blob_name)
+            .finalize(futures::io::Cursor::new(b"batch".to_vec()))
+            .await
+            .unwrap();
+    }
+
+    let mut batch = BlobBatch::new();
+    for blob_name in &blob_names {
+        batch.set_tier(container_name, blob_name, AccessTier::Cool);
+    }
+    let res = client
+        .blob_batch()
+        .with_blob_batch(&batch)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.sub_responses.len(), blob_names.len());
+    assert!(res.sub_responses.iter().all(|r| r.is_success()));
+
+    let mut batch = BlobBatch::new();
+    for blob_name in &blob_names {
+        batch.delete(container_name, blob_name, None);
+    }
+    batch.delete(container_name, "batch_missing", None);
+    let res = client
+        .blob_batch()
+        .with_blob_batch(&batch)
+        .finalize()
+        .await
+        .unwrap();
+    for (sub_response, blob_name) in res.sub_responses.iter().zip(&blob_names) {
+        assert_eq!(&sub_response.blob_name, blob_name);
+        assert!(sub_response.is_success());
+    }
+    let missing = res.sub_responses.last().unwrap();
+    assert_eq!(missing.blob_name, "batch_missing");
+    assert_eq!(missing.error_code, Some("BlobNotFound".to_owned()));
+}
+
+#[tokio::test]
+async fn blob_tags() {
+    use futures::stream::StreamExt;
+
+    let client = initialize().unwrap();
+
+    let container_name: &'static str = "rust-upload-test";
+    let blob_name = format!("tagged{}", Uuid::new_v4());
+    let project = Uuid::new_v4().to_string();
+
+    let mut tags = std::collections::HashMap::new();
+    tags.insert("project", project.as_str());
+    tags.insert("status", "draft");
+
+    client
+        .put_block_blob()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_body(&b"tagged"[..])
+        .with_tags(&tags)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_blob_tags()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.tags.len(), 2);
+    assert_eq!(res.tags["status"], "draft");
+
+    tags.insert("status", "done");
+    client
+        .set_blob_tags()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_tags(&tags)
+        .with_if_tags("\"status\" = 'draft'")
+        .finalize()
+        .await
+        .unwrap();
+
+    // the tags do not match the condition anymore
+    assert!(client
+        .get_blob_properties()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_if_tags("\"status\" = 'draft'")
+        .finalize()
+        .await
+        .is_err());
+
+    // the tag index is eventually consistent, so the blob might not be
+    // found right away
+    let tag_filter = format!("\"project\" = '{}'", project);
+    let mut found = Vec::new();
+    for _ in 0..30 {
+        let mut stream = Box::pin(
+            client
+                .find_blobs_by_tags()
+                .with_tag_filter(&tag_filter)
+                .finalize(),
+        );
+        found.clear();
+        while let Some(blobs) = stream.next().await {
+            found.extend(blobs.unwrap());
+        }
+        if !found.is_empty() {
+            break;
+        }
+        tokio::timer::delay_for(std::time::Duration::from_secs(1)).await;
+    }
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name, blob_name);
+    assert_eq!(found[0].container_name, container_name);
+
+    client
+        .delete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+}
+
 fn initialize() -> Result<Client, AzureError> {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
@@ -2,10 +2,12 @@
 use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::{
     ContainerNameSupport, LeaseBreakPeriodSupport, LeaseDurationSupport, LeaseIdSupport,
+    MetadataSupport,
 };
 use azure_sdk_storage_blob::container::{PublicAccess, PublicAccessSupport};
 use azure_sdk_storage_blob::prelude::*;
 use azure_sdk_storage_core::prelude::*;
+use std::collections::HashMap;
 
 #[tokio::test]
 async fn lease() {
@@ -91,6 +93,51 @@ async fn break_lease() {
         .unwrap();
 }
 
+#[tokio::test]
+async fn set_metadata() {
+    let container_name: &'static str = "azuresdkrustetoets4";
+
+    let client = initialize().unwrap();
+    client
+        .create_container()
+        .with_container_name(container_name)
+        .with_public_access(PublicAccess::None)
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("pollo", "arrosto");
+    metadata.insert("patatine", "fritte");
+
+    client
+        .set_container_metadata()
+        .with_container_name(container_name)
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_container_properties()
+        .with_container_name(container_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    assert_eq!(res.metadata().len(), 2);
+    assert_eq!(res.metadata()["pollo"], "arrosto");
+    assert!(!res.has_immutability_policy());
+    assert!(!res.has_legal_hold());
+
+    client
+        .delete_container()
+        .with_container_name(container_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
 fn initialize() -> Result<Client, AzureError> {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
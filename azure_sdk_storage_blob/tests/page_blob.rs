@@ -56,6 +56,102 @@ async fn put_page_blob() {
     trace!("created {:?}", blob_name);
 }
 
+#[tokio::test]
+async fn get_page_ranges() {
+    let client = initialize().unwrap();
+
+    let blob_name: &'static str = "page_ranges.vhd";
+    let container_name: &'static str = "rust-upload-test";
+    let page = vec![b'a'; 512];
+
+    client
+        .put_page_blob()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_content_length(2048)
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .update_page()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_ba512_range(&BA512Range::new(0, 511).unwrap())
+        .with_body(&page[..])
+        .finalize()
+        .await
+        .unwrap();
+
+    let snapshot = client
+        .snapshot_blob()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .finalize()
+        .await
+        .unwrap()
+        .snapshot;
+
+    client
+        .update_page()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_ba512_range(&BA512Range::new(1024, 1535).unwrap())
+        .with_body(&page[..])
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .clear_page()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_ba512_range(&BA512Range::new(0, 511).unwrap())
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_page_ranges()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.blob_content_length, 2048);
+    assert_eq!(
+        res.page_range_list.page_ranges,
+        vec![BA512Range::new(1024, 1535).unwrap()]
+    );
+
+    let res = client
+        .get_page_ranges()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_prev_snapshot(snapshot)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(
+        res.page_range_list.page_ranges,
+        vec![BA512Range::new(1024, 1535).unwrap()]
+    );
+    assert_eq!(
+        res.page_range_list.clear_ranges,
+        vec![BA512Range::new(0, 511).unwrap()]
+    );
+
+    client
+        .delete_blob()
+        .with_container_name(&container_name)
+        .with_blob_name(&blob_name)
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+}
+
 fn initialize() -> Result<Client, AzureError> {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
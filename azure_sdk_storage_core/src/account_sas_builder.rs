@@ -0,0 +1,1152 @@
+use crate::shared_access_signature::{SasProtocol, SasResourceType, SasService};
+use crate::IPRange;
+use azure_sdk_core::{No, ToAssign, Yes};
+use chrono::{DateTime, SecondsFormat, Utc};
+use ring::hmac;
+use std::marker::PhantomData;
+use url::form_urlencoded;
+
+// The first version supporting encryption scopes in account SAS.
+const ACCOUNT_SAS_VERSION: &str = "2020-12-06";
+
+/// Builds an account shared access signature, granting access to the resources of one or more
+/// services of the account ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/create-account-sas)).
+///
+/// The resulting token can be used to create a client with `Client::azure_sas`.
+#[derive(Debug, Clone)]
+pub struct AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    account: &'a str,
+    p_key: PhantomData<KeySet>,
+    key: Option<&'a str>,
+    p_validity_end: PhantomData<ValidityEndSet>,
+    validity_end: Option<&'a DateTime<Utc>>,
+    p_permission: PhantomData<AtLeastOnePermission>,
+    p_service: PhantomData<AtLeastOneService>,
+    p_resource_type: PhantomData<AtLeastOneResourceType>,
+    validity_start: Option<&'a DateTime<Utc>>,
+    ip_range: Option<&'a IPRange>,
+    protocol: Option<SasProtocol>,
+    encryption_scope: Option<&'a str>,
+    blob_service: bool,
+    queue_service: bool,
+    table_service: bool,
+    file_service: bool,
+    service_resources: bool,
+    container_resources: bool,
+    object_resources: bool,
+    allow_read: bool,
+    allow_write: bool,
+    allow_delete: bool,
+    allow_delete_version: bool,
+    allow_list: bool,
+    allow_add: bool,
+    allow_create: bool,
+    allow_update: bool,
+    allow_process: bool,
+    allow_tag: bool,
+    allow_filter: bool,
+}
+
+impl<'a> AccountSASBuilder<'a, No, No, No, No, No> {
+    #[inline]
+    pub fn new(account: &'a str) -> AccountSASBuilder<'a, No, No, No, No, No> {
+        AccountSASBuilder {
+            account,
+            p_key: PhantomData {},
+            key: None,
+            p_validity_end: PhantomData {},
+            validity_end: None,
+            p_permission: PhantomData {},
+            p_service: PhantomData {},
+            p_resource_type: PhantomData {},
+            validity_start: None,
+            ip_range: None,
+            protocol: None,
+            encryption_scope: None,
+            blob_service: false,
+            queue_service: false,
+            table_service: false,
+            file_service: false,
+            service_resources: false,
+            container_resources: false,
+            object_resources: false,
+            allow_read: false,
+            allow_write: false,
+            allow_delete: false,
+            allow_delete_version: false,
+            allow_list: false,
+            allow_add: false,
+            allow_create: false,
+            allow_update: false,
+            allow_process: false,
+            allow_tag: false,
+            allow_filter: false,
+        }
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    #[inline]
+    pub fn with_key(
+        &self,
+        key: &'a str,
+    ) -> AccountSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: PhantomData {},
+            key: Some(key),
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: self.p_permission,
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+}
+
+impl<'a, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        self.key.unwrap()
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    #[inline]
+    pub fn with_validity_end(
+        &self,
+        validity_end: &'a DateTime<Utc>,
+    ) -> AccountSASBuilder<'a, KeySet, Yes, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: PhantomData {},
+            validity_end: Some(validity_end),
+            p_permission: self.p_permission,
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+}
+
+impl<'a, KeySet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, KeySet, Yes, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    #[inline]
+    pub fn validity_end(&self) -> &'a DateTime<Utc> {
+        self.validity_end.unwrap()
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    #[inline]
+    pub fn allow_read(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: true,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_write(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: true,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_delete(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: true,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_delete_version(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: true,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_list(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: true,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_add(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: true,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_create(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: true,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_update(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: true,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_process(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: true,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_tag(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: true,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn allow_filter(&self) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, Yes, AtLeastOneService, AtLeastOneResourceType> {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: PhantomData {},
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: true,
+        }
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    /// Adds `service` to the services the signature is valid for: call it once per service.
+    #[inline]
+    pub fn with_service(
+        &self,
+        service: SasService,
+    ) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, Yes, AtLeastOneResourceType> {
+        let mut builder = AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: self.p_permission,
+            p_service: PhantomData {},
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        };
+        match service {
+            SasService::Blob => builder.blob_service = true,
+            SasService::Queue => builder.queue_service = true,
+            SasService::Table => builder.table_service = true,
+            SasService::File => builder.file_service = true,
+        }
+        builder
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    /// Adds `resource_type` to the resource types the signature grants access to: call it
+    /// once per resource type.
+    #[inline]
+    pub fn with_resource_type(
+        &self,
+        resource_type: SasResourceType,
+    ) -> AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, Yes> {
+        let mut builder = AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: self.p_permission,
+            p_service: self.p_service,
+            p_resource_type: PhantomData {},
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        };
+        match resource_type {
+            SasResourceType::Service => builder.service_resources = true,
+            SasResourceType::Container => builder.container_resources = true,
+            SasResourceType::Object => builder.object_resources = true,
+        }
+        builder
+    }
+}
+
+// methods callable regardless
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+    AccountSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission, AtLeastOneService, AtLeastOneResourceType>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+    AtLeastOneService: ToAssign,
+    AtLeastOneResourceType: ToAssign,
+{
+    fn account(&self) -> &'a str {
+        self.account
+    }
+
+    #[inline]
+    pub fn validity_start(&self) -> Option<&'a DateTime<Utc>> {
+        self.validity_start
+    }
+
+    #[inline]
+    pub fn ip_range(&self) -> Option<&'a IPRange> {
+        self.ip_range
+    }
+
+    #[inline]
+    pub fn protocol(&self) -> Option<SasProtocol> {
+        self.protocol
+    }
+
+    #[inline]
+    pub fn encryption_scope(&self) -> Option<&'a str> {
+        self.encryption_scope
+    }
+
+    #[inline]
+    pub fn can_read(&self) -> bool {
+        self.allow_read
+    }
+    #[inline]
+    pub fn can_write(&self) -> bool {
+        self.allow_write
+    }
+    #[inline]
+    pub fn can_delete(&self) -> bool {
+        self.allow_delete
+    }
+    #[inline]
+    pub fn can_delete_version(&self) -> bool {
+        self.allow_delete_version
+    }
+    #[inline]
+    pub fn can_list(&self) -> bool {
+        self.allow_list
+    }
+    #[inline]
+    pub fn can_add(&self) -> bool {
+        self.allow_add
+    }
+    #[inline]
+    pub fn can_create(&self) -> bool {
+        self.allow_create
+    }
+    #[inline]
+    pub fn can_update(&self) -> bool {
+        self.allow_update
+    }
+    #[inline]
+    pub fn can_process(&self) -> bool {
+        self.allow_process
+    }
+    #[inline]
+    pub fn can_tag(&self) -> bool {
+        self.allow_tag
+    }
+    #[inline]
+    pub fn can_filter(&self) -> bool {
+        self.allow_filter
+    }
+
+    #[inline]
+    pub fn with_validity_start(self, validity_start: &'a DateTime<Utc>) -> Self {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: self.p_permission,
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: Some(validity_start),
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn with_ip_range(self, ip_range: &'a IPRange) -> Self {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: self.p_permission,
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: Some(ip_range),
+            protocol: self.protocol,
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn with_protocol(self, protocol: SasProtocol) -> Self {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: self.p_permission,
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: Some(protocol),
+            encryption_scope: self.encryption_scope,
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+
+    #[inline]
+    pub fn with_encryption_scope(self, encryption_scope: &'a str) -> Self {
+        AccountSASBuilder {
+            account: self.account,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            validity_end: self.validity_end,
+            p_permission: self.p_permission,
+            p_service: self.p_service,
+            p_resource_type: self.p_resource_type,
+            validity_start: self.validity_start,
+            ip_range: self.ip_range,
+            protocol: self.protocol,
+            encryption_scope: Some(encryption_scope),
+            blob_service: self.blob_service,
+            queue_service: self.queue_service,
+            table_service: self.table_service,
+            file_service: self.file_service,
+            service_resources: self.service_resources,
+            container_resources: self.container_resources,
+            object_resources: self.object_resources,
+            allow_read: self.allow_read,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_delete_version: self.allow_delete_version,
+            allow_list: self.allow_list,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_update: self.allow_update,
+            allow_process: self.allow_process,
+            allow_tag: self.allow_tag,
+            allow_filter: self.allow_filter,
+        }
+    }
+}
+
+// methods callable only when fully constructed
+impl<'a> AccountSASBuilder<'a, Yes, Yes, Yes, Yes, Yes> {
+    /// Returns the signature as a query string, without the leading question mark.
+    pub fn finalize(self) -> String {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, &base64::decode(self.key()).unwrap());
+        let sig = base64::encode(hmac::sign(&key, self.string_to_sign().as_bytes()).as_ref());
+
+        let mut elements = vec![
+            format!("sv={}", ACCOUNT_SAS_VERSION),
+            format!("ss={}", self.services_string()),
+            format!("srt={}", self.resource_types_string()),
+            format!("sp={}", self.permission_string()),
+            format!("se={}", encode(&format_date(self.validity_end()))),
+        ];
+        if let Some(validity_start) = self.validity_start() {
+            elements.push(format!("st={}", encode(&format_date(validity_start))));
+        }
+        if let Some(ip_range) = self.ip_range() {
+            elements.push(format!("sip={}", format_ip_range(ip_range)));
+        }
+        if let Some(protocol) = self.protocol() {
+            elements.push(format!("spr={}", encode(&protocol.to_string())));
+        }
+        if let Some(encryption_scope) = self.encryption_scope() {
+            elements.push(format!("ses={}", encode(encryption_scope)));
+        }
+        elements.push(format!("sig={}", encode(&sig)));
+
+        elements.join("&")
+    }
+
+    pub fn permission_string(&self) -> String {
+        // the service expects the permissions in this order
+        let permissions = [
+            (self.can_read(), 'r'),
+            (self.can_write(), 'w'),
+            (self.can_delete(), 'd'),
+            (self.can_delete_version(), 'x'),
+            (self.can_list(), 'l'),
+            (self.can_add(), 'a'),
+            (self.can_create(), 'c'),
+            (self.can_update(), 'u'),
+            (self.can_process(), 'p'),
+            (self.can_tag(), 't'),
+            (self.can_filter(), 'f'),
+        ];
+        flags_to_string(&permissions)
+    }
+
+    pub fn services_string(&self) -> String {
+        flags_to_string(&[
+            (self.blob_service, 'b'),
+            (self.queue_service, 'q'),
+            (self.table_service, 't'),
+            (self.file_service, 'f'),
+        ])
+    }
+
+    pub fn resource_types_string(&self) -> String {
+        flags_to_string(&[
+            (self.service_resources, 's'),
+            (self.container_resources, 'c'),
+            (self.object_resources, 'o'),
+        ])
+    }
+
+    // https://docs.microsoft.com/en-us/rest/api/storageservices/create-account-sas#constructing-the-signature-string
+    fn string_to_sign(&self) -> String {
+        format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n",
+            self.account(),
+            self.permission_string(),
+            self.services_string(),
+            self.resource_types_string(),
+            self.validity_start().map(format_date).unwrap_or_default(),
+            format_date(self.validity_end()),
+            self.ip_range().map(format_ip_range).unwrap_or_default(),
+            self.protocol().map(|protocol| protocol.to_string()).unwrap_or_default(),
+            ACCOUNT_SAS_VERSION,
+            self.encryption_scope().unwrap_or_default(),
+        )
+    }
+}
+
+fn flags_to_string(flags: &[(bool, char)]) -> String {
+    flags.iter().filter(|(set, _)| *set).map(|(_, c)| *c).collect()
+}
+
+fn format_date(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn format_ip_range(ip_range: &IPRange) -> String {
+    if ip_range.start == ip_range.end {
+        ip_range.start.to_string()
+    } else {
+        format!("{}-{}", ip_range.start, ip_range.end)
+    }
+}
+
+fn encode(s: &str) -> String {
+    form_urlencoded::byte_serialize(s.as_bytes()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn string_to_sign() {
+        // the parameters of the example in the Azure documentation
+        let start = date("2019-08-01T22:18:26Z");
+        let end = date("2019-08-10T02:23:26Z");
+        let ip_range = IPRange {
+            start: "168.1.5.60".parse().unwrap(),
+            end: "168.1.5.70".parse().unwrap(),
+        };
+
+        let builder = AccountSASBuilder::new("storagesample")
+            .with_key("MDEyMzQ1Njc4OWFiY2RlZg==")
+            .with_validity_end(&end)
+            .allow_write()
+            .allow_read()
+            .with_service(SasService::File)
+            .with_service(SasService::Blob)
+            .with_resource_type(SasResourceType::Service)
+            .with_validity_start(&start)
+            .with_ip_range(&ip_range)
+            .with_protocol(SasProtocol::Https);
+
+        assert_eq!(
+            builder.string_to_sign(),
+            "storagesample\nrw\nbf\ns\n2019-08-01T22:18:26Z\n2019-08-10T02:23:26Z\n\
+             168.1.5.60-168.1.5.70\nhttps\n2020-12-06\n\n"
+        );
+    }
+
+    #[test]
+    fn permissions_services_and_resource_types_are_ordered() {
+        let end = date("2020-01-01T00:00:00Z");
+
+        let builder = AccountSASBuilder::new("mindrust")
+            .with_key("MDEyMzQ1Njc4OWFiY2RlZg==")
+            .with_validity_end(&end)
+            .allow_filter()
+            .allow_list()
+            .allow_delete_version()
+            .allow_read()
+            .allow_process()
+            .with_service(SasService::Table)
+            .with_service(SasService::Blob)
+            .with_service(SasService::Queue)
+            .with_service(SasService::Blob)
+            .with_resource_type(SasResourceType::Object)
+            .with_resource_type(SasResourceType::Service);
+
+        assert_eq!(builder.permission_string(), "rxlpf");
+        assert_eq!(builder.services_string(), "bqt");
+        assert_eq!(builder.resource_types_string(), "so");
+    }
+
+    #[test]
+    fn token() {
+        let end = date("2020-01-01T00:00:00Z");
+
+        let token = AccountSASBuilder::new("mindrust")
+            .with_key("MDEyMzQ1Njc4OWFiY2RlZg==")
+            .with_validity_end(&end)
+            .allow_read()
+            .allow_list()
+            .with_service(SasService::Blob)
+            .with_resource_type(SasResourceType::Container)
+            .with_resource_type(SasResourceType::Object)
+            .with_protocol(SasProtocol::HttpHttps)
+            .with_encryption_scope("scope1")
+            .finalize();
+
+        assert_eq!(
+            token,
+            "sv=2020-12-06&ss=b&srt=co&sp=rl&se=2020-01-01T00%3A00%3A00Z&spr=http%2Chttps\
+             &ses=scope1&sig=zfrVvsrtpwpS0uAYRZudtHePZhJbID87dqrcOAoui%2FM%3D"
+        );
+    }
+}
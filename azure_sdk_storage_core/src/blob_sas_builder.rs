@@ -1,5 +1,5 @@
-use crate::rest_client::{generate_storage_sas, SASType};
-use crate::IPRange;
+use crate::rest_client::{generate_storage_sas, get_account, SASType};
+use crate::{IPRange, UserDelegationKey};
 use azure_sdk_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
 use std::marker::PhantomData;
@@ -27,6 +27,8 @@ where
     p_validity_end: PhantomData<ValidityEndSet>,
     at_least_one_permission: PhantomData<AtLeastOnePermission>,
     key: Option<&'a str>,
+    account: Option<&'a str>,
+    user_delegation_key: Option<&'a UserDelegationKey>,
     identifier: Option<&'a str>,
     ip_range: Option<&'a IPRange>,
     validity_start: Option<&'a DateTime<Utc>>,
@@ -51,6 +53,8 @@ impl<'a> BlobSASBuilder<'a, No, No, No> {
             path,
             p_key: PhantomData {},
             key: None,
+            account: None,
+            user_delegation_key: None,
             p_validity_end: PhantomData {},
             at_least_one_permission: PhantomData {},
             validity_end: None,
@@ -84,6 +88,41 @@ where
             path: self.path,
             p_key: PhantomData {},
             key: Some(key),
+            account: self.account,
+            user_delegation_key: None,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            snapshot_time: self.snapshot_time,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    /// Signs the SAS with a user delegation key instead of the account key, so it can be
+    /// generated by a client authorized with Azure Active Directory.
+    #[inline]
+    pub fn with_user_delegation_key(
+        &self,
+        user_delegation_key: &'a UserDelegationKey,
+    ) -> BlobSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission> {
+        BlobSASBuilder {
+            path: self.path,
+            p_key: PhantomData {},
+            key: Some(user_delegation_key.value.as_str()),
+            account: self.account,
+            user_delegation_key: Some(user_delegation_key),
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -128,6 +167,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: PhantomData {},
             at_least_one_permission: self.at_least_one_permission,
             validity_end: Some(validity_end),
@@ -172,6 +213,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -198,6 +241,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -224,6 +269,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -250,6 +297,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -276,6 +325,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -308,6 +359,16 @@ where
         self.path
     }
 
+    #[inline]
+    pub fn account(&self) -> &'a str {
+        self.account.unwrap_or_else(|| get_account(self.path))
+    }
+
+    #[inline]
+    pub fn user_delegation_key(&self) -> Option<&'a UserDelegationKey> {
+        self.user_delegation_key
+    }
+
     #[inline]
     pub fn identifier(&self) -> Option<&'a str> {
         self.identifier
@@ -380,6 +441,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -400,12 +463,44 @@ where
         }
     }
 
+    /// The account the resource belongs to. By default it is the first label of the
+    /// host, which is wrong for custom domains and path-style endpoints like the emulator.
+    #[inline]
+    pub fn with_account(self, account: &'a str) -> Self {
+        BlobSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: Some(account),
+            user_delegation_key: self.user_delegation_key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            snapshot_time: self.snapshot_time,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+        }
+    }
+
     #[inline]
     pub fn with_ip_range(self, ip_range: &'a IPRange) -> Self {
         BlobSASBuilder {
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -432,6 +527,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -458,6 +555,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -484,6 +583,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -510,6 +611,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -536,6 +639,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -562,6 +667,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -588,6 +695,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -613,12 +722,14 @@ where
 impl<'a> BlobSASBuilder<'a, Yes, Yes, Yes> {
     pub fn finalize(self) -> Url {
         let sas = generate_storage_sas(
+            self.account(),
             self.key(),
             self.validity_start(),
             self.validity_end(),
             self.path(),
             &self.permission_string(),
             if let Some(identifier) = self.identifier() { identifier } else { "" },
+            self.user_delegation_key(),
             self.ip_range(),
             SASType::Blob,
             self.snapshot_time(),
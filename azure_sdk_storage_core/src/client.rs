@@ -1,7 +1,17 @@
-use crate::rest_client::{perform_request, ServiceType};
-use azure_sdk_core::errors::AzureError;
-use hyper::{self, Method};
-use hyper_rustls::HttpsConnector;
+use crate::blob_sas_builder::BlobSASBuilder;
+use crate::connection_string::{ConnectionString, EMULATOR_ACCOUNT, EMULATOR_ACCOUNT_KEY};
+use crate::container_sas_builder::ContainerSASBuilder;
+use crate::rest_client::{perform_bearer_request, perform_request, sign_sub_request, ServiceType, SharedKeySigner};
+use crate::service_sas_builder::{FileSASBuilder, ShareSASBuilder};
+use crate::shared_access_signature::SharedAccessSignature;
+use crate::table_sas_builder::TableSASBuilder;
+use azure_sdk_core::errors::{AzureError, ConnectionStringError};
+use azure_sdk_core::{
+    default_http_client, CachedTokenCredential, HttpClient, No, ResponseFuture, RetryHttpClient, RetryPolicy,
+    TokenCredential, Yes,
+};
+use hyper::{HeaderMap, Method};
+use std::sync::Arc;
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -9,69 +19,246 @@ pub struct Client {
     account: String,
     key: String,
     sas_token: Option<Vec<(String, String)>>,
-    hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    token_credential: Option<Arc<dyn TokenCredential>>,
+    http_client: Arc<dyn HttpClient>,
+    retry_policy: RetryPolicy,
     blob_uri: String,
     table_uri: String,
+    queue_uri: String,
+    file_uri: String,
 }
 
+pub const AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX: &str = "core.windows.net";
+pub const AZURE_CHINA_CLOUD_ENDPOINT_SUFFIX: &str = "core.chinacloudapi.cn";
+pub const AZURE_US_GOVERNMENT_ENDPOINT_SUFFIX: &str = "core.usgovcloudapi.net";
+pub const AZURE_GERMAN_CLOUD_ENDPOINT_SUFFIX: &str = "core.cloudapi.de";
+
+/// The scope of the tokens requested by the clients created with
+/// [`Client::azure_token_credential`](struct.Client.html#method.azure_token_credential).
+pub const STORAGE_TOKEN_SCOPE: &str = "https://storage.azure.com/.default";
+
 impl Client {
     pub fn new(account: &str, key: &str) -> Result<Client, AzureError> {
         Client::azure(account, key)
     }
 
     pub fn azure_sas(account: &str, sas_token: &str) -> Result<Client, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
-        let params: Vec<(String, String)> = Url::options()
-            // Any base url will do: we just need to parse the SAS token
-            // to get its query pairs.
-            .base_url(Some(&Url::parse("https://blob.core.windows.net").unwrap()))
-            .parse(sas_token)
-            .unwrap()
-            .query_pairs()
-            .map(|p| (String::from(p.0), String::from(p.1)))
-            .collect();
+        let params = get_sas_token_parms(sas_token)?;
 
         Ok(Client {
             account: account.to_owned(),
             key: String::new(),
             sas_token: Some(params),
-            hc: client,
-            blob_uri: format!("https://{}.blob.core.windows.net", account),
-            table_uri: format!("https://{}.table.core.windows.net", account),
+            token_credential: None,
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
+            blob_uri: get_endpoint(account, "blob", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            table_uri: get_endpoint(account, "table", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            queue_uri: get_endpoint(account, "queue", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            file_uri: get_endpoint(account, "file", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
         })
     }
 
     pub fn azure(account: &str, key: &str) -> Result<Client, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
-
         Ok(Client {
             account: account.to_owned(),
             key: key.to_owned(),
             sas_token: None,
-            hc: client,
-            blob_uri: format!("https://{}.blob.core.windows.net", account),
-            table_uri: format!("https://{}.table.core.windows.net", account),
+            token_credential: None,
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
+            blob_uri: get_endpoint(account, "blob", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            table_uri: get_endpoint(account, "table", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            queue_uri: get_endpoint(account, "queue", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            file_uri: get_endpoint(account, "file", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
         })
     }
 
-    pub fn emulator(blob_storage_url: &Url, table_storage_url: &Url) -> Result<Client, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+    /// Creates a client that authorizes its requests with the OAuth2 tokens of
+    /// `token_credential`, sent as `Authorization: Bearer`, instead of the account key.
+    ///
+    /// The tokens are cached and refreshed automatically shortly before they expire.
+    /// The identity behind the token needs a data plane role, such as Storage Blob Data
+    /// Contributor, on the account. Blob batches are not supported by these clients.
+    pub fn azure_token_credential(account: &str, token_credential: Arc<dyn TokenCredential>) -> Result<Client, AzureError> {
+        Ok(Client {
+            account: account.to_owned(),
+            key: String::new(),
+            sas_token: None,
+            token_credential: Some(Arc::new(CachedTokenCredential::new(token_credential))),
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
+            blob_uri: get_endpoint(account, "blob", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            table_uri: get_endpoint(account, "table", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            queue_uri: get_endpoint(account, "queue", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+            file_uri: get_endpoint(account, "file", AZURE_PUBLIC_CLOUD_ENDPOINT_SUFFIX),
+        })
+    }
 
+    pub fn emulator(blob_storage_url: &Url, table_storage_url: &Url) -> Result<Client, AzureError> {
         let blob_uri = format!("{}devstoreaccount1", blob_storage_url.as_str());
         debug!("blob_uri == {}", blob_uri);
         let table_uri = format!("{}devstoreaccount1", table_storage_url.as_str());
         debug!("table_uri == {}", table_uri);
+        // the emulator listens for queue requests on the port following the blob one
+        let mut queue_storage_url = blob_storage_url.clone();
+        if let Some(port) = blob_storage_url.port_or_known_default() {
+            let _ = queue_storage_url.set_port(Some(port + 1));
+        }
+        let queue_uri = format!("{}devstoreaccount1", queue_storage_url.as_str());
+        debug!("queue_uri == {}", queue_uri);
 
         Ok(Client {
-            account: "devstoreaccount1".to_owned(),
-            key: "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==".to_owned(),
+            account: EMULATOR_ACCOUNT.to_owned(),
+            key: EMULATOR_ACCOUNT_KEY.to_owned(),
             sas_token: None,
-            hc: client,
+            token_credential: None,
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
             blob_uri,
             table_uri,
+            queue_uri,
+            // the emulator does not support the File service: the requests sent there fail
+            file_uri: String::new(),
+        })
+    }
+
+    /// Creates a client from a storage connection string, for example
+    /// `DefaultEndpointsProtocol=https;AccountName=myaccount;AccountKey=...;EndpointSuffix=core.windows.net`.
+    ///
+    /// Besides the account key, the connection string can specify a shared access signature
+    /// (`SharedAccessSignature=`), explicit service endpoints (`BlobEndpoint=`, `TableEndpoint=`, ...)
+    /// or the storage emulator (`UseDevelopmentStorage=true`).
+    pub fn from_connection_string(connection_string: &str) -> Result<Client, AzureError> {
+        let connection_string = ConnectionString::new(connection_string)?;
+
+        if connection_string.use_development_storage == Some(true) {
+            let proxy_uri = connection_string.development_storage_proxy_uri.unwrap_or("http://127.0.0.1");
+            let mut blob_storage_url = Url::parse(proxy_uri)?;
+            blob_storage_url
+                .set_port(Some(10000))
+                .map_err(|_| AzureError::InputParametersError(format!("Cannot set the emulator port on {}", proxy_uri)))?;
+            let mut table_storage_url = blob_storage_url.clone();
+            table_storage_url
+                .set_port(Some(10002))
+                .map_err(|_| AzureError::InputParametersError(format!("Cannot set the emulator port on {}", proxy_uri)))?;
+            return Client::emulator(&blob_storage_url, &table_storage_url);
+        }
+
+        // A SAS connection string can specify only the endpoints of the services
+        // the token grants access to: the other services are left unavailable.
+        let table_uri = connection_string.endpoint(connection_string.table_endpoint, "table").ok();
+        let queue_uri = connection_string.endpoint(connection_string.queue_endpoint, "queue").ok();
+        let file_uri = connection_string.endpoint(connection_string.file_endpoint, "file").ok();
+        let blob_uri = match connection_string.endpoint(connection_string.blob_endpoint, "blob") {
+            Ok(blob_uri) => Some(blob_uri),
+            Err(err) if table_uri.is_none() && queue_uri.is_none() && file_uri.is_none() => return Err(err.into()),
+            Err(_) => None,
+        };
+
+        Ok(Client {
+            account: connection_string
+                .account_name
+                .or_else(|| connection_string.account_from_endpoints().map(|(account_name, _)| account_name))
+                .unwrap_or_default()
+                .to_owned(),
+            key: connection_string.account_key.unwrap_or_default().to_owned(),
+            sas_token: connection_string
+                .sas
+                .map(|sas| {
+                    get_sas_token_parms(sas)
+                        .map_err(|_| ConnectionStringError::InvalidValue("SharedAccessSignature".to_owned(), sas.to_owned()))
+                })
+                .transpose()?,
+            token_credential: None,
+            http_client: default_http_client(),
+            retry_policy: RetryPolicy::default(),
+            blob_uri: blob_uri.unwrap_or_default(),
+            table_uri: table_uri.unwrap_or_default(),
+            queue_uri: queue_uri.unwrap_or_default(),
+            file_uri: file_uri.unwrap_or_default(),
         })
     }
 
+    /// Points the client to the endpoints of a sovereign cloud, for example
+    /// [`AZURE_CHINA_CLOUD_ENDPOINT_SUFFIX`](constant.AZURE_CHINA_CLOUD_ENDPOINT_SUFFIX.html):
+    /// every service endpoint becomes `https://{account}.{service}.{endpoint_suffix}`.
+    pub fn with_endpoint_suffix(self, endpoint_suffix: &str) -> Client {
+        let endpoint_suffix = endpoint_suffix.trim_start_matches('.');
+        Client {
+            blob_uri: get_endpoint(&self.account, "blob", endpoint_suffix),
+            table_uri: get_endpoint(&self.account, "table", endpoint_suffix),
+            queue_uri: get_endpoint(&self.account, "queue", endpoint_suffix),
+            file_uri: get_endpoint(&self.account, "file", endpoint_suffix),
+            ..self
+        }
+    }
+
+    /// Replaces the Blob service endpoint, for example with a private endpoint,
+    /// a custom domain or an emulator listening on a custom port.
+    pub fn with_blob_endpoint(self, blob_endpoint: &Url) -> Client {
+        Client {
+            blob_uri: trim_endpoint(blob_endpoint),
+            ..self
+        }
+    }
+
+    /// Replaces the Table service endpoint.
+    pub fn with_table_endpoint(self, table_endpoint: &Url) -> Client {
+        Client {
+            table_uri: trim_endpoint(table_endpoint),
+            ..self
+        }
+    }
+
+    /// Replaces the Queue service endpoint.
+    pub fn with_queue_endpoint(self, queue_endpoint: &Url) -> Client {
+        Client {
+            queue_uri: trim_endpoint(queue_endpoint),
+            ..self
+        }
+    }
+
+    /// Replaces the File service endpoint.
+    pub fn with_file_endpoint(self, file_endpoint: &Url) -> Client {
+        Client {
+            file_uri: trim_endpoint(file_endpoint),
+            ..self
+        }
+    }
+
+    /// Replaces the transport used to send the requests of this client.
+    pub fn with_http_client(self, http_client: Arc<dyn HttpClient>) -> Client {
+        Client { http_client, ..self }
+    }
+
+    /// Sets the policy used to retry the requests of this client that fail
+    /// with a transient error. By default requests are not retried.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Client {
+        Client { retry_policy, ..self }
+    }
+
+    /// The transport of this client, retrying the failed requests according to
+    /// its retry policy. Requests sent through it directly are not signed again
+    /// when retried.
+    #[inline]
+    pub fn http_client(&self) -> Arc<dyn HttpClient> {
+        Arc::new(RetryHttpClient::new(self.http_client.clone(), self.retry_policy.clone()))
+    }
+
+    #[inline]
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    fn retry_http_client(&self, service_type: ServiceType) -> RetryHttpClient {
+        RetryHttpClient::new(self.http_client.clone(), self.retry_policy.clone()).with_signer(Arc::new(SharedKeySigner::new(
+            &self.account,
+            &self.key,
+            service_type,
+        )))
+    }
+
     pub fn account(&self) -> &str {
         &self.account
     }
@@ -90,10 +277,21 @@ impl Client {
         &self.table_uri
     }
 
-    fn add_sas_token_to_uri(&self, uri: &str) -> String {
+    #[inline]
+    pub fn queue_uri(&self) -> &str {
+        &self.queue_uri
+    }
+
+    /// Empty for the storage emulator, which has no File service.
+    #[inline]
+    pub fn file_uri(&self) -> &str {
+        &self.file_uri
+    }
+
+    fn add_sas_token_to_uri(&self, uri: &str) -> Result<String, AzureError> {
         match &self.sas_token {
-            Some(token) => Url::parse_with_params(uri, token).unwrap().to_string(),
-            None => String::from(uri),
+            Some(token) => Ok(Url::parse_with_params(uri, token)?.to_string()),
+            None => Ok(String::from(uri)),
         }
     }
 
@@ -103,13 +301,37 @@ impl Client {
         method: &Method,
         headers_func: F,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>
+    ) -> Result<ResponseFuture, AzureError>
     where
         F: FnOnce(&mut ::http::request::Builder),
     {
-        let uri = self.add_sas_token_to_uri(uri);
+        // The uris built on an empty endpoint (like the File one of the emulator) are relative.
+        if uri.starts_with('/') {
+            return Err(AzureError::InputParametersError(format!(
+                "the client has no endpoint for the service of {}",
+                uri
+            )));
+        }
+        let uri = self.add_sas_token_to_uri(uri)?;
 
-        perform_request(&self.hc, &uri, method, &self.key, headers_func, request_body, ServiceType::Blob)
+        self.perform_service_request(&uri, method, headers_func, request_body, ServiceType::Blob)
+    }
+
+    /// Signs a blob sub-request embedded in the body of another request.
+    /// Returns the uri the sub-request must target, which carries the SAS
+    /// token if the client has one.
+    pub fn sign_sub_request(&self, uri: &str, method: &Method, headers: &mut HeaderMap) -> Result<String, AzureError> {
+        if self.token_credential.is_some() {
+            return Err(AzureError::InputParametersError(
+                "sub-requests cannot be signed by a client using a token credential".to_owned(),
+            ));
+        }
+
+        let uri = self.add_sas_token_to_uri(uri)?;
+
+        sign_sub_request(&uri, method, headers, &self.account, &self.key, ServiceType::Blob)?;
+
+        Ok(uri)
     }
 
     pub fn perform_table_request<F>(
@@ -118,22 +340,367 @@ impl Client {
         method: &Method,
         headers_func: F,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>
+    ) -> Result<ResponseFuture, AzureError>
     where
         F: FnOnce(&mut ::http::request::Builder),
     {
         debug!("segment: {}, method: {:?}", segment, method,);
 
-        let uri = self.add_sas_token_to_uri((self.get_uri_prefix(ServiceType::Table) + segment).as_str());
+        let uri = self.add_sas_token_to_uri((self.get_uri_prefix(ServiceType::Table) + segment).as_str())?;
 
-        perform_request(&self.hc, &uri, method, &self.key, headers_func, request_str, ServiceType::Table)
+        self.perform_service_request(&uri, method, headers_func, request_str, ServiceType::Table)
+    }
+
+    fn perform_service_request<F>(
+        &self,
+        uri: &str,
+        method: &Method,
+        headers_func: F,
+        request_body: Option<&[u8]>,
+        service_type: ServiceType,
+    ) -> Result<ResponseFuture, AzureError>
+    where
+        F: FnOnce(&mut ::http::request::Builder),
+    {
+        match self.token_credential {
+            // bearer tokens do not depend on the date of the request, so the retries are not re-signed
+            Some(ref token_credential) => perform_bearer_request(
+                Arc::new(RetryHttpClient::new(self.http_client.clone(), self.retry_policy.clone())),
+                uri,
+                method,
+                token_credential.clone(),
+                STORAGE_TOKEN_SCOPE,
+                headers_func,
+                request_body,
+            ),
+            None => perform_request(
+                &self.retry_http_client(service_type),
+                uri,
+                method,
+                &self.account,
+                &self.key,
+                headers_func,
+                request_body,
+                service_type,
+            ),
+        }
+    }
+
+    /// A builder of a service SAS for the blob at `path`, signed with the account
+    /// and the key of this client.
+    pub fn blob_sas_builder<'a>(&'a self, path: &'a Url) -> BlobSASBuilder<'a, Yes, No, No> {
+        BlobSASBuilder::new(path).with_key(&self.key).with_account(&self.account)
+    }
+
+    /// A builder of a service SAS for the container at `path`, signed with the account
+    /// and the key of this client.
+    pub fn container_sas_builder<'a>(&'a self, path: &'a Url) -> ContainerSASBuilder<'a, Yes, No, No> {
+        ContainerSASBuilder::new(path).with_key(&self.key).with_account(&self.account)
+    }
+
+    /// A builder of a service SAS for the file at `path`, signed with the account
+    /// and the key of this client.
+    pub fn file_sas_builder<'a>(&'a self, path: &'a Url) -> FileSASBuilder<'a, Yes, No, No> {
+        FileSASBuilder::new(path).with_key(&self.key).with_account(&self.account)
+    }
+
+    /// A builder of a service SAS for the share at `path`, signed with the account
+    /// and the key of this client.
+    pub fn share_sas_builder<'a>(&'a self, path: &'a Url) -> ShareSASBuilder<'a, Yes, No, No> {
+        ShareSASBuilder::new(path).with_key(&self.key).with_account(&self.account)
+    }
+
+    /// A builder of a service SAS for the table at `path`, signed with the account
+    /// and the key of this client.
+    pub fn table_sas_builder<'a>(&'a self, path: &'a Url) -> TableSASBuilder<'a, Yes, No, No> {
+        TableSASBuilder::new(path).with_key(&self.key).with_account(&self.account)
     }
 
     /// Uri scheme + authority e.g. http://myaccount.table.core.windows.net/
     pub fn get_uri_prefix(&self, service_type: ServiceType) -> String {
         match service_type {
             ServiceType::Blob => format!("{}/", self.blob_uri()),
+            ServiceType::Queue => format!("{}/", self.queue_uri()),
+            ServiceType::File => format!("{}/", self.file_uri()),
             ServiceType::Table => format!("{}/", self.table_uri()),
         }
     }
 }
+
+fn get_endpoint(account: &str, service: &str, endpoint_suffix: &str) -> String {
+    format!("https://{}.{}.{}", account, service, endpoint_suffix)
+}
+
+fn trim_endpoint(endpoint: &Url) -> String {
+    endpoint.as_str().trim_end_matches('/').to_owned()
+}
+
+// The token can be specified with or without the leading question mark, and is
+// rejected if it lacks the fields every SAS must have.
+fn get_sas_token_parms(sas_token: &str) -> Result<Vec<(String, String)>, AzureError> {
+    Ok(SharedAccessSignature::parse(sas_token)?.query_pairs().to_vec())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use azure_sdk_core::{AccessToken, TokenFuture};
+    use chrono::{Duration, Utc};
+    use hyper::{Body, Request};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingHttpClient {
+        requests: Mutex<Vec<(Method, String, Option<String>)>>,
+    }
+
+    impl HttpClient for RecordingHttpClient {
+        fn request(&self, request: Request<Body>) -> ResponseFuture {
+            self.requests.lock().unwrap().push((
+                request.method().clone(),
+                request.uri().to_string(),
+                request
+                    .headers()
+                    .get(hyper::header::AUTHORIZATION)
+                    .map(|auth| auth.to_str().unwrap().to_owned()),
+            ));
+            Box::pin(async { Err(AzureError::GenericError) })
+        }
+    }
+
+    #[test]
+    fn requests_go_through_custom_http_client() {
+        let http_client = Arc::new(RecordingHttpClient::default());
+        let client = Client::azure(
+            "account",
+            "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+        )
+        .unwrap()
+        .with_http_client(http_client.clone());
+
+        let _response = client
+            .perform_request(
+                "https://account.blob.core.windows.net/container?restype=container",
+                &Method::PUT,
+                |_| {},
+                None,
+            )
+            .unwrap();
+
+        let requests = http_client.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, Method::PUT);
+        assert_eq!(requests[0].1, "https://account.blob.core.windows.net/container?restype=container");
+        assert!(requests[0].2.as_ref().unwrap().starts_with("SharedKey account:"));
+    }
+
+    #[derive(Debug, Default)]
+    struct StaticTokenCredential {
+        calls: AtomicUsize,
+    }
+
+    impl TokenCredential for StaticTokenCredential {
+        fn get_token<'a>(&'a self, _scope: &'a str) -> TokenFuture<'a> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(AccessToken::new("token", Utc::now() + Duration::hours(1))) })
+        }
+    }
+
+    #[test]
+    fn token_credential_clients_do_not_use_the_account_key() {
+        let http_client = Arc::new(RecordingHttpClient::default());
+        let credential = Arc::new(StaticTokenCredential::default());
+        let client = Client::azure_token_credential("account", credential.clone())
+            .unwrap()
+            .with_http_client(http_client.clone());
+        assert_eq!(client.key(), "");
+
+        // the token is requested, and the request sent, only when the future is polled
+        let response = client
+            .perform_request("https://account.blob.core.windows.net/container/blob", &Method::GET, |_| {}, None)
+            .unwrap();
+        assert!(http_client.requests.lock().unwrap().is_empty());
+        assert_eq!(credential.calls.load(Ordering::SeqCst), 0);
+
+        // the recording client fails every request once it is recorded
+        assert!(futures::executor::block_on(response).is_err());
+        let response = client
+            .perform_request("https://account.blob.core.windows.net/container/blob", &Method::GET, |_| {}, None)
+            .unwrap();
+        assert!(futures::executor::block_on(response).is_err());
+
+        let requests = http_client.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        for (_, _, authorization) in requests.iter() {
+            assert_eq!(authorization.as_deref(), Some("Bearer token"));
+        }
+        // the second request reuses the cached token
+        assert_eq!(credential.calls.load(Ordering::SeqCst), 1);
+
+        let mut headers = HeaderMap::new();
+        assert!(client
+            .sign_sub_request(
+                "https://account.blob.core.windows.net/container/blob",
+                &Method::DELETE,
+                &mut headers
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn retries_are_signed_with_a_fresh_date() {
+        use azure_sdk_core::RequestSigner;
+
+        let signer = SharedKeySigner::new(
+            "account",
+            "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+            ServiceType::Blob,
+        );
+        let mut request = Request::builder()
+            .method(Method::PUT)
+            .uri("https://account.blob.core.windows.net/container?restype=container")
+            .header(crate::rest_client::HEADER_DATE, "Mon, 01 Jan 2018 00:00:00 GMT")
+            .header(hyper::header::AUTHORIZATION, "SharedKey account:old")
+            .body(Body::empty())
+            .unwrap();
+
+        signer.sign(&mut request).unwrap();
+
+        assert_ne!(request.headers()[crate::rest_client::HEADER_DATE], "Mon, 01 Jan 2018 00:00:00 GMT");
+        let auth = request.headers()[hyper::header::AUTHORIZATION].to_str().unwrap();
+        assert!(auth.starts_with("SharedKey account:"));
+        assert_ne!(auth, "SharedKey account:old");
+
+        // requests authorized by a SAS token are left untouched
+        let mut request = Request::builder()
+            .uri("https://account.blob.core.windows.net/container?sig=abc")
+            .header(crate::rest_client::HEADER_DATE, "Mon, 01 Jan 2018 00:00:00 GMT")
+            .body(Body::empty())
+            .unwrap();
+        signer.sign(&mut request).unwrap();
+        assert_eq!(request.headers()[crate::rest_client::HEADER_DATE], "Mon, 01 Jan 2018 00:00:00 GMT");
+        assert!(!request.headers().contains_key(hyper::header::AUTHORIZATION));
+    }
+
+    #[test]
+    fn from_connection_string() {
+        let client = Client::from_connection_string(
+            "DefaultEndpointsProtocol=http;AccountName=mindrust;AccountKey=Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==;EndpointSuffix=core.usgovcloudapi.net",
+        )
+        .unwrap();
+        assert_eq!(client.account(), "mindrust");
+        assert_eq!(client.blob_uri(), "http://mindrust.blob.core.usgovcloudapi.net");
+        assert_eq!(client.table_uri(), "http://mindrust.table.core.usgovcloudapi.net");
+
+        let client = Client::from_connection_string(
+            "BlobEndpoint=https://mindrust.blob.core.windows.net/;SharedAccessSignature=sv=2019-02-02&ss=b&srt=sco&sp=rl&sig=abc",
+        )
+        .unwrap();
+        assert_eq!(client.blob_uri(), "https://mindrust.blob.core.windows.net");
+        assert_eq!(
+            client
+                .add_sas_token_to_uri("https://mindrust.blob.core.windows.net/container")
+                .unwrap(),
+            "https://mindrust.blob.core.windows.net/container?sv=2019-02-02&ss=b&srt=sco&sp=rl&sig=abc"
+        );
+
+        let client = Client::from_connection_string("UseDevelopmentStorage=true").unwrap();
+        assert_eq!(client.account(), "devstoreaccount1");
+        assert_eq!(client.blob_uri(), "http://127.0.0.1:10000/devstoreaccount1");
+        assert_eq!(client.table_uri(), "http://127.0.0.1:10002/devstoreaccount1");
+        assert_eq!(client.queue_uri(), "http://127.0.0.1:10001/devstoreaccount1");
+
+        match Client::from_connection_string("AccountName=mindrust") {
+            Err(AzureError::ConnectionStringError(_)) => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+        match Client::from_connection_string("BlobEndpoint=https://mindrust.blob.core.windows.net/;SharedAccessSignature=sv=2019-02-02") {
+            Err(AzureError::ConnectionStringError(ConnectionStringError::InvalidValue(key, _))) => assert_eq!(key, "SharedAccessSignature"),
+            r => panic!("unexpected result {:?}", r),
+        }
+        assert!(Client::azure_sas("mindrust", "?sv=2019-02-02&ss=b").is_err());
+    }
+
+    #[test]
+    fn azure_sas_rejects_invalid_tokens() {
+        let client = Client::azure_sas("mindrust", "?sv=2019-02-02&ss=b&srt=sco&sp=rl&sig=abc").unwrap();
+        assert_eq!(
+            client
+                .add_sas_token_to_uri("https://mindrust.blob.core.windows.net/container")
+                .unwrap(),
+            "https://mindrust.blob.core.windows.net/container?sv=2019-02-02&ss=b&srt=sco&sp=rl&sig=abc"
+        );
+
+        assert!(Client::azure_sas("mindrust", "sv=2019-02-02&ss=b&srt=sco&sp=rl").is_err());
+        assert!(Client::azure_sas("mindrust", "sv=2019-02-02&ss=b&se=tomorrow&sig=abc").is_err());
+    }
+
+    #[test]
+    fn custom_endpoints() {
+        let client = Client::azure(
+            "mindrust",
+            "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+        )
+        .unwrap();
+        assert_eq!(client.blob_uri(), "https://mindrust.blob.core.windows.net");
+        assert_eq!(client.file_uri(), "https://mindrust.file.core.windows.net");
+
+        let client = client.with_endpoint_suffix(AZURE_US_GOVERNMENT_ENDPOINT_SUFFIX);
+        assert_eq!(client.blob_uri(), "https://mindrust.blob.core.usgovcloudapi.net");
+        assert_eq!(client.table_uri(), "https://mindrust.table.core.usgovcloudapi.net");
+        assert_eq!(client.queue_uri(), "https://mindrust.queue.core.usgovcloudapi.net");
+        assert_eq!(client.file_uri(), "https://mindrust.file.core.usgovcloudapi.net");
+
+        let client = client
+            .with_blob_endpoint(&Url::parse("https://mindrust.privatelink.blob.core.windows.net/").unwrap())
+            .with_table_endpoint(&Url::parse("http://localhost:11002/mindrust").unwrap());
+        assert_eq!(client.blob_uri(), "https://mindrust.privatelink.blob.core.windows.net");
+        assert_eq!(client.table_uri(), "http://localhost:11002/mindrust");
+        assert_eq!(client.get_uri_prefix(ServiceType::Table), "http://localhost:11002/mindrust/");
+    }
+
+    #[test]
+    fn emulator_has_no_file_service() {
+        let client = Client::emulator(
+            &Url::parse("http://127.0.0.1:10000").unwrap(),
+            &Url::parse("http://127.0.0.1:10002").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(client.file_uri(), "");
+
+        let uri = format!("{}/share/file", client.file_uri());
+        match client.perform_request(&uri, &Method::GET, |_| {}, None) {
+            Err(AzureError::InputParametersError(_)) => {}
+            r => panic!("unexpected result {:?}", r.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn sas_builders_use_the_client_account() {
+        let client = Client::azure(
+            "mindrust",
+            "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+        )
+        .unwrap();
+        let path = Url::parse("https://www.contoso.com/mycontainer/myblob").unwrap();
+        let end = chrono::Utc::now();
+
+        let builder = client.blob_sas_builder(&path);
+        assert_eq!(builder.account(), "mindrust");
+        assert_eq!(BlobSASBuilder::new(&path).account(), "www");
+
+        let with_client_account = builder.with_validity_end(&end).allow_read().finalize();
+        let with_host_account = BlobSASBuilder::new(&path)
+            .with_key(client.key())
+            .with_validity_end(&end)
+            .allow_read()
+            .finalize();
+        assert_ne!(with_client_account, with_host_account);
+
+        assert_eq!(client.container_sas_builder(&path).account(), "mindrust");
+        assert_eq!(client.file_sas_builder(&path).account(), "mindrust");
+        assert_eq!(client.share_sas_builder(&path).account(), "mindrust");
+        assert_eq!(client.table_sas_builder(&path).account(), "mindrust");
+    }
+}
@@ -0,0 +1,474 @@
+use azure_sdk_core::errors::ConnectionStringError;
+
+pub const EMULATOR_ACCOUNT: &str = "devstoreaccount1";
+pub const EMULATOR_ACCOUNT_KEY: &str =
+    "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+const ACCOUNT_KEY_KEY_NAME: &str = "AccountKey";
+const ACCOUNT_NAME_KEY_NAME: &str = "AccountName";
+const SAS_KEY_NAME: &str = "SharedAccessSignature";
+const ENDPOINT_SUFFIX_KEY_NAME: &str = "EndpointSuffix";
+const DEFAULT_ENDPOINTS_PROTOCOL_KEY_NAME: &str = "DefaultEndpointsProtocol";
+const USE_DEVELOPMENT_STORAGE_KEY_NAME: &str = "UseDevelopmentStorage";
+const DEVELOPMENT_STORAGE_PROXY_URI_KEY_NAME: &str = "DevelopmentStorageProxyUri";
+const BLOB_ENDPOINT_KEY_NAME: &str = "BlobEndpoint";
+const BLOB_SECONDARY_ENDPOINT_KEY_NAME: &str = "BlobSecondaryEndpoint";
+const TABLE_ENDPOINT_KEY_NAME: &str = "TableEndpoint";
+const TABLE_SECONDARY_ENDPOINT_KEY_NAME: &str = "TableSecondaryEndpoint";
+const QUEUE_ENDPOINT_KEY_NAME: &str = "QueueEndpoint";
+const QUEUE_SECONDARY_ENDPOINT_KEY_NAME: &str = "QueueSecondaryEndpoint";
+const FILE_ENDPOINT_KEY_NAME: &str = "FileEndpoint";
+const FILE_SECONDARY_ENDPOINT_KEY_NAME: &str = "FileSecondaryEndpoint";
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EndpointProtocol {
+    Http,
+    #[default]
+    Https,
+}
+
+impl EndpointProtocol {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EndpointProtocol::Http => "http",
+            EndpointProtocol::Https => "https",
+        }
+    }
+}
+
+/// A parsed storage connection string, as handed out by the Azure portal
+/// (for example `DefaultEndpointsProtocol=https;AccountName=...;AccountKey=...;EndpointSuffix=core.windows.net`).
+///
+/// Keys are matched case insensitively. Every segment must be a `key=value` pair
+/// with a documented key and each key can be specified only once.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectionString<'a> {
+    pub account_name: Option<&'a str>,
+    pub account_key: Option<&'a str>,
+    pub sas: Option<&'a str>,
+    pub endpoint_suffix: Option<&'a str>,
+    pub default_endpoints_protocol: Option<EndpointProtocol>,
+    pub use_development_storage: Option<bool>,
+    pub development_storage_proxy_uri: Option<&'a str>,
+    pub blob_endpoint: Option<&'a str>,
+    pub blob_secondary_endpoint: Option<&'a str>,
+    pub table_endpoint: Option<&'a str>,
+    pub table_secondary_endpoint: Option<&'a str>,
+    pub queue_endpoint: Option<&'a str>,
+    pub queue_secondary_endpoint: Option<&'a str>,
+    pub file_endpoint: Option<&'a str>,
+    pub file_secondary_endpoint: Option<&'a str>,
+}
+
+impl<'a> ConnectionString<'a> {
+    pub fn new(connection_string: &'a str) -> Result<Self, ConnectionStringError> {
+        let mut connection_string_builder = Self::default();
+
+        for segment in connection_string.split(';') {
+            let segment = segment.trim();
+            // a trailing semicolon is allowed
+            if segment.is_empty() {
+                continue;
+            }
+
+            // values (keys and SAS tokens) can contain '=' so we split on the first one only
+            let equal_sign = segment
+                .find('=')
+                .ok_or_else(|| ConnectionStringError::MalformedSegment(segment.to_owned()))?;
+            let key = segment[..equal_sign].trim();
+            let value = segment[equal_sign + 1..].trim();
+
+            if key.is_empty() || value.is_empty() {
+                return Err(ConnectionStringError::MalformedSegment(segment.to_owned()));
+            }
+
+            macro_rules! set {
+                ($field:ident, $value:expr) => {{
+                    if connection_string_builder.$field.is_some() {
+                        return Err(ConnectionStringError::DuplicateKey(key.to_owned()));
+                    }
+                    connection_string_builder.$field = Some($value);
+                }};
+            }
+
+            let is = |key_name: &str| key.eq_ignore_ascii_case(key_name);
+
+            if is(ACCOUNT_NAME_KEY_NAME) {
+                set!(account_name, value)
+            } else if is(ACCOUNT_KEY_KEY_NAME) {
+                set!(account_key, value)
+            } else if is(SAS_KEY_NAME) {
+                set!(sas, value)
+            } else if is(ENDPOINT_SUFFIX_KEY_NAME) {
+                set!(endpoint_suffix, value.trim_start_matches('.'))
+            } else if is(DEFAULT_ENDPOINTS_PROTOCOL_KEY_NAME) {
+                let protocol = if value.eq_ignore_ascii_case("https") {
+                    EndpointProtocol::Https
+                } else if value.eq_ignore_ascii_case("http") {
+                    EndpointProtocol::Http
+                } else {
+                    return Err(ConnectionStringError::InvalidValue(
+                        key.to_owned(),
+                        value.to_owned(),
+                    ));
+                };
+                set!(default_endpoints_protocol, protocol)
+            } else if is(USE_DEVELOPMENT_STORAGE_KEY_NAME) {
+                let use_development_storage = if value.eq_ignore_ascii_case("true") {
+                    true
+                } else if value.eq_ignore_ascii_case("false") {
+                    false
+                } else {
+                    return Err(ConnectionStringError::InvalidValue(
+                        key.to_owned(),
+                        value.to_owned(),
+                    ));
+                };
+                set!(use_development_storage, use_development_storage)
+            } else if is(DEVELOPMENT_STORAGE_PROXY_URI_KEY_NAME) {
+                set!(development_storage_proxy_uri, value)
+            } else if is(BLOB_ENDPOINT_KEY_NAME) {
+                set!(blob_endpoint, value)
+            } else if is(BLOB_SECONDARY_ENDPOINT_KEY_NAME) {
+                set!(blob_secondary_endpoint, value)
+            } else if is(TABLE_ENDPOINT_KEY_NAME) {
+                set!(table_endpoint, value)
+            } else if is(TABLE_SECONDARY_ENDPOINT_KEY_NAME) {
+                set!(table_secondary_endpoint, value)
+            } else if is(QUEUE_ENDPOINT_KEY_NAME) {
+                set!(queue_endpoint, value)
+            } else if is(QUEUE_SECONDARY_ENDPOINT_KEY_NAME) {
+                set!(queue_secondary_endpoint, value)
+            } else if is(FILE_ENDPOINT_KEY_NAME) {
+                set!(file_endpoint, value)
+            } else if is(FILE_SECONDARY_ENDPOINT_KEY_NAME) {
+                set!(file_secondary_endpoint, value)
+            } else {
+                return Err(ConnectionStringError::UnsupportedKey(key.to_owned()));
+            }
+        }
+
+        connection_string_builder.validate()?;
+        Ok(connection_string_builder)
+    }
+
+    fn validate(&self) -> Result<(), ConnectionStringError> {
+        if self.use_development_storage == Some(true) {
+            let has_account_settings = self.account_name.is_some()
+                || self.account_key.is_some()
+                || self.sas.is_some()
+                || self.endpoint_suffix.is_some()
+                || self.default_endpoints_protocol.is_some()
+                || self
+                    .explicit_endpoints()
+                    .any(|(_, endpoint)| endpoint.is_some());
+            if has_account_settings {
+                return Err(ConnectionStringError::InvalidCombination(format!(
+                    "{} can only be combined with {}",
+                    USE_DEVELOPMENT_STORAGE_KEY_NAME, DEVELOPMENT_STORAGE_PROXY_URI_KEY_NAME
+                )));
+            }
+            if let Some(proxy_uri) = self.development_storage_proxy_uri {
+                url::Url::parse(proxy_uri).map_err(|err| {
+                    ConnectionStringError::InvalidEndpoint(
+                        DEVELOPMENT_STORAGE_PROXY_URI_KEY_NAME.to_owned(),
+                        err,
+                    )
+                })?;
+            }
+            return Ok(());
+        }
+
+        if self.development_storage_proxy_uri.is_some() {
+            return Err(ConnectionStringError::InvalidCombination(format!(
+                "{} requires {}=true",
+                DEVELOPMENT_STORAGE_PROXY_URI_KEY_NAME, USE_DEVELOPMENT_STORAGE_KEY_NAME
+            )));
+        }
+
+        match (self.account_key, self.sas) {
+            (Some(_), Some(_)) => {
+                return Err(ConnectionStringError::InvalidCombination(format!(
+                    "{} and {} are mutually exclusive",
+                    ACCOUNT_KEY_KEY_NAME, SAS_KEY_NAME
+                )))
+            }
+            (None, None) => {
+                return Err(ConnectionStringError::MissingKey(format!(
+                    "{} or {}",
+                    ACCOUNT_KEY_KEY_NAME, SAS_KEY_NAME
+                )))
+            }
+            (Some(account_key), None) => {
+                if self.account_name.is_none() {
+                    return Err(ConnectionStringError::MissingKey(
+                        ACCOUNT_NAME_KEY_NAME.to_owned(),
+                    ));
+                }
+                if base64::decode(account_key).is_err() {
+                    return Err(ConnectionStringError::InvalidValue(
+                        ACCOUNT_KEY_KEY_NAME.to_owned(),
+                        "<not a valid base64 string>".to_owned(),
+                    ));
+                }
+            }
+            (None, Some(_)) => {
+                // without the account name every endpoint must be explicit
+                if self.account_name.is_none()
+                    && self.blob_endpoint.is_none()
+                    && self.table_endpoint.is_none()
+                {
+                    return Err(ConnectionStringError::MissingKey(format!(
+                        "{} or {}",
+                        ACCOUNT_NAME_KEY_NAME, BLOB_ENDPOINT_KEY_NAME
+                    )));
+                }
+            }
+        }
+
+        for (key_name, endpoint) in self.explicit_endpoints() {
+            if let Some(endpoint) = endpoint {
+                url::Url::parse(endpoint).map_err(|err| {
+                    ConnectionStringError::InvalidEndpoint(key_name.to_owned(), err)
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn explicit_endpoints(&self) -> impl Iterator<Item = (&'static str, Option<&'a str>)> {
+        vec![
+            (BLOB_ENDPOINT_KEY_NAME, self.blob_endpoint),
+            (
+                BLOB_SECONDARY_ENDPOINT_KEY_NAME,
+                self.blob_secondary_endpoint,
+            ),
+            (TABLE_ENDPOINT_KEY_NAME, self.table_endpoint),
+            (
+                TABLE_SECONDARY_ENDPOINT_KEY_NAME,
+                self.table_secondary_endpoint,
+            ),
+            (QUEUE_ENDPOINT_KEY_NAME, self.queue_endpoint),
+            (
+                QUEUE_SECONDARY_ENDPOINT_KEY_NAME,
+                self.queue_secondary_endpoint,
+            ),
+            (FILE_ENDPOINT_KEY_NAME, self.file_endpoint),
+            (
+                FILE_SECONDARY_ENDPOINT_KEY_NAME,
+                self.file_secondary_endpoint,
+            ),
+        ]
+        .into_iter()
+    }
+
+    /// The primary endpoint of a service: either the explicit one or the one
+    /// derived from the protocol, the account name and the endpoint suffix.
+    /// Endpoints never end with a slash.
+    pub(crate) fn endpoint(
+        &self,
+        explicit_endpoint: Option<&str>,
+        service: &str,
+    ) -> Result<String, ConnectionStringError> {
+        if let Some(endpoint) = explicit_endpoint {
+            return Ok(endpoint.trim_end_matches('/').to_owned());
+        }
+
+        let protocol = self.default_endpoints_protocol.unwrap_or_default().as_str();
+
+        if let Some(account_name) = self.account_name {
+            return Ok(format!(
+                "{}://{}.{}.{}",
+                protocol,
+                account_name,
+                service,
+                self.endpoint_suffix.unwrap_or("core.windows.net")
+            ));
+        }
+
+        // SAS connection strings often specify only the endpoints of the services
+        // the token grants access to: the missing ones are derived from those.
+        let (account_name, endpoint_suffix) = self
+            .account_from_endpoints()
+            .ok_or_else(|| ConnectionStringError::MissingKey(ACCOUNT_NAME_KEY_NAME.to_owned()))?;
+        Ok(format!(
+            "{}://{}.{}.{}",
+            protocol,
+            account_name,
+            service,
+            self.endpoint_suffix.unwrap_or(endpoint_suffix)
+        ))
+    }
+
+    /// Extracts the account name and the endpoint suffix from the first explicit
+    /// endpoint in the `https://{account}.{service}.{suffix}` form.
+    pub(crate) fn account_from_endpoints(&self) -> Option<(&'a str, &'a str)> {
+        [
+            self.blob_endpoint,
+            self.table_endpoint,
+            self.queue_endpoint,
+            self.file_endpoint,
+        ]
+        .iter()
+        .filter_map(|endpoint| *endpoint)
+        .find_map(|endpoint| {
+            let host = &endpoint[endpoint.find("://")? + 3..];
+            let host = &host[..host
+                .find(['/', ':'])
+                .unwrap_or(host.len())];
+
+            let mut labels = host.splitn(3, '.');
+            let account_name = labels.next()?;
+            let service = labels.next()?;
+            let endpoint_suffix = labels.next()?;
+            match service {
+                "blob" | "table" | "queue" | "file" => Some((account_name, endpoint_suffix)),
+                _ => None,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const KEY: &str =
+        "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+    #[test]
+    fn parse_account_key() {
+        let s = format!(
+            "DefaultEndpointsProtocol=https;AccountName=mindrust;AccountKey={};EndpointSuffix=core.chinacloudapi.cn;",
+            KEY
+        );
+        let cs = ConnectionString::new(&s).unwrap();
+        assert_eq!(cs.account_name, Some("mindrust"));
+        assert_eq!(cs.account_key, Some(KEY));
+        assert_eq!(cs.endpoint_suffix, Some("core.chinacloudapi.cn"));
+        assert_eq!(cs.default_endpoints_protocol, Some(EndpointProtocol::Https));
+        assert_eq!(
+            cs.endpoint(cs.blob_endpoint, "blob").unwrap(),
+            "https://mindrust.blob.core.chinacloudapi.cn"
+        );
+    }
+
+    #[test]
+    fn parse_is_case_insensitive_and_keeps_equal_signs() {
+        let cs = ConnectionString::new(
+            "accountname=mindrust; SharedAccessSignature=sv=2019-02-02&ss=b&sig=abc%3D",
+        )
+        .unwrap();
+        assert_eq!(cs.account_name, Some("mindrust"));
+        assert_eq!(cs.sas, Some("sv=2019-02-02&ss=b&sig=abc%3D"));
+        assert_eq!(
+            cs.endpoint(None, "table").unwrap(),
+            "https://mindrust.table.core.windows.net"
+        );
+    }
+
+    #[test]
+    fn parse_explicit_endpoints() {
+        let s = format!(
+            "AccountName=mindrust;AccountKey={};BlobEndpoint=https://storage.contoso.com/;TableEndpoint=http://127.0.0.1:10002/mindrust",
+            KEY
+        );
+        let cs = ConnectionString::new(&s).unwrap();
+        assert_eq!(
+            cs.endpoint(cs.blob_endpoint, "blob").unwrap(),
+            "https://storage.contoso.com"
+        );
+        assert_eq!(
+            cs.endpoint(cs.table_endpoint, "table").unwrap(),
+            "http://127.0.0.1:10002/mindrust"
+        );
+    }
+
+    #[test]
+    fn derive_endpoints_from_sas_endpoints() {
+        let cs = ConnectionString::new("BlobEndpoint=https://mindrust.blob.core.chinacloudapi.cn/;SharedAccessSignature=sv=2019-02-02&sig=abc")
+            .unwrap();
+        assert_eq!(
+            cs.endpoint(cs.table_endpoint, "table").unwrap(),
+            "https://mindrust.table.core.chinacloudapi.cn"
+        );
+
+        let cs = ConnectionString::new(
+            "BlobEndpoint=https://storage.contoso.com;SharedAccessSignature=sv=2019-02-02&sig=abc",
+        )
+        .unwrap();
+        match cs.endpoint(cs.table_endpoint, "table").unwrap_err() {
+            ConnectionStringError::MissingKey(key) => assert_eq!(key, "AccountName"),
+            e => panic!("unexpected error {:?}", e),
+        }
+    }
+
+    #[test]
+    fn parse_development_storage() {
+        let cs = ConnectionString::new("UseDevelopmentStorage=true").unwrap();
+        assert_eq!(cs.use_development_storage, Some(true));
+
+        let cs = ConnectionString::new(
+            "UseDevelopmentStorage=true;DevelopmentStorageProxyUri=http://ipv4.fiddler",
+        )
+        .unwrap();
+        assert_eq!(
+            cs.development_storage_proxy_uri,
+            Some("http://ipv4.fiddler")
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |s: &str| ConnectionString::new(s).unwrap_err();
+
+        match err("AccountName=mindrust;AccountKey") {
+            ConnectionStringError::MalformedSegment(segment) => assert_eq!(segment, "AccountKey"),
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err("AccountName=mindrust;AccountName=other") {
+            ConnectionStringError::DuplicateKey(key) => assert_eq!(key, "AccountName"),
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err("AccountName=mindrust;Pippo=pluto") {
+            ConnectionStringError::UnsupportedKey(key) => assert_eq!(key, "Pippo"),
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err("DefaultEndpointsProtocol=ftp;AccountName=mindrust") {
+            ConnectionStringError::InvalidValue(key, value) => {
+                assert_eq!(key, "DefaultEndpointsProtocol");
+                assert_eq!(value, "ftp");
+            }
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err(&format!("AccountKey={}", KEY)) {
+            ConnectionStringError::MissingKey(key) => assert_eq!(key, "AccountName"),
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err("AccountName=mindrust") {
+            ConnectionStringError::MissingKey(key) => {
+                assert_eq!(key, "AccountKey or SharedAccessSignature")
+            }
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err(&format!(
+            "AccountName=mindrust;AccountKey={};SharedAccessSignature=sv=1",
+            KEY
+        )) {
+            ConnectionStringError::InvalidCombination(_) => {}
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err("UseDevelopmentStorage=true;AccountName=mindrust") {
+            ConnectionStringError::InvalidCombination(_) => {}
+            e => panic!("unexpected error {:?}", e),
+        }
+        match err(&format!(
+            "AccountName=mindrust;AccountKey={};BlobEndpoint=not an url",
+            KEY
+        )) {
+            ConnectionStringError::InvalidEndpoint(key, _) => assert_eq!(key, "BlobEndpoint"),
+            e => panic!("unexpected error {:?}", e),
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crate::rest_client::{generate_storage_sas, SASType};
-use crate::IPRange;
+use crate::rest_client::{generate_storage_sas, get_account, SASType};
+use crate::{IPRange, UserDelegationKey};
 use azure_sdk_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
 use std::marker::PhantomData;
@@ -27,6 +27,8 @@ where
     p_validity_end: PhantomData<ValidityEndSet>,
     at_least_one_permission: PhantomData<AtLeastOnePermission>,
     key: Option<&'a str>,
+    account: Option<&'a str>,
+    user_delegation_key: Option<&'a UserDelegationKey>,
     identifier: Option<&'a str>,
     ip_range: Option<&'a IPRange>,
     validity_start: Option<&'a DateTime<Utc>>,
@@ -52,6 +54,8 @@ impl<'a> ContainerSASBuilder<'a, No, No, No> {
             path,
             p_key: PhantomData {},
             key: None,
+            account: None,
+            user_delegation_key: None,
             p_validity_end: PhantomData {},
             at_least_one_permission: PhantomData {},
             validity_end: None,
@@ -86,6 +90,42 @@ where
             path: self.path,
             p_key: PhantomData {},
             key: Some(key),
+            account: self.account,
+            user_delegation_key: None,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            snapshot_time: self.snapshot_time,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    /// Signs the SAS with a user delegation key instead of the account key, so it can be
+    /// generated by a client authorized with Azure Active Directory.
+    #[inline]
+    pub fn with_user_delegation_key(
+        &self,
+        user_delegation_key: &'a UserDelegationKey,
+    ) -> ContainerSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission> {
+        ContainerSASBuilder {
+            path: self.path,
+            p_key: PhantomData {},
+            key: Some(user_delegation_key.value.as_str()),
+            account: self.account,
+            user_delegation_key: Some(user_delegation_key),
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -131,6 +171,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: PhantomData {},
             at_least_one_permission: self.at_least_one_permission,
             validity_end: Some(validity_end),
@@ -176,6 +218,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -203,6 +247,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -230,6 +276,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -257,6 +305,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -284,6 +334,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -311,6 +363,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -344,6 +398,16 @@ where
         self.path
     }
 
+    #[inline]
+    pub fn account(&self) -> &'a str {
+        self.account.unwrap_or_else(|| get_account(self.path))
+    }
+
+    #[inline]
+    pub fn user_delegation_key(&self) -> Option<&'a UserDelegationKey> {
+        self.user_delegation_key
+    }
+
     #[inline]
     pub fn identifier(&self) -> Option<&'a str> {
         self.identifier
@@ -420,6 +484,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -441,12 +507,45 @@ where
         }
     }
 
+    /// The account the resource belongs to. By default it is the first label of the
+    /// host, which is wrong for custom domains and path-style endpoints like the emulator.
+    #[inline]
+    pub fn with_account(self, account: &'a str) -> Self {
+        ContainerSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: Some(account),
+            user_delegation_key: self.user_delegation_key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            snapshot_time: self.snapshot_time,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
     #[inline]
     pub fn with_ip_range(self, ip_range: &'a IPRange) -> Self {
         ContainerSASBuilder {
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -474,6 +573,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -501,6 +602,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -528,6 +631,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -555,6 +660,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -582,6 +689,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -609,6 +718,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -636,6 +747,8 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            account: self.account,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -662,12 +775,14 @@ where
 impl<'a> ContainerSASBuilder<'a, Yes, Yes, Yes> {
     pub fn finalize(self) -> Url {
         let sas = generate_storage_sas(
+            self.account(),
             self.key(),
             self.validity_start(),
             self.validity_end(),
             self.path(),
             &self.permission_string(),
             if let Some(identifier) = self.identifier() { identifier } else { "" },
+            self.user_delegation_key(),
             self.ip_range(),
             SASType::Container,
             self.snapshot_time(),
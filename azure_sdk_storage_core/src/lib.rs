@@ -3,15 +3,25 @@
 #[macro_use]
 extern crate log;
 pub mod client;
+mod connection_string;
+pub use self::connection_string::{ConnectionString, EndpointProtocol};
 mod rest_client;
-pub use self::rest_client::{get_default_json_mime, get_json_mime_fullmetadata, get_json_mime_nometadata, perform_request, ServiceType};
+pub use self::rest_client::{
+    get_default_json_mime, get_json_mime_fullmetadata, get_json_mime_nometadata, perform_bearer_request, perform_request, sign_sub_request,
+    ServiceType,
+};
 use crate::client::Client;
 mod into_azure_path;
 pub mod prelude;
 pub use self::into_azure_path::IntoAzurePath;
+mod account_sas_builder;
 mod blob_sas_builder;
 mod container_sas_builder;
+mod service_sas_builder;
 pub mod shared_access_signature;
+mod table_sas_builder;
+mod user_delegation_key;
+pub use self::user_delegation_key::UserDelegationKey;
 
 pub trait ClientRequired<'a> {
     fn client(&self) -> &'a Client;
@@ -1,11 +1,16 @@
+pub use crate::account_sas_builder::AccountSASBuilder;
 pub use crate::blob_sas_builder::BlobSASBuilder;
 pub use crate::client::Client;
 pub use crate::container_sas_builder::ContainerSASBuilder;
+pub use crate::service_sas_builder::{FileSASBuilder, ShareSASBuilder};
+pub use crate::table_sas_builder::TableSASBuilder;
 pub use crate::IPRange;
+pub use crate::UserDelegationKey;
 
 pub use crate::SharedAccessSignatureSupport;
 
 pub use crate::shared_access_signature::{
-    ClientSharedAccessSignature, SasExpirySupport, SasIpSupport, SasPermissions, SasPermissionsSupport, SasProtocol, SasProtocolSupport,
-    SasResource, SasResourceSupport, SasResourceType, SasResourceTypeSupport, SasService, SasStartSupport, SasVersion,
+    ClientSharedAccessSignature, ParsedSharedAccessSignature, SasExpirySupport, SasIpSupport, SasPermissions, SasPermissionsSupport,
+    SasProtocol, SasProtocolSupport, SasResource, SasResourceSupport, SasResourceType, SasResourceTypeSupport, SasService, SasStartSupport,
+    SasVersion,
 };
@@ -1,22 +1,24 @@
-use crate::IPRange;
+use crate::user_delegation_key::format_key_date;
+use crate::{IPRange, UserDelegationKey};
 use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::headers;
 use azure_sdk_core::util::{format_header_value, HeaderMapExt, RequestBuilderExt};
+use azure_sdk_core::{HttpClient, RequestSigner, ResponseFuture, TokenCredential};
 use base64;
 use chrono;
 use chrono::{DateTime, Utc};
 use hyper::{self, header, HeaderMap, Method};
-use hyper_rustls::HttpsConnector;
 use ring::hmac;
 use std::fmt::Write;
+use std::sync::Arc;
 use url;
 use url::form_urlencoded;
 
 #[derive(Debug, Clone, Copy)]
 pub enum ServiceType {
     Blob,
-    // Queue,
-    // File,
+    Queue,
+    File,
     Table,
 }
 
@@ -30,10 +32,11 @@ fn generate_authorization(
     h: &HeaderMap,
     u: &url::Url,
     method: &Method,
+    account: &str,
     hmac_key: &str,
     service_type: ServiceType,
 ) -> String {
-    let str_to_sign = string_to_sign(h, u, method, service_type);
+    let str_to_sign = string_to_sign(h, u, method, account, service_type);
 
     // debug!("\nstr_to_sign == {:?}\n", str_to_sign);
     // debug!("str_to_sign == {}", str_to_sign);
@@ -41,7 +44,7 @@ fn generate_authorization(
     let auth = encode_str_to_sign(&str_to_sign, hmac_key);
     // debug!("auth == {:?}", auth);
 
-    format!("SharedKey {}:{}", get_account(u), auth)
+    format!("SharedKey {}:{}", account, auth)
 }
 
 fn encode_str_to_sign(str_to_sign: &str, hmac_key: &str) -> String {
@@ -74,12 +77,14 @@ pub(crate) enum SASType {
 }
 
 pub(crate) fn generate_storage_sas(
+    account: &str,
     key: &str,
     start: Option<&DateTime<Utc>>,
     end: &DateTime<Utc>,
     path: &url::Url,
     permission: &str,
     identifier: &str,
+    user_delegation_key: Option<&UserDelegationKey>,
     ip_range: Option<&IPRange>,
     resource: SASType,
     snapshot_time: Option<&DateTime<Utc>>,
@@ -93,12 +98,9 @@ pub(crate) fn generate_storage_sas(
     starting_rk: &str,
     ending_rk: &str,
 ) -> String {
-    let canonicalized_resource = canonicalized_resource(path);
+    let canonicalized_resource = sas_canonicalized_resource(path, account);
     debug!("canonicalized_resource == {}", canonicalized_resource);
 
-    // if it's multiline take the first line only
-    let canonicalized_resource = canonicalized_resource.lines().next().unwrap();
-
     let start_string = if let Some(start) = start {
         start.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
     } else {
@@ -120,13 +122,19 @@ pub(crate) fn generate_storage_sas(
     let resource_char = match resource {
         SASType::Blob => "b",
         SASType::Container => "c",
-        SASType::FileShare => "s", // this is guessed... can and most likely will be wrong
-        SASType::File => "f",      // this is guessed... can and most likely will be wrong
+        SASType::FileShare => "s",
+        SASType::File => "f",
         SASType::Queue => "q",
         SASType::Table => "t",
     };
     debug!("resource_char == {}", resource_char);
 
+    // tables are signed with the lowercase name, whatever the case of the url
+    let canonicalized_resource = match resource {
+        SASType::Table => canonicalized_resource.to_lowercase(),
+        _ => canonicalized_resource.to_owned(),
+    };
+
     let type_canonicalized_resource = format!(
         "{}{}",
         match resource {
@@ -154,11 +162,24 @@ pub(crate) fn generate_storage_sas(
         "".to_owned()
     };
 
+    // A user delegation SAS signs the fields of the key in place of the identifier:
+    // https://docs.microsoft.com/en-us/rest/api/storageservices/create-user-delegation-sas#construct-a-user-delegation-signature
+    let identifier = match user_delegation_key {
+        Some(user_delegation_key) => format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            user_delegation_key.signed_oid,
+            user_delegation_key.signed_tid,
+            format_key_date(&user_delegation_key.signed_start),
+            format_key_date(&user_delegation_key.signed_expiry),
+            user_delegation_key.signed_service,
+            user_delegation_key.signed_version
+        ),
+        None => identifier.to_owned(),
+    };
+
     let string_to_sign = match resource {
-        // File and FileShare are guessed. The documentation is
-        // incosistent
-        // (https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#version-2018-11-09-and-later)
-        SASType::Blob | SASType::Container | SASType::File | SASType::FileShare => format!(
+        // https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#version-2018-11-09-and-later
+        SASType::Blob | SASType::Container => format!(
             "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
             permission,
             start_string,
@@ -176,6 +197,23 @@ pub(crate) fn generate_storage_sas(
             content_language,
             content_type
         ),
+        // the Files service signs neither the resource nor the snapshot time
+        SASType::File | SASType::FileShare => format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            permission,
+            start_string,
+            end_string,
+            type_canonicalized_resource,
+            identifier,
+            ip_range_string,
+            protocol,
+            SAS_VERSION,
+            cache_control,
+            content_disposition,
+            content_encoding,
+            content_language,
+            content_type
+        ),
         SASType::Table => format!(
             "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
             permission,
@@ -211,8 +249,23 @@ pub(crate) fn generate_storage_sas(
 
     let result = base64::encode(sig.as_ref());
 
+    // a table SAS names the table in place of the signed resource
+    let resource_parameter = match resource {
+        SASType::Table => format!(
+            "tn={}",
+            form_urlencoded::byte_serialize(
+                path.path_segments()
+                    .and_then(|mut segments| segments.next_back())
+                    .unwrap_or("")
+                    .as_bytes()
+            )
+            .collect::<String>()
+        ),
+        _ => format!("sr={}", resource_char),
+    };
+
     let token = format!(
-        "{}{}{}{}{}{}{}{}{}{}{}{}se={}&sp={}&sr={}&spr={}&sv={}&sig={}",
+        "{}{}{}{}{}{}{}{}{}{}{}{}se={}&sp={}&{}&spr={}&sv={}&sig={}",
         if let Some(_) = start {
             format!(
                 "st={}&",
@@ -314,13 +367,31 @@ pub(crate) fn generate_storage_sas(
         },
         form_urlencoded::byte_serialize(end_string.as_bytes()).collect::<String>(),
         permission,
-        resource_char,
+        resource_parameter,
         protocol,
         SAS_VERSION,
         form_urlencoded::byte_serialize(result.as_bytes()).collect::<String>()
     );
 
-    token
+    match user_delegation_key {
+        Some(user_delegation_key) => format!(
+            "{}&skoid={}&sktid={}&skt={}&ske={}&sks={}&skv={}",
+            token,
+            user_delegation_key.signed_oid,
+            user_delegation_key.signed_tid,
+            form_urlencoded::byte_serialize(
+                format_key_date(&user_delegation_key.signed_start).as_bytes()
+            )
+            .collect::<String>(),
+            form_urlencoded::byte_serialize(
+                format_key_date(&user_delegation_key.signed_expiry).as_bytes()
+            )
+            .collect::<String>(),
+            user_delegation_key.signed_service,
+            user_delegation_key.signed_version
+        ),
+        None => token,
+    }
 }
 
 #[allow(unknown_lints)]
@@ -328,6 +399,7 @@ fn string_to_sign(
     h: &HeaderMap,
     u: &url::Url,
     method: &Method,
+    account: &str,
     service_type: ServiceType,
 ) -> String {
     match service_type {
@@ -340,7 +412,7 @@ fn string_to_sign(
                 add_if_exists(h, headers::CONTENT_MD5),
                 add_if_exists(h, header::CONTENT_TYPE),
                 add_if_exists(h, HEADER_DATE),
-                canonicalized_resource_table(u)
+                canonicalized_resource_table(u, account)
             )
             .unwrap();
             s
@@ -369,7 +441,7 @@ fn string_to_sign(
                 add_if_exists(h, header::IF_UNMODIFIED_SINCE),
                 add_if_exists(h, header::RANGE),
                 canonicalize_header(h),
-                canonicalized_resource(u)
+                canonicalized_resource(u, account)
             )
             .unwrap();
             s
@@ -415,7 +487,7 @@ fn canonicalize_header(h: &HeaderMap) -> String {
 }
 
 #[inline]
-fn get_account(u: &url::Url) -> &str {
+pub(crate) fn get_account(u: &url::Url) -> &str {
     match u.host().unwrap().clone() {
         url::Host::Domain(dm) => {
             // debug!("dom == {:?}", dm);
@@ -432,16 +504,34 @@ fn get_account(u: &url::Url) -> &str {
 }
 
 // For table
-fn canonicalized_resource_table(u: &url::Url) -> String {
-    format!("/{}{}", get_account(u), u.path())
+fn canonicalized_resource_table(u: &url::Url, account: &str) -> String {
+    format!("/{}{}", account, u.path())
 }
 
-fn canonicalized_resource(u: &url::Url) -> String {
+// The resource signed by a service SAS, without the service prefix. Unlike the Shared Key
+// canonicalized resource it does not repeat the account name that path-style endpoints
+// (like the emulator) put in the path.
+pub(crate) fn sas_canonicalized_resource(u: &url::Url, account: &str) -> String {
+    let path = u.path();
+    let path_style = !u
+        .host_str()
+        .is_some_and(|host| host.starts_with(&format!("{}.", account)));
+
+    let path = match path.strip_prefix('/').and_then(|p| p.strip_prefix(account)) {
+        Some(rest) if path_style && (rest.is_empty() || rest.starts_with('/')) => rest,
+        _ => path,
+    };
+
+    format!("/{}{}", account, path)
+}
+
+// The account is not necessarily the first label of the host: custom domains,
+// private endpoints and emulators use a different host.
+fn canonicalized_resource(u: &url::Url, account: &str) -> String {
     let mut can_res: String = String::new();
     can_res += "/";
 
-    let account = get_account(u);
-    can_res += &account;
+    can_res += account;
 
     let paths = u.path_segments().unwrap();
 
@@ -506,24 +596,133 @@ fn lexy_sort(vec: &url::form_urlencoded::Parse, query_param: &str) -> Vec<String
     v_values
 }
 
-#[allow(unknown_lints)]
+/// Signs again with the account key the retries of the requests authorized
+/// with a shared key, refreshing their date.
+#[derive(Debug, Clone)]
+pub(crate) struct SharedKeySigner {
+    account: String,
+    key: String,
+    service_type: ServiceType,
+}
+
+impl SharedKeySigner {
+    pub(crate) fn new(account: &str, key: &str, service_type: ServiceType) -> SharedKeySigner {
+        SharedKeySigner {
+            account: account.to_owned(),
+            key: key.to_owned(),
+            service_type,
+        }
+    }
+}
+
+impl RequestSigner for SharedKeySigner {
+    fn sign(&self, request: &mut hyper::Request<hyper::Body>) -> Result<(), AzureError> {
+        // Requests authorized by a SAS token carry no shared key signature.
+        let signed = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .is_some_and(|auth| auth.as_bytes().starts_with(b"SharedKey "));
+        if !signed {
+            return Ok(());
+        }
+
+        let time = format!("{}", chrono::Utc::now().format("%a, %d %h %Y %T GMT"));
+        request
+            .headers_mut()
+            .insert(HEADER_DATE, format_header_value(time)?);
+
+        let url = url::Url::parse(&request.uri().to_string())?;
+        let auth = generate_authorization(
+            request.headers(),
+            &url,
+            request.method(),
+            &self.account,
+            &self.key,
+            self.service_type,
+        );
+        request
+            .headers_mut()
+            .insert(header::AUTHORIZATION, format_header_value(auth)?);
+        Ok(())
+    }
+}
+
+#[allow(unknown_lints, clippy::too_many_arguments)]
 pub fn perform_request<F>(
-    client: &hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    client: &dyn HttpClient,
     uri: &str,
     http_method: &Method,
+    account: &str,
     azure_key: &str,
     headers_func: F,
     request_body: Option<&[u8]>,
     service_type: ServiceType,
-) -> Result<hyper::client::ResponseFuture, AzureError>
+) -> Result<ResponseFuture, AzureError>
+where
+    F: FnOnce(&mut ::http::request::Builder),
+{
+    let url = url::Url::parse(uri)?;
+    let mut request = build_request(uri, http_method, headers_func, request_body)?;
+
+    // We sign the request only if it is not already signed (with the signature of an
+    // SAS token for example)
+    if url.query_pairs().find(|p| p.0 == "sig").is_none() {
+        let auth = generate_authorization(
+            request.headers(),
+            &url,
+            http_method,
+            account,
+            azure_key,
+            service_type,
+        );
+        request
+            .headers_mut()
+            .insert(header::AUTHORIZATION, format_header_value(auth)?);
+    }
+
+    Ok(client.request(request))
+}
+
+/// Sends the request like `perform_request` but authorizes it with a token
+/// of `token_credential` for `scope`, sent as `Authorization: Bearer`, instead
+/// of signing it with the account key. The token is requested when the
+/// returned future is first polled.
+pub fn perform_bearer_request<F>(
+    client: Arc<dyn HttpClient>,
+    uri: &str,
+    http_method: &Method,
+    token_credential: Arc<dyn TokenCredential>,
+    scope: &'static str,
+    headers_func: F,
+    request_body: Option<&[u8]>,
+) -> Result<ResponseFuture, AzureError>
+where
+    F: FnOnce(&mut ::http::request::Builder),
+{
+    let mut request = build_request(uri, http_method, headers_func, request_body)?;
+
+    Ok(Box::pin(async move {
+        let token = token_credential.get_token(scope).await?;
+        request.headers_mut().insert(
+            header::AUTHORIZATION,
+            format_header_value(format!("Bearer {}", token.token))?,
+        );
+        client.request(request).await
+    }))
+}
+
+fn build_request<F>(
+    uri: &str,
+    http_method: &Method,
+    headers_func: F,
+    request_body: Option<&[u8]>,
+) -> Result<hyper::Request<hyper::Body>, AzureError>
 where
     F: FnOnce(&mut ::http::request::Builder),
 {
     let dt = chrono::Utc::now();
     let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
 
-    let url = url::Url::parse(uri)?;
-
     // for header in additional_headers.iter() {
     //     debug!("{:?}", header.value_string());
     //     h.set();
@@ -542,31 +741,51 @@ where
     // a Cow with 'static lifetime...
     headers_func(&mut request);
 
-    request
-        .header_bytes(HEADER_DATE, time)
-        .header_static(HEADER_VERSION, AZURE_VERSION);
+    request.header_bytes(HEADER_DATE, time);
+
+    // Operations introduced after AZURE_VERSION pass the version they need
+    // in headers_func.
+    let has_version = request
+        .headers_ref()
+        .map(|h| h.contains_key(HEADER_VERSION))
+        .unwrap_or(false);
+    if !has_version {
+        request.header_static(HEADER_VERSION, AZURE_VERSION);
+    }
 
     let b = request_body
         .map(|v| Vec::from(v).into())
         .unwrap_or_else(hyper::Body::empty);
-    let mut request = request.body(b)?;
+    Ok(request.body(b)?)
+}
+
+/// Signs a request that is not sent on its own but embedded in the body
+/// of another one, like the sub-requests of a blob batch. `headers` must
+/// hold every header of the sub-request but `x-ms-date`, which is added
+/// here along with the `Authorization` header. As in `perform_request`,
+/// requests already carrying a SAS signature are not signed.
+pub fn sign_sub_request(
+    uri: &str,
+    http_method: &Method,
+    headers: &mut HeaderMap,
+    account: &str,
+    azure_key: &str,
+    service_type: ServiceType,
+) -> Result<(), AzureError> {
+    let dt = chrono::Utc::now();
+    let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
+
+    let url = url::Url::parse(uri)?;
+
+    headers.insert(HEADER_DATE, format_header_value(time)?);
 
-    // We sign the request only if it is not already signed (with the signature of an
-    // SAS token for example)
     if url.query_pairs().find(|p| p.0 == "sig").is_none() {
-        let auth = generate_authorization(
-            request.headers(),
-            &url,
-            http_method,
-            azure_key,
-            service_type,
-        );
-        request
-            .headers_mut()
-            .insert(header::AUTHORIZATION, format_header_value(auth)?);
+        let auth =
+            generate_authorization(headers, &url, http_method, account, azure_key, service_type);
+        headers.insert(header::AUTHORIZATION, format_header_value(auth)?);
     }
 
-    Ok(client.request(request))
+    Ok(())
 }
 
 #[inline]
@@ -640,7 +859,7 @@ mod test {
             header::HeaderValue::from_static(AZURE_VERSION),
         );
 
-        let s = string_to_sign(&headers, &u, &method, service_type);
+        let s = string_to_sign(&headers, &u, &method, "mindrust", service_type);
 
         assert_eq!(
             s,
@@ -655,7 +874,48 @@ Wed, 03 May 2017 14:04:56 GMT
     #[test]
     fn test_canonicalize_resource_10() {
         let url = url::Url::parse("https://mindrust.table.core.windows.net/TABLES").unwrap();
-        assert_eq!(super::canonicalized_resource(&url), "/mindrust/TABLES");
+        assert_eq!(
+            super::canonicalized_resource(&url, "mindrust"),
+            "/mindrust/TABLES"
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_resource_custom_endpoint() {
+        let url = url::Url::parse(
+            "http://localhost:10000/devstoreaccount1/mycontainer?restype=container",
+        )
+        .unwrap();
+        assert_eq!(
+            super::canonicalized_resource(&url, "devstoreaccount1"),
+            "/devstoreaccount1/devstoreaccount1/mycontainer\nrestype:container"
+        );
+    }
+
+    #[test]
+    fn test_sas_canonicalized_resource() {
+        let url =
+            url::Url::parse("https://mindrust.blob.core.windows.net/mycontainer/myblob?comp=list")
+                .unwrap();
+        assert_eq!(
+            super::sas_canonicalized_resource(&url, "mindrust"),
+            "/mindrust/mycontainer/myblob"
+        );
+
+        // the emulator puts the account in the path
+        let url =
+            url::Url::parse("http://127.0.0.1:10000/devstoreaccount1/mycontainer/myblob").unwrap();
+        assert_eq!(
+            super::sas_canonicalized_resource(&url, "devstoreaccount1"),
+            "/devstoreaccount1/mycontainer/myblob"
+        );
+
+        // custom domains do not name the account
+        let url = url::Url::parse("https://www.contoso.com/mycontainer/myblob").unwrap();
+        assert_eq!(
+            super::sas_canonicalized_resource(&url, "mindrust"),
+            "/mindrust/mycontainer/myblob"
+        );
     }
 
     #[test]
@@ -666,7 +926,7 @@ Wed, 03 May 2017 14:04:56 GMT
         )
         .unwrap();
         assert_eq!(
-            super::canonicalized_resource(&url),
+            super::canonicalized_resource(&url, "myaccount"),
             "/myaccount/mycontainer\ncomp:metadata\nrestype:container"
         );
     }
@@ -680,7 +940,7 @@ Wed, 03 May 2017 14:04:56 GMT
         )
         .unwrap();
         assert_eq!(
-            super::canonicalized_resource(&url),
+            super::canonicalized_resource(&url, "myaccount"),
             "/myaccount/mycontainer\ncomp:list\ninclude:metadata,snapshots,\
              uncommittedblobs\nrestype:container"
         );
@@ -694,7 +954,7 @@ Wed, 03 May 2017 14:04:56 GMT
         )
         .unwrap();
         assert_eq!(
-            super::canonicalized_resource(&url),
+            super::canonicalized_resource(&url, "myaccount-secondary"),
             "/myaccount-secondary/mycontainer/myblob"
         );
     }
@@ -737,4 +997,177 @@ Wed, 03 May 2017 14:04:56 GMT
             "YuKoXELO9M9HXeeGaSXBr4Nk+CgPAEQhcwJ6tVtBRCw=".to_owned()
         );
     }
+
+    #[test]
+    fn file_sas_does_not_sign_the_resource() {
+        use super::*;
+
+        let url =
+            url::Url::parse("https://mindrust.file.core.windows.net/myshare/dir/file.txt").unwrap();
+        let end = chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        let token = generate_storage_sas(
+            "mindrust",
+            "MDEyMzQ1Njc4OWFiY2RlZg==",
+            None,
+            &end,
+            &url,
+            "r",
+            "",
+            None,
+            None,
+            SASType::File,
+            None,
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+        );
+
+        assert_eq!(
+            token,
+            "se=2020-01-01T00%3A00%3A00Z&sp=r&sr=f&spr=https&sv=2019-02-02\
+             &sig=CczCL3jFDiLy9TVYmiUYRtq3oZnsNXk%2Fg0Lycmhoyvw%3D"
+        );
+    }
+
+    #[test]
+    fn table_sas_names_the_table() {
+        use super::*;
+
+        let url = url::Url::parse("https://mindrust.table.core.windows.net/MyTable").unwrap();
+        let end = chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        let token = generate_storage_sas(
+            "mindrust",
+            "MDEyMzQ1Njc4OWFiY2RlZg==",
+            None,
+            &end,
+            &url,
+            "raud",
+            "",
+            None,
+            None,
+            SASType::Table,
+            None,
+            "",
+            "",
+            "",
+            "",
+            "",
+            "p1",
+            "p2",
+            "r1",
+            "",
+        );
+
+        assert_eq!(
+            token,
+            "spk=p1&epk=p2&srk=r1&se=2020-01-01T00%3A00%3A00Z&sp=raud&tn=MyTable&spr=https\
+             &sv=2019-02-02&sig=D7dpwX3M6AkYcmbjKvIiu0ap68RBL%2BzV8cAo9xmfTEI%3D"
+        );
+    }
+
+    #[test]
+    fn user_delegation_sas_signs_the_key_fields() {
+        use super::*;
+        use chrono::TimeZone;
+
+        let url =
+            url::Url::parse("https://mindrust.blob.core.windows.net/container/blob.txt").unwrap();
+        let end = chrono::Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let user_delegation_key = UserDelegationKey {
+            signed_oid: "e4c1b45a-b2e3-4c4e-a8b1-0e6e2b6d5f1b".to_owned(),
+            signed_tid: "72f988bf-86f1-41af-91ab-2d7cd011db47".to_owned(),
+            signed_start: chrono::Utc.with_ymd_and_hms(2020, 3, 1, 10, 0, 0).unwrap(),
+            signed_expiry: chrono::Utc.with_ymd_and_hms(2020, 3, 2, 10, 0, 0).unwrap(),
+            signed_service: "b".to_owned(),
+            signed_version: "2019-02-02".to_owned(),
+            value: "MDEyMzQ1Njc4OWFiY2RlZg==".to_owned(),
+        };
+
+        let token = generate_storage_sas(
+            "mindrust",
+            &user_delegation_key.value,
+            None,
+            &end,
+            &url,
+            "r",
+            "",
+            Some(&user_delegation_key),
+            None,
+            SASType::Blob,
+            None,
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+        );
+
+        assert_eq!(
+            token,
+            "se=2020-01-01T00%3A00%3A00Z&sp=r&sr=b&spr=https&sv=2019-02-02\
+             &sig=aaVBGiLg2CIMpx4nwUpwSfPZ1UGeaTfxGrwCwlqzNbg%3D\
+             &skoid=e4c1b45a-b2e3-4c4e-a8b1-0e6e2b6d5f1b&sktid=72f988bf-86f1-41af-91ab-2d7cd011db47\
+             &skt=2020-03-01T10%3A00%3A00Z&ske=2020-03-02T10%3A00%3A00Z&sks=b&skv=2019-02-02"
+        );
+    }
+
+    #[test]
+    fn sign_sub_request_adds_date_and_authorization() {
+        use super::*;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_LENGTH, "0".parse().unwrap());
+
+        sign_sub_request(
+            "https://mindrust.blob.core.windows.net/mycontainer/myblob",
+            &Method::DELETE,
+            &mut headers,
+            "mindrust",
+            "MDEyMzQ1Njc4OWFiY2RlZg==",
+            ServiceType::Blob,
+        )
+        .unwrap();
+
+        assert!(headers.contains_key(HEADER_DATE));
+        assert!(headers
+            .get_as_str(header::AUTHORIZATION)
+            .unwrap()
+            .starts_with("SharedKey mindrust:"));
+    }
+
+    #[test]
+    fn sign_sub_request_skips_sas_requests() {
+        use super::*;
+
+        let mut headers = HeaderMap::new();
+
+        sign_sub_request(
+            "https://mindrust.blob.core.windows.net/mycontainer/myblob?sv=2019-02-02&sig=abc",
+            &Method::DELETE,
+            &mut headers,
+            "mindrust",
+            "",
+            ServiceType::Blob,
+        )
+        .unwrap();
+
+        assert!(headers.contains_key(HEADER_DATE));
+        assert!(!headers.contains_key(header::AUTHORIZATION));
+    }
 }
@@ -0,0 +1,466 @@
+use crate::rest_client::{generate_storage_sas, get_account, SASType};
+use crate::IPRange;
+use azure_sdk_core::{No, ToAssign, Yes};
+use chrono::{DateTime, Utc};
+use std::marker::PhantomData;
+use url::Url;
+
+/// A file, the resource of a [`FileSASBuilder`](type.FileSASBuilder.html).
+#[derive(Debug, Clone)]
+pub struct File {}
+
+/// A file share, the resource of a [`ShareSASBuilder`](type.ShareSASBuilder.html).
+#[derive(Debug, Clone)]
+pub struct Share {}
+
+/// Builds a service SAS for a file. `path` is the url of the file, for example
+/// `https://myaccount.file.core.windows.net/myshare/mydirectory/myfile`.
+pub type FileSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission> =
+    ServiceSASBuilder<'a, File, KeySet, ValidityEndSet, AtLeastOnePermission>;
+
+/// Builds a service SAS for a file share. `path` is the url of the share, for example
+/// `https://myaccount.file.core.windows.net/myshare`.
+pub type ShareSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission> =
+    ServiceSASBuilder<'a, Share, KeySet, ValidityEndSet, AtLeastOnePermission>;
+
+/// Builds a service SAS for a `Resource`
+/// ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas)).
+/// The resource decides which permissions can be granted and how the SAS is signed.
+#[derive(Debug, Clone)]
+pub struct ServiceSASBuilder<'a, Resource, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    path: &'a Url,
+    p_resource: PhantomData<Resource>,
+    p_key: PhantomData<KeySet>,
+    p_validity_end: PhantomData<ValidityEndSet>,
+    at_least_one_permission: PhantomData<AtLeastOnePermission>,
+    key: Option<&'a str>,
+    account: Option<&'a str>,
+    identifier: Option<&'a str>,
+    ip_range: Option<&'a IPRange>,
+    validity_start: Option<&'a DateTime<Utc>>,
+    validity_end: Option<&'a DateTime<Utc>>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    content_type: Option<&'a str>,
+    allow_read: bool,
+    allow_create: bool,
+    allow_write: bool,
+    allow_delete: bool,
+    allow_list: bool,
+}
+
+impl<'a, Resource> ServiceSASBuilder<'a, Resource, No, No, No> {
+    #[inline]
+    pub fn new(path: &'a Url) -> ServiceSASBuilder<'a, Resource, No, No, No> {
+        ServiceSASBuilder {
+            path,
+            p_resource: PhantomData {},
+            p_key: PhantomData {},
+            p_validity_end: PhantomData {},
+            at_least_one_permission: PhantomData {},
+            key: None,
+            account: None,
+            identifier: None,
+            ip_range: None,
+            validity_start: None,
+            validity_end: None,
+            cache_control: None,
+            content_disposition: None,
+            content_encoding: None,
+            content_language: None,
+            content_type: None,
+            allow_read: false,
+            allow_create: false,
+            allow_write: false,
+            allow_delete: false,
+            allow_list: false,
+        }
+    }
+}
+
+impl<'a, Resource, KeySet, ValidityEndSet, AtLeastOnePermission>
+    ServiceSASBuilder<'a, Resource, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    // the same parameters in another state
+    fn with_state<K, V, P>(&self) -> ServiceSASBuilder<'a, Resource, K, V, P>
+    where
+        K: ToAssign,
+        V: ToAssign,
+        P: ToAssign,
+    {
+        ServiceSASBuilder {
+            path: self.path,
+            p_resource: PhantomData {},
+            p_key: PhantomData {},
+            p_validity_end: PhantomData {},
+            at_least_one_permission: PhantomData {},
+            key: self.key,
+            account: self.account,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            validity_end: self.validity_end,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_key(&self, key: &'a str) -> ServiceSASBuilder<'a, Resource, Yes, ValidityEndSet, AtLeastOnePermission> {
+        ServiceSASBuilder {
+            key: Some(key),
+            ..self.with_state()
+        }
+    }
+
+    #[inline]
+    pub fn with_validity_end(&self, validity_end: &'a DateTime<Utc>) -> ServiceSASBuilder<'a, Resource, KeySet, Yes, AtLeastOnePermission> {
+        ServiceSASBuilder {
+            validity_end: Some(validity_end),
+            ..self.with_state()
+        }
+    }
+
+    #[inline]
+    pub fn allow_read(&self) -> ServiceSASBuilder<'a, Resource, KeySet, ValidityEndSet, Yes> {
+        ServiceSASBuilder {
+            allow_read: true,
+            ..self.with_state()
+        }
+    }
+
+    #[inline]
+    pub fn allow_create(&self) -> ServiceSASBuilder<'a, Resource, KeySet, ValidityEndSet, Yes> {
+        ServiceSASBuilder {
+            allow_create: true,
+            ..self.with_state()
+        }
+    }
+
+    #[inline]
+    pub fn allow_write(&self) -> ServiceSASBuilder<'a, Resource, KeySet, ValidityEndSet, Yes> {
+        ServiceSASBuilder {
+            allow_write: true,
+            ..self.with_state()
+        }
+    }
+
+    #[inline]
+    pub fn allow_delete(&self) -> ServiceSASBuilder<'a, Resource, KeySet, ValidityEndSet, Yes> {
+        ServiceSASBuilder {
+            allow_delete: true,
+            ..self.with_state()
+        }
+    }
+}
+
+impl<'a, Resource, ValidityEndSet, AtLeastOnePermission> ServiceSASBuilder<'a, Resource, Yes, ValidityEndSet, AtLeastOnePermission>
+where
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        self.key.unwrap()
+    }
+}
+
+impl<'a, Resource, KeySet, AtLeastOnePermission> ServiceSASBuilder<'a, Resource, KeySet, Yes, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn validity_end(&self) -> &'a DateTime<Utc> {
+        self.validity_end.unwrap()
+    }
+}
+
+// only the shares can be listed
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission> ServiceSASBuilder<'a, Share, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn allow_list(&self) -> ServiceSASBuilder<'a, Share, KeySet, ValidityEndSet, Yes> {
+        ServiceSASBuilder {
+            allow_list: true,
+            ..self.with_state()
+        }
+    }
+
+    #[inline]
+    pub fn can_list(&self) -> bool {
+        self.allow_list
+    }
+}
+
+// methods callable regardless
+impl<'a, Resource, KeySet, ValidityEndSet, AtLeastOnePermission>
+    ServiceSASBuilder<'a, Resource, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    fn path(&self) -> &'a Url {
+        self.path
+    }
+
+    #[inline]
+    pub fn account(&self) -> &'a str {
+        self.account.unwrap_or_else(|| get_account(self.path))
+    }
+
+    #[inline]
+    pub fn identifier(&self) -> Option<&'a str> {
+        self.identifier
+    }
+
+    #[inline]
+    pub fn ip_range(&self) -> Option<&'a IPRange> {
+        self.ip_range
+    }
+
+    #[inline]
+    pub fn validity_start(&self) -> Option<&'a DateTime<Utc>> {
+        self.validity_start
+    }
+
+    #[inline]
+    pub fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+
+    #[inline]
+    pub fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+
+    #[inline]
+    pub fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+
+    #[inline]
+    pub fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+
+    #[inline]
+    pub fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+
+    #[inline]
+    pub fn can_read(&self) -> bool {
+        self.allow_read
+    }
+    #[inline]
+    pub fn can_create(&self) -> bool {
+        self.allow_create
+    }
+    #[inline]
+    pub fn can_write(&self) -> bool {
+        self.allow_write
+    }
+    #[inline]
+    pub fn can_delete(&self) -> bool {
+        self.allow_delete
+    }
+
+    /// The account the resource belongs to. By default it is the first label of the
+    /// host, which is wrong for custom domains and path-style endpoints like the emulator.
+    #[inline]
+    pub fn with_account(self, account: &'a str) -> Self {
+        ServiceSASBuilder {
+            account: Some(account),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_identifier(self, identifier: &'a str) -> Self {
+        ServiceSASBuilder {
+            identifier: Some(identifier),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_ip_range(self, ip_range: &'a IPRange) -> Self {
+        ServiceSASBuilder {
+            ip_range: Some(ip_range),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_validity_start(self, validity_start: &'a DateTime<Utc>) -> Self {
+        ServiceSASBuilder {
+            validity_start: Some(validity_start),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_cache_control(self, cache_control: &'a str) -> Self {
+        ServiceSASBuilder {
+            cache_control: Some(cache_control),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_content_disposition(self, content_disposition: &'a str) -> Self {
+        ServiceSASBuilder {
+            content_disposition: Some(content_disposition),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_content_encoding(self, content_encoding: &'a str) -> Self {
+        ServiceSASBuilder {
+            content_encoding: Some(content_encoding),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_content_language(self, content_language: &'a str) -> Self {
+        ServiceSASBuilder {
+            content_language: Some(content_language),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_content_type(self, content_type: &'a str) -> Self {
+        ServiceSASBuilder {
+            content_type: Some(content_type),
+            ..self
+        }
+    }
+}
+
+// methods callable only when fully constructed
+impl<'a, Resource> ServiceSASBuilder<'a, Resource, Yes, Yes, Yes> {
+    pub fn permission_string(&self) -> String {
+        let mut s = String::with_capacity(5);
+        if self.can_read() {
+            s.push('r')
+        }
+        if self.can_create() {
+            s.push('c')
+        }
+        if self.can_write() {
+            s.push('w')
+        }
+        if self.can_delete() {
+            s.push('d')
+        }
+        if self.allow_list {
+            s.push('l')
+        }
+
+        s
+    }
+
+    fn sign(&self, resource: SASType) -> Url {
+        let sas = generate_storage_sas(
+            self.account(),
+            self.key(),
+            self.validity_start(),
+            self.validity_end(),
+            self.path(),
+            &self.permission_string(),
+            self.identifier().unwrap_or(""),
+            None,
+            self.ip_range(),
+            resource,
+            None,
+            self.cache_control().unwrap_or(""),
+            self.content_disposition().unwrap_or(""),
+            self.content_encoding().unwrap_or(""),
+            self.content_language().unwrap_or(""),
+            self.content_type().unwrap_or(""),
+            "",
+            "",
+            "",
+            "",
+        );
+
+        if self.path().query().is_some() {
+            Url::parse(&format!("{}&{}", self.path(), &sas)).unwrap()
+        } else {
+            Url::parse(&format!("{}?{}", self.path(), &sas)).unwrap()
+        }
+    }
+}
+
+impl<'a> ServiceSASBuilder<'a, File, Yes, Yes, Yes> {
+    pub fn finalize(self) -> Url {
+        self.sign(SASType::File)
+    }
+}
+
+impl<'a> ServiceSASBuilder<'a, Share, Yes, Yes, Yes> {
+    pub fn finalize(self) -> Url {
+        self.sign(SASType::FileShare)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn file_and_share_sas() {
+        let end = chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let path = Url::parse("https://mindrust.file.core.windows.net/myshare/dir/file.txt").unwrap();
+        let sas = FileSASBuilder::new(&path)
+            .with_key("MDEyMzQ1Njc4OWFiY2RlZg==")
+            .with_validity_end(&end)
+            .allow_read()
+            .finalize();
+        assert_eq!(
+            sas.query(),
+            Some("se=2020-01-01T00%3A00%3A00Z&sp=r&sr=f&spr=https&sv=2019-02-02&sig=CczCL3jFDiLy9TVYmiUYRtq3oZnsNXk%2Fg0Lycmhoyvw%3D")
+        );
+
+        let path = Url::parse("https://mindrust.file.core.windows.net/myshare?restype=share").unwrap();
+        let sas = ShareSASBuilder::new(&path)
+            .with_key("MDEyMzQ1Njc4OWFiY2RlZg==")
+            .with_validity_end(&end)
+            .allow_list()
+            .allow_read()
+            .finalize();
+        let query = sas.query().unwrap();
+        assert!(query.starts_with("restype=share&se=2020-01-01T00%3A00%3A00Z&sp=rl&sr=s&"));
+    }
+}
@@ -1,11 +1,15 @@
 use super::{Client, ClientRequired};
+use crate::rest_client::sas_canonicalized_resource;
+use crate::IPRange;
+use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::{No, ToAssign};
 use base64::encode;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
 use ring::hmac;
 use std::fmt;
 use std::marker::PhantomData;
-use url::form_urlencoded;
+use std::net::IpAddr;
+use url::{form_urlencoded, Url};
 
 /// Service version of the shared access signature ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#specifying-the-signed-version-field)).
 #[derive(Copy, Clone)]
@@ -27,7 +31,7 @@ impl fmt::Display for SasVersion {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub enum SasService {
     Blob,
     Queue,
@@ -47,7 +51,7 @@ impl fmt::Display for SasService {
 }
 
 /// Specifies the protocol permitted for a request made with the SAS ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#specifying-the-http-protocol)).
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum SasProtocol {
     Https,
     HttpHttps,
@@ -82,7 +86,7 @@ impl fmt::Display for SasResource {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub enum SasResourceType {
     Service,
     Container,
@@ -757,3 +761,451 @@ where
         }
     }
 }
+
+/// A shared access signature parsed from a SAS token or a SAS URL.
+///
+/// The fields hold what the token claims: use
+/// [`verify`](struct.ParsedSharedAccessSignature.html#method.verify) to check the
+/// signature before trusting them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedSharedAccessSignature {
+    pub version: String,
+    /// The services of an account SAS (`ss`).
+    pub services: Option<String>,
+    /// The resource types of an account SAS (`srt`).
+    pub resource_types: Option<String>,
+    /// The resource of a service SAS (`sr`).
+    pub resource: Option<String>,
+    /// The table of a table SAS (`tn`), which has no resource.
+    pub table_name: Option<String>,
+    pub permissions: Option<String>,
+    pub start: Option<DateTime<Utc>>,
+    pub expiry: Option<DateTime<Utc>>,
+    pub ip_range: Option<IPRange>,
+    pub protocol: Option<SasProtocol>,
+    /// The stored access policy the SAS refers to (`si`).
+    pub identifier: Option<String>,
+    pub signature: String,
+    url: Option<Url>,
+    query_pairs: Vec<(String, String)>,
+}
+
+impl SharedAccessSignature {
+    /// Parses a SAS token, with or without the leading question mark, or a SAS URL.
+    pub fn parse(sas: &str) -> Result<ParsedSharedAccessSignature, AzureError> {
+        ParsedSharedAccessSignature::parse(sas)
+    }
+}
+
+impl ParsedSharedAccessSignature {
+    pub fn parse(sas: &str) -> Result<ParsedSharedAccessSignature, AzureError> {
+        let (url, query_pairs) = if sas.starts_with("https://") || sas.starts_with("http://") {
+            let url = Url::parse(sas)?;
+            let query_pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+            (Some(url), query_pairs)
+        } else {
+            let query = sas.trim_start_matches('?');
+            let query_pairs = form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect();
+            (None, query_pairs)
+        };
+
+        let mut sas = ParsedSharedAccessSignature {
+            version: String::new(),
+            services: None,
+            resource_types: None,
+            resource: None,
+            table_name: None,
+            permissions: None,
+            start: None,
+            expiry: None,
+            ip_range: None,
+            protocol: None,
+            identifier: None,
+            signature: String::new(),
+            url,
+            query_pairs,
+        };
+
+        sas.version = sas.required_parameter("sv")?.to_owned();
+        sas.signature = sas.required_parameter("sig")?.to_owned();
+        sas.services = sas.parameter("ss").map(str::to_owned);
+        sas.resource_types = sas.parameter("srt").map(str::to_owned);
+        sas.resource = sas.parameter("sr").map(str::to_owned);
+        sas.table_name = sas.parameter("tn").map(str::to_owned);
+        sas.permissions = sas.parameter("sp").map(str::to_owned);
+        sas.identifier = sas.parameter("si").map(str::to_owned);
+        sas.start = sas.parameter("st").map(parse_sas_date).transpose()?;
+        sas.expiry = sas.parameter("se").map(parse_sas_date).transpose()?;
+        sas.ip_range = sas.parameter("sip").map(parse_ip_range).transpose()?;
+        sas.protocol = sas.parameter("spr").map(parse_protocol).transpose()?;
+
+        if sas.services.is_none() && sas.resource.is_none() && sas.table_name.is_none() {
+            return Err(AzureError::InputParametersError(
+                "the SAS specifies neither the services (ss), the resource (sr) nor the table (tn)"
+                    .to_owned(),
+            ));
+        }
+
+        Ok(sas)
+    }
+
+    /// The value of the query parameter `name` of the token, if present.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.query_pairs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn required_parameter(&self, name: &str) -> Result<&str, AzureError> {
+        self.parameter(name).ok_or_else(|| {
+            AzureError::InputParametersError(format!("the SAS does not specify {}", name))
+        })
+    }
+
+    /// All the query parameters of the token, in their original order.
+    pub fn query_pairs(&self) -> &[(String, String)] {
+        &self.query_pairs
+    }
+
+    /// The URL the SAS was parsed from, if it was not a bare token.
+    pub fn url(&self) -> Option<&Url> {
+        self.url.as_ref()
+    }
+
+    pub fn is_account_sas(&self) -> bool {
+        self.services.is_some()
+    }
+
+    /// Returns `true` if the SAS has an expiry time in the past. The expiry of a
+    /// SAS referring to a stored access policy is not known without the policy.
+    pub fn is_expired(&self) -> bool {
+        self.expiry.is_some_and(|expiry| expiry <= Utc::now())
+    }
+
+    /// Returns `true` if the SAS grants `permission`. Permissions granted only by
+    /// a stored access policy are not known without the policy.
+    pub fn allows(&self, permission: SasPermissions) -> bool {
+        let permission = permission.to_string();
+        self.permissions
+            .as_ref()
+            .is_some_and(|permissions| permissions.contains(&permission))
+    }
+
+    /// Checks the signature against `key`: the account key, or the value of the user
+    /// delegation key for a user delegation SAS. `account` is the name of the storage
+    /// account. Service SAS can be verified only for tables, blobs and containers; the
+    /// latter only if the SAS was parsed from a URL, since the signature covers the
+    /// resource path.
+    pub fn verify(&self, account: &str, key: &str) -> Result<bool, AzureError> {
+        let string_to_sign = self.string_to_sign(account)?;
+
+        let signature = base64::decode(&self.signature).map_err(|_| {
+            AzureError::InputParametersError("the SAS signature is not base64".to_owned())
+        })?;
+        let key = hmac::Key::new(hmac::HMAC_SHA256, &base64::decode(key)?);
+
+        Ok(hmac::verify(&key, string_to_sign.as_bytes(), &signature).is_ok())
+    }
+
+    fn string_to_sign(&self, account: &str) -> Result<String, AzureError> {
+        let p = |name: &str| self.parameter(name).unwrap_or("");
+
+        if self.is_account_sas() {
+            // https://docs.microsoft.com/en-us/rest/api/storageservices/create-account-sas#constructing-the-signature-string
+            let mut string_to_sign = format!(
+                "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n",
+                account,
+                p("sp"),
+                p("ss"),
+                p("srt"),
+                p("st"),
+                p("se"),
+                p("sip"),
+                p("spr"),
+                p("sv"),
+            );
+            if self.version.as_str() >= "2020-12-06" {
+                string_to_sign.push_str(&format!("{}\n", p("ses")));
+            }
+            return Ok(string_to_sign);
+        }
+
+        if let Some(table_name) = &self.table_name {
+            if self.version.as_str() < "2015-04-05" {
+                return Err(AzureError::InputParametersError(format!(
+                    "cannot verify a table SAS with version {}",
+                    self.version
+                )));
+            }
+
+            // https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#version-2015-04-05-and-later
+            return Ok(format!(
+                "{}\n{}\n{}\n/table/{}/{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+                p("sp"),
+                p("st"),
+                p("se"),
+                account,
+                table_name.to_lowercase(),
+                p("si"),
+                p("sip"),
+                p("spr"),
+                p("sv"),
+                p("spk"),
+                p("srk"),
+                p("epk"),
+                p("erk")
+            ));
+        }
+
+        let resource = self.resource.as_deref().unwrap_or("");
+        let user_delegation = self.parameter("skoid").is_some();
+        let supported = match resource {
+            "b" | "c" if user_delegation => {
+                self.version.as_str() >= "2018-11-09" && self.version.as_str() < "2020-02-10"
+            }
+            "b" | "c" => {
+                self.version.as_str() >= "2018-11-09" && self.version.as_str() < "2020-12-06"
+            }
+            _ => false,
+        };
+        if !supported {
+            return Err(AzureError::InputParametersError(format!(
+                "cannot verify a service SAS with resource {} and version {}",
+                resource, self.version
+            )));
+        }
+
+        let url = self.url.as_ref().ok_or_else(|| {
+            AzureError::InputParametersError(
+                "the URL of the resource is needed to verify a service SAS".to_owned(),
+            )
+        })?;
+        let canonicalized_resource = sas_canonicalized_resource(url, account);
+
+        let identifier = if user_delegation {
+            format!(
+                "{}\n{}\n{}\n{}\n{}\n{}",
+                p("skoid"),
+                p("sktid"),
+                p("skt"),
+                p("ske"),
+                p("sks"),
+                p("skv")
+            )
+        } else {
+            p("si").to_owned()
+        };
+
+        // https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#version-2018-11-09-and-later
+        Ok(format!(
+            "{}\n{}\n{}\n/blob{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            p("sp"),
+            p("st"),
+            p("se"),
+            canonicalized_resource,
+            identifier,
+            p("sip"),
+            p("spr"),
+            p("sv"),
+            p("sr"),
+            p("snapshot"),
+            p("rscc"),
+            p("rscd"),
+            p("rsce"),
+            p("rscl"),
+            p("rsct")
+        ))
+    }
+}
+
+// The service accepts both full UTC times and plain dates.
+fn parse_sas_date(date: &str) -> Result<DateTime<Utc>, AzureError> {
+    match DateTime::parse_from_rfc3339(date) {
+        Ok(date) => Ok(date.with_timezone(&Utc)),
+        Err(_) => Ok(Utc.from_utc_datetime(
+            &NaiveDate::parse_from_str(date, "%Y-%m-%d")?.and_time(NaiveTime::MIN),
+        )),
+    }
+}
+
+fn parse_ip_range(ip_range: &str) -> Result<IPRange, AzureError> {
+    let parse = |ip: &str| {
+        ip.parse::<IpAddr>().map_err(|_| {
+            AzureError::InputParametersError(format!("invalid SAS IP range {}", ip_range))
+        })
+    };
+
+    let mut ips = ip_range.splitn(2, '-');
+    let start = parse(ips.next().unwrap_or(""))?;
+    let end = match ips.next() {
+        Some(end) => parse(end)?,
+        None => start,
+    };
+    Ok(IPRange { start, end })
+}
+
+fn parse_protocol(protocol: &str) -> Result<SasProtocol, AzureError> {
+    match protocol {
+        "https" => Ok(SasProtocol::Https),
+        "http,https" | "https,http" => Ok(SasProtocol::HttpHttps),
+        _ => Err(AzureError::InputParametersError(format!(
+            "invalid SAS protocol {}",
+            protocol
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    const KEY: &str = "MDEyMzQ1Njc4OWFiY2RlZg==";
+
+    #[test]
+    fn parse_service_sas_url() {
+        let sas = SharedAccessSignature::parse(
+            "https://mindrust.blob.core.windows.net/container/blob.txt?sv=2019-02-02\
+             &st=2020-01-01&se=2020-01-02T00%3A00%3A00Z&sr=b&sp=rw&sip=168.1.5.60-168.1.5.70\
+             &spr=https&rscc=no-cache&sig=Wv4loh%2FbJG0cm3j%2FUHbrKTNfBsMXKGsxXWMFNLDlxJU%3D",
+        )
+        .unwrap();
+
+        assert_eq!(sas.version, "2019-02-02");
+        assert!(!sas.is_account_sas());
+        assert_eq!(sas.resource.as_deref(), Some("b"));
+        assert_eq!(sas.start.unwrap().hour(), 0);
+        assert_eq!(sas.expiry.unwrap().day(), 2);
+        assert_eq!(
+            sas.ip_range.as_ref().unwrap().end,
+            "168.1.5.70".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(sas.protocol, Some(SasProtocol::Https));
+        assert_eq!(sas.parameter("rscc"), Some("no-cache"));
+        assert!(sas.is_expired());
+        assert!(sas.allows(SasPermissions::Write));
+        assert!(!sas.allows(SasPermissions::Delete));
+
+        assert!(sas.verify("mindrust", KEY).unwrap());
+        assert!(!sas.verify("mindrust", "Zm9vYmFy").unwrap());
+        assert!(!sas.verify("otheraccount", KEY).unwrap());
+
+        // the signature covers the path of the resource
+        let sas = SharedAccessSignature::parse(
+            &sas.url().unwrap().as_str().replace("blob.txt", "other.txt"),
+        )
+        .unwrap();
+        assert!(!sas.verify("mindrust", KEY).unwrap());
+    }
+
+    #[test]
+    fn verify_path_style_service_sas() {
+        // the emulator puts the account in the path, which the signature does not repeat
+        let url =
+            Url::parse("https://127.0.0.1:10000/devstoreaccount1/container/blob.txt").unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let signed = crate::blob_sas_builder::BlobSASBuilder::new(&url)
+            .with_key(KEY)
+            .with_account("devstoreaccount1")
+            .with_validity_end(&end)
+            .allow_read()
+            .finalize();
+
+        let sas = SharedAccessSignature::parse(signed.as_str()).unwrap();
+        assert!(sas.verify("devstoreaccount1", KEY).unwrap());
+    }
+
+    #[test]
+    fn verify_account_sas() {
+        let sas = SharedAccessSignature::parse(
+            "?sv=2020-12-06&ss=b&srt=co&sp=rl&se=2020-01-01T00%3A00%3A00Z&spr=http%2Chttps\
+             &ses=scope1&sig=zfrVvsrtpwpS0uAYRZudtHePZhJbID87dqrcOAoui%2FM%3D",
+        )
+        .unwrap();
+
+        assert!(sas.is_account_sas());
+        assert_eq!(sas.services.as_deref(), Some("b"));
+        assert_eq!(sas.resource_types.as_deref(), Some("co"));
+        assert_eq!(sas.protocol, Some(SasProtocol::HttpHttps));
+        assert!(sas.allows(SasPermissions::List));
+        assert!(sas.verify("mindrust", KEY).unwrap());
+
+        let tampered = SharedAccessSignature::parse(&format!(
+            "?{}",
+            sas.query_pairs()
+                .iter()
+                .map(|(key, value)| {
+                    let value = if key == "sp" { "rwl" } else { value };
+                    format!(
+                        "{}={}",
+                        key,
+                        form_urlencoded::byte_serialize(value.as_bytes()).collect::<String>()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("&")
+        ))
+        .unwrap();
+        assert!(tampered.allows(SasPermissions::Write));
+        assert!(!tampered.verify("mindrust", KEY).unwrap());
+    }
+
+    #[test]
+    fn verify_user_delegation_sas() {
+        let sas = SharedAccessSignature::parse(
+            "https://mindrust.blob.core.windows.net/container/blob.txt\
+             ?se=2020-01-01T00%3A00%3A00Z&sp=r&sr=b&spr=https&sv=2019-02-02\
+             &sig=aaVBGiLg2CIMpx4nwUpwSfPZ1UGeaTfxGrwCwlqzNbg%3D\
+             &skoid=e4c1b45a-b2e3-4c4e-a8b1-0e6e2b6d5f1b&sktid=72f988bf-86f1-41af-91ab-2d7cd011db47\
+             &skt=2020-03-01T10%3A00%3A00Z&ske=2020-03-02T10%3A00%3A00Z&sks=b&skv=2019-02-02",
+        )
+        .unwrap();
+
+        assert!(sas.verify("mindrust", KEY).unwrap());
+    }
+
+    #[test]
+    fn verify_table_sas() {
+        let sas = SharedAccessSignature::parse(
+            "spk=p1&epk=p2&srk=r1&se=2020-01-01T00%3A00%3A00Z&sp=raud&tn=MyTable&spr=https\
+             &sv=2019-02-02&sig=D7dpwX3M6AkYcmbjKvIiu0ap68RBL%2BzV8cAo9xmfTEI%3D",
+        )
+        .unwrap();
+
+        assert_eq!(sas.table_name.as_deref(), Some("MyTable"));
+        assert_eq!(sas.resource, None);
+        assert!(sas.allows(SasPermissions::Update));
+        assert!(sas.verify("mindrust", KEY).unwrap());
+        assert!(!sas.verify("mindrust", "Zm9vYmFy").unwrap());
+    }
+
+    #[test]
+    fn unverifiable_sas() {
+        // a service SAS needs the url of the resource
+        let sas =
+            SharedAccessSignature::parse("sv=2019-02-02&sr=b&sp=r&se=2020-01-01&sig=abc").unwrap();
+        assert!(sas.verify("mindrust", KEY).is_err());
+
+        let sas = SharedAccessSignature::parse(
+            "https://mindrust.file.core.windows.net/share/file.txt?sv=2019-02-02&sr=f&sp=r&se=2020-01-01&sig=abc",
+        )
+        .unwrap();
+        assert!(sas.verify("mindrust", KEY).is_err());
+    }
+
+    #[test]
+    fn parse_invalid_sas() {
+        assert!(SharedAccessSignature::parse("sv=2019-02-02&ss=b&sp=r").is_err());
+        assert!(SharedAccessSignature::parse("ss=b&sp=r&sig=abc").is_err());
+        assert!(SharedAccessSignature::parse("sv=2019-02-02&sp=r&sig=abc").is_err());
+        assert!(SharedAccessSignature::parse("sv=2019-02-02&ss=b&se=2020-13-01&sig=abc").is_err());
+        assert!(SharedAccessSignature::parse("sv=2019-02-02&ss=b&sip=localhost&sig=abc").is_err());
+        assert!(SharedAccessSignature::parse("sv=2019-02-02&ss=b&spr=http&sig=abc").is_err());
+        assert!(
+            SharedAccessSignature::parse("https://[mindrust/?sv=2019-02-02&ss=b&sig=abc").is_err()
+        );
+    }
+}
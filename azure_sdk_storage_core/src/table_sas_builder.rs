@@ -0,0 +1,563 @@
+use crate::rest_client::{generate_storage_sas, get_account, SASType};
+use crate::IPRange;
+use azure_sdk_core::{No, ToAssign, Yes};
+use chrono::{DateTime, Utc};
+use std::marker::PhantomData;
+use url::Url;
+
+/// Builds a service SAS for a table
+/// ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#specifying-table-access-ranges)).
+///
+/// `path` is the url of the table, for example `https://myaccount.table.core.windows.net/mytable`.
+/// The partition and row key ranges restrict the entities the SAS grants access to: a starting
+/// row key needs a starting partition key, and an ending row key an ending partition key.
+#[derive(Debug, Clone)]
+pub struct TableSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    path: &'a Url,
+    p_key: PhantomData<KeySet>,
+    p_validity_end: PhantomData<ValidityEndSet>,
+    at_least_one_permission: PhantomData<AtLeastOnePermission>,
+    key: Option<&'a str>,
+    account: Option<&'a str>,
+    identifier: Option<&'a str>,
+    ip_range: Option<&'a IPRange>,
+    validity_start: Option<&'a DateTime<Utc>>,
+    validity_end: Option<&'a DateTime<Utc>>,
+    starting_partition_key: Option<&'a str>,
+    starting_row_key: Option<&'a str>,
+    ending_partition_key: Option<&'a str>,
+    ending_row_key: Option<&'a str>,
+    allow_query: bool,
+    allow_add: bool,
+    allow_update: bool,
+    allow_delete: bool,
+}
+
+impl<'a> TableSASBuilder<'a, No, No, No> {
+    #[inline]
+    pub fn new(path: &'a Url) -> TableSASBuilder<'a, No, No, No> {
+        TableSASBuilder {
+            path,
+            p_key: PhantomData {},
+            key: None,
+            account: None,
+            p_validity_end: PhantomData {},
+            at_least_one_permission: PhantomData {},
+            validity_end: None,
+            identifier: None,
+            ip_range: None,
+            validity_start: None,
+            starting_partition_key: None,
+            starting_row_key: None,
+            ending_partition_key: None,
+            ending_row_key: None,
+            allow_query: false,
+            allow_add: false,
+            allow_update: false,
+            allow_delete: false,
+        }
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission> TableSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn with_key(&self, key: &'a str) -> TableSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission> {
+        TableSASBuilder {
+            path: self.path,
+            p_key: PhantomData {},
+            key: Some(key),
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+}
+
+impl<'a, ValidityEndSet, AtLeastOnePermission> TableSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission>
+where
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        self.key.unwrap()
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission> TableSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn with_validity_end(&self, validity_end: &'a DateTime<Utc>) -> TableSASBuilder<'a, KeySet, Yes, AtLeastOnePermission> {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: PhantomData {},
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: Some(validity_end),
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+}
+
+impl<'a, KeySet, AtLeastOnePermission> TableSASBuilder<'a, KeySet, Yes, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn validity_end(&self) -> &'a DateTime<Utc> {
+        self.validity_end.unwrap()
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission> TableSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn allow_query(&self) -> TableSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: true,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn allow_add(&self) -> TableSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: true,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn allow_update(&self) -> TableSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: true,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn allow_delete(&self) -> TableSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: true,
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission> TableSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    fn path(&self) -> &'a Url {
+        self.path
+    }
+
+    #[inline]
+    pub fn account(&self) -> &'a str {
+        self.account.unwrap_or_else(|| get_account(self.path))
+    }
+
+    #[inline]
+    pub fn identifier(&self) -> Option<&'a str> {
+        self.identifier
+    }
+
+    #[inline]
+    pub fn ip_range(&self) -> Option<&'a IPRange> {
+        self.ip_range
+    }
+
+    #[inline]
+    pub fn validity_start(&self) -> Option<&'a DateTime<Utc>> {
+        self.validity_start
+    }
+
+    #[inline]
+    pub fn starting_partition_key(&self) -> Option<&'a str> {
+        self.starting_partition_key
+    }
+
+    #[inline]
+    pub fn starting_row_key(&self) -> Option<&'a str> {
+        self.starting_row_key
+    }
+
+    #[inline]
+    pub fn ending_partition_key(&self) -> Option<&'a str> {
+        self.ending_partition_key
+    }
+
+    #[inline]
+    pub fn ending_row_key(&self) -> Option<&'a str> {
+        self.ending_row_key
+    }
+
+    #[inline]
+    pub fn can_query(&self) -> bool {
+        self.allow_query
+    }
+    #[inline]
+    pub fn can_add(&self) -> bool {
+        self.allow_add
+    }
+    #[inline]
+    pub fn can_update(&self) -> bool {
+        self.allow_update
+    }
+    #[inline]
+    pub fn can_delete(&self) -> bool {
+        self.allow_delete
+    }
+
+    /// The account the table belongs to. By default it is the first label of the
+    /// host, which is wrong for custom domains and path-style endpoints like the emulator.
+    #[inline]
+    pub fn with_account(self, account: &'a str) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: Some(account),
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn with_identifier(self, identifier: &'a str) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: Some(identifier),
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn with_ip_range(self, ip_range: &'a IPRange) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: Some(ip_range),
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn with_validity_start(self, validity_start: &'a DateTime<Utc>) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: Some(validity_start),
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn with_starting_partition_key(self, starting_partition_key: &'a str) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: Some(starting_partition_key),
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn with_starting_row_key(self, starting_row_key: &'a str) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: Some(starting_row_key),
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn with_ending_partition_key(self, ending_partition_key: &'a str) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: Some(ending_partition_key),
+            ending_row_key: self.ending_row_key,
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    #[inline]
+    pub fn with_ending_row_key(self, ending_row_key: &'a str) -> Self {
+        TableSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            account: self.account,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            starting_partition_key: self.starting_partition_key,
+            starting_row_key: self.starting_row_key,
+            ending_partition_key: self.ending_partition_key,
+            ending_row_key: Some(ending_row_key),
+            allow_query: self.allow_query,
+            allow_add: self.allow_add,
+            allow_update: self.allow_update,
+            allow_delete: self.allow_delete,
+        }
+    }
+}
+
+// methods callable only when fully constructed
+impl<'a> TableSASBuilder<'a, Yes, Yes, Yes> {
+    /// Returns the SAS token, to be used with `Client::azure_sas` to create a client
+    /// for the `TableService`.
+    pub fn finalize(self) -> String {
+        generate_storage_sas(
+            self.account(),
+            self.key(),
+            self.validity_start(),
+            self.validity_end(),
+            self.path(),
+            &self.permission_string(),
+            self.identifier().unwrap_or(""),
+            None,
+            self.ip_range(),
+            SASType::Table,
+            None,
+            "",
+            "",
+            "",
+            "",
+            "",
+            self.starting_partition_key().unwrap_or(""),
+            self.ending_partition_key().unwrap_or(""),
+            self.starting_row_key().unwrap_or(""),
+            self.ending_row_key().unwrap_or(""),
+        )
+    }
+
+    pub fn permission_string(&self) -> String {
+        let mut s = String::with_capacity(4);
+        if self.can_query() {
+            s.push('r')
+        }
+        if self.can_add() {
+            s.push('a')
+        }
+        if self.can_update() {
+            s.push('u')
+        }
+        if self.can_delete() {
+            s.push('d')
+        }
+
+        s
+    }
+}
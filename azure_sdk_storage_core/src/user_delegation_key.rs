@@ -0,0 +1,74 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::cast_must;
+use chrono::{DateTime, SecondsFormat, Utc};
+use xml::Element;
+
+/// A key obtained with an Azure Active Directory identity, used in place of the
+/// account key to sign user delegation shared access signatures
+/// ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/get-user-delegation-key)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserDelegationKey {
+    pub signed_oid: String,
+    pub signed_tid: String,
+    pub signed_start: DateTime<Utc>,
+    pub signed_expiry: DateTime<Utc>,
+    pub signed_service: String,
+    pub signed_version: String,
+    /// The base64 encoded key.
+    pub value: String,
+}
+
+impl UserDelegationKey {
+    pub fn from_xml(xml: &str) -> Result<UserDelegationKey, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+        Ok(UserDelegationKey {
+            signed_oid: cast_must::<String>(&elem, &["SignedOid"])?,
+            signed_tid: cast_must::<String>(&elem, &["SignedTid"])?,
+            signed_start: parse_date(&cast_must::<String>(&elem, &["SignedStart"])?)?,
+            signed_expiry: parse_date(&cast_must::<String>(&elem, &["SignedExpiry"])?)?,
+            signed_service: cast_must::<String>(&elem, &["SignedService"])?,
+            signed_version: cast_must::<String>(&elem, &["SignedVersion"])?,
+            value: cast_must::<String>(&elem, &["Value"])?,
+        })
+    }
+}
+
+/// The format of the dates of a user delegation key in the shared access
+/// signatures signed with it.
+pub(crate) fn format_key_date(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn parse_date(date: &str) -> Result<DateTime<Utc>, AzureError> {
+    Ok(DateTime::parse_from_rfc3339(date)?.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    #[test]
+    fn parse_from_xml() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <UserDelegationKey>
+                <SignedOid>e4c1b45a-b2e3-4c4e-a8b1-0e6e2b6d5f1b</SignedOid>
+                <SignedTid>72f988bf-86f1-41af-91ab-2d7cd011db47</SignedTid>
+                <SignedStart>2020-03-01T10:00:00Z</SignedStart>
+                <SignedExpiry>2020-03-02T10:00:00Z</SignedExpiry>
+                <SignedService>b</SignedService>
+                <SignedVersion>2019-02-02</SignedVersion>
+                <Value>MDEyMzQ1Njc4OWFiY2RlZg==</Value>
+            </UserDelegationKey>";
+
+        let key = UserDelegationKey::from_xml(xml).unwrap();
+        assert_eq!(key.signed_oid, "e4c1b45a-b2e3-4c4e-a8b1-0e6e2b6d5f1b");
+        assert_eq!(key.signed_tid, "72f988bf-86f1-41af-91ab-2d7cd011db47");
+        assert_eq!(key.signed_start.day(), 1);
+        assert_eq!(key.signed_expiry.hour(), 10);
+        assert_eq!(key.signed_service, "b");
+        assert_eq!(key.signed_version, "2019-02-02");
+        assert_eq!(key.value, "MDEyMzQ1Njc4OWFiY2RlZg==");
+    }
+}
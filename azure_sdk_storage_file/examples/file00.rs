@@ -0,0 +1,66 @@
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_file::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let share_name = std::env::args()
+        .nth(1)
+        .expect("please specify the share name as command line parameter");
+    let file_path = std::env::args()
+        .nth(2)
+        .expect("please specify the file path as command line parameter");
+
+    let client = Client::new(&account, &master_key)?;
+
+    let response = client.list_shares().finalize().await?;
+    println!(
+        "List shares returned {} shares.",
+        response.incomplete_vector.len()
+    );
+    for share in response.incomplete_vector.iter() {
+        println!("\t{}\t{} GiB", share.name, share.quota);
+    }
+
+    let data = b"hello from Rust!";
+
+    client
+        .create_file()
+        .with_share_name(&share_name)
+        .with_file_path(&file_path)
+        .with_file_size(data.len() as u64)
+        .finalize()
+        .await?;
+
+    let range = Range::new(0, data.len() as u64 - 1);
+    let response = client
+        .put_range()
+        .with_share_name(&share_name)
+        .with_file_path(&file_path)
+        .with_range(&range)
+        .with_body(&data[..])
+        .finalize()
+        .await?;
+    println!("put_range response == {:?}", response);
+
+    let response = client
+        .get_file()
+        .with_share_name(&share_name)
+        .with_file_path(&file_path)
+        .finalize()
+        .await?;
+    println!(
+        "{:?}\n{}",
+        response.properties,
+        String::from_utf8_lossy(&response.data)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+pub mod requests;
+pub mod responses;
+use crate::share::{encode_path, generate_share_uri, ShareNameRequired};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::cast_must;
+use azure_sdk_storage_core::ClientRequired;
+use xml::Element;
+
+pub trait DirectoryPathSupport<'a> {
+    type O;
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O;
+}
+
+pub trait DirectoryPathRequired<'a> {
+    /// Path of the directory relative to the share root, using `/` as separator.
+    fn directory_path(&self) -> &'a str;
+}
+
+pub trait DirectoryPathOption<'a> {
+    /// Path of the directory relative to the share root. `None` means the root.
+    fn directory_path(&self) -> Option<&'a str>;
+}
+
+/// An entry returned by List Directories and Files.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DirectoryEntry {
+    Directory { name: String },
+    File { name: String, content_length: u64 },
+}
+
+impl DirectoryEntry {
+    pub fn name(&self) -> &str {
+        match self {
+            DirectoryEntry::Directory { name } => name,
+            DirectoryEntry::File { name, .. } => name,
+        }
+    }
+
+    pub(crate) fn parse_directory(elem: &Element) -> Result<DirectoryEntry, AzureError> {
+        Ok(DirectoryEntry::Directory {
+            name: cast_must::<String>(elem, &["Name"])?,
+        })
+    }
+
+    pub(crate) fn parse_file(elem: &Element) -> Result<DirectoryEntry, AzureError> {
+        Ok(DirectoryEntry::File {
+            name: cast_must::<String>(elem, &["Name"])?,
+            content_length: cast_must::<u64>(elem, &["Properties", "Content-Length"])?,
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn generate_directory_uri<'a, T>(t: &T, params: Option<&str>) -> String
+where
+    T: ClientRequired<'a> + ShareNameRequired<'a> + DirectoryPathRequired<'a>,
+{
+    let uri = format!(
+        "{}/{}",
+        generate_share_uri(t, None),
+        encode_path(t.directory_path())
+    );
+
+    match params {
+        Some(ref params) => format!("{}?{}", uri, params),
+        None => uri,
+    }
+}
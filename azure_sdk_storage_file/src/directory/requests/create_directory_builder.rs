@@ -0,0 +1,264 @@
+use crate::directory::responses::CreateDirectoryResponse;
+use crate::directory::{generate_directory_uri, DirectoryPathRequired, DirectoryPathSupport};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, MetadataOption, MetadataSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_directory_path: PhantomData<DirectoryPathSet>,
+    share_name: Option<&'a str>,
+    directory_path: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> CreateDirectoryBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> CreateDirectoryBuilder<'a, No, No> {
+        CreateDirectoryBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_directory_path: PhantomData {},
+            directory_path: None,
+            metadata: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ClientRequired<'a>
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, DirectoryPathSet> ShareNameRequired<'a>
+    for CreateDirectoryBuilder<'a, Yes, DirectoryPathSet>
+where
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> DirectoryPathRequired<'a> for CreateDirectoryBuilder<'a, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn directory_path(&self) -> &'a str {
+        self.directory_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> MetadataOption<'a>
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> TimeoutOption
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ClientRequestIdOption<'a>
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ShareNameSupport<'a>
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = CreateDirectoryBuilder<'a, Yes, DirectoryPathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        CreateDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: Some(share_name),
+            directory_path: self.directory_path,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> DirectoryPathSupport<'a>
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = CreateDirectoryBuilder<'a, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O {
+        CreateDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: Some(directory_path),
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> MetadataSupport<'a>
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            metadata: Some(metadata),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> TimeoutSupport
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            metadata: self.metadata,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ClientRequestIdSupport<'a>
+    for CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, DirectoryPathSet> CreateDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+}
+
+impl<'a> CreateDirectoryBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<CreateDirectoryResponse, AzureError> {
+        let mut uri = generate_directory_uri(&self, Some("restype=directory"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("create_directory uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                MetadataOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreateDirectoryResponse::from_headers(&headers)
+    }
+}
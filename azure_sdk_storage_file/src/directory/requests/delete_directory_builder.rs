@@ -0,0 +1,221 @@
+use crate::directory::responses::DeleteDirectoryResponse;
+use crate::directory::{generate_directory_uri, DirectoryPathRequired, DirectoryPathSupport};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_directory_path: PhantomData<DirectoryPathSet>,
+    share_name: Option<&'a str>,
+    directory_path: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> DeleteDirectoryBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> DeleteDirectoryBuilder<'a, No, No> {
+        DeleteDirectoryBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_directory_path: PhantomData {},
+            directory_path: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ClientRequired<'a>
+    for DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, DirectoryPathSet> ShareNameRequired<'a>
+    for DeleteDirectoryBuilder<'a, Yes, DirectoryPathSet>
+where
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> DirectoryPathRequired<'a> for DeleteDirectoryBuilder<'a, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn directory_path(&self) -> &'a str {
+        self.directory_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> TimeoutOption
+    for DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ClientRequestIdOption<'a>
+    for DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ShareNameSupport<'a>
+    for DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = DeleteDirectoryBuilder<'a, Yes, DirectoryPathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        DeleteDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: Some(share_name),
+            directory_path: self.directory_path,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> DirectoryPathSupport<'a>
+    for DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = DeleteDirectoryBuilder<'a, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O {
+        DeleteDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: Some(directory_path),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> TimeoutSupport
+    for DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DeleteDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, DirectoryPathSet> ClientRequestIdSupport<'a>
+    for DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+    type O = DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DeleteDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, DirectoryPathSet> DeleteDirectoryBuilder<'a, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+{
+}
+
+impl<'a> DeleteDirectoryBuilder<'a, Yes, Yes> {
+    /// Only empty directories can be deleted.
+    pub async fn finalize(self) -> Result<DeleteDirectoryResponse, AzureError> {
+        let mut uri = generate_directory_uri(&self, Some("restype=directory"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("delete_directory uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        DeleteDirectoryResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,444 @@
+use crate::directory::responses::ListDirectoryResponse;
+use crate::directory::{DirectoryEntry, DirectoryPathOption, DirectoryPathSupport};
+use crate::share::{
+    encode_path, generate_share_uri, ShareNameRequired, ShareNameSupport, ShareSnapshotOption,
+    ShareSnapshotSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::parsing::{cast_optional, traverse};
+use azure_sdk_core::{
+    request_id_from_headers, ClientRequestIdOption, ClientRequestIdSupport, MaxResultsOption,
+    MaxResultsSupport, NextMarkerOption, NextMarkerSupport, PrefixOption, PrefixSupport,
+    TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+use xml::Element;
+
+#[derive(Debug, Clone)]
+pub struct ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    share_name: Option<&'a str>,
+    directory_path: Option<&'a str>,
+    prefix: Option<&'a str>,
+    next_marker: Option<&'a str>,
+    max_results: Option<u32>,
+    share_snapshot: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> ListDirectoryBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> ListDirectoryBuilder<'a, No> {
+        ListDirectoryBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            directory_path: None,
+            prefix: None,
+            next_marker: None,
+            max_results: None,
+            share_snapshot: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequired<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ShareNameRequired<'a> for ListDirectoryBuilder<'a, Yes> {
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> DirectoryPathOption<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn directory_path(&self) -> Option<&'a str> {
+        self.directory_path
+    }
+}
+
+impl<'a, ShareNameSet> PrefixOption<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn prefix(&self) -> Option<&'a str> {
+        self.prefix
+    }
+}
+
+impl<'a, ShareNameSet> NextMarkerOption<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn next_marker(&self) -> Option<&'a str> {
+        self.next_marker
+    }
+}
+
+impl<'a, ShareNameSet> MaxResultsOption for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn max_results(&self) -> Option<u32> {
+        self.max_results
+    }
+}
+
+impl<'a, ShareNameSet> ShareSnapshotOption<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn share_snapshot(&self) -> Option<&'a str> {
+        self.share_snapshot
+    }
+}
+
+impl<'a, ShareNameSet> TimeoutOption for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequestIdOption<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet> ShareNameSupport<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: Some(share_name),
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> DirectoryPathSupport<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: Some(directory_path),
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> PrefixSupport<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_prefix(self, prefix: &'a str) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: Some(prefix),
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> NextMarkerSupport<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_next_marker(self, next_marker: &'a str) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: Some(next_marker),
+            max_results: self.max_results,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> MaxResultsSupport for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_max_results(self, max_results: u32) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: Some(max_results),
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ShareSnapshotSupport<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_share_snapshot(self, share_snapshot: &'a str) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            share_snapshot: Some(share_snapshot),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> TimeoutSupport for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            share_snapshot: self.share_snapshot,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequestIdSupport<'a> for ListDirectoryBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = ListDirectoryBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ListDirectoryBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet> ListDirectoryBuilder<'a, ShareNameSet> where ShareNameSet: ToAssign {}
+
+impl<'a> ListDirectoryBuilder<'a, Yes> {
+    pub async fn finalize(self) -> Result<ListDirectoryResponse, AzureError> {
+        let mut uri = match self.directory_path() {
+            Some(directory_path) => format!(
+                "{}/{}?restype=directory&comp=list",
+                generate_share_uri(&self, None),
+                encode_path(directory_path)
+            ),
+            None => generate_share_uri(&self, Some("restype=directory&comp=list")),
+        };
+
+        if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = MaxResultsOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = ShareSnapshotOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("list_directory uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
+                .await?;
+        let incomplete_vector = incomplete_vector_from_response(&body)?;
+        let request_id = request_id_from_headers(&headers)?;
+        Ok(ListDirectoryResponse {
+            incomplete_vector,
+            request_id,
+        })
+    }
+}
+
+pub(crate) fn incomplete_vector_from_response(
+    body: &str,
+) -> Result<IncompleteVector<DirectoryEntry>, AzureError> {
+    let elem: Element = body.parse()?;
+
+    let mut v = Vec::new();
+
+    for directory in traverse(&elem, &["Entries", "Directory"], true)? {
+        v.push(DirectoryEntry::parse_directory(directory)?);
+    }
+    for file in traverse(&elem, &["Entries", "File"], true)? {
+        v.push(DirectoryEntry::parse_file(file)?);
+    }
+
+    let next_marker = match cast_optional::<String>(&elem, &["NextMarker"])? {
+        Some(ref nm) if nm.is_empty() => None,
+        Some(nm) => Some(nm),
+        None => None,
+    };
+
+    Ok(IncompleteVector::new(next_marker, v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_list_directory() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<EnumerationResults ServiceEndpoint=\"https://myaccount.file.core.windows.net/\" ShareName=\"myshare\" DirectoryPath=\"mydir\">
+  <Entries>
+    <File>
+      <Name>file.txt</Name>
+      <Properties>
+        <Content-Length>1024</Content-Length>
+      </Properties>
+    </File>
+    <Directory>
+      <Name>subdir</Name>
+      <Properties />
+    </Directory>
+  </Entries>
+  <NextMarker />
+</EnumerationResults>";
+
+        let v = incomplete_vector_from_response(body).unwrap();
+        assert!(v.is_complete());
+        assert_eq!(v.len(), 2);
+        assert_eq!(
+            v[0],
+            DirectoryEntry::Directory {
+                name: "subdir".to_owned()
+            }
+        );
+        assert_eq!(
+            v[1],
+            DirectoryEntry::File {
+                name: "file.txt".to_owned(),
+                content_length: 1024
+            }
+        );
+    }
+}
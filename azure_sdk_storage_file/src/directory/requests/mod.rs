@@ -0,0 +1,6 @@
+mod create_directory_builder;
+pub use self::create_directory_builder::CreateDirectoryBuilder;
+mod delete_directory_builder;
+pub use self::delete_directory_builder::DeleteDirectoryBuilder;
+mod list_directory_builder;
+pub use self::list_directory_builder::ListDirectoryBuilder;
@@ -0,0 +1,15 @@
+use crate::directory::DirectoryEntry;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::RequestId;
+
+#[derive(Debug, Clone)]
+pub struct ListDirectoryResponse {
+    pub incomplete_vector: IncompleteVector<DirectoryEntry>,
+    pub request_id: RequestId,
+}
+
+impl ListDirectoryResponse {
+    pub fn is_complete(&self) -> bool {
+        self.incomplete_vector.is_complete()
+    }
+}
@@ -0,0 +1,6 @@
+mod create_directory_response;
+pub use self::create_directory_response::CreateDirectoryResponse;
+mod delete_directory_response;
+pub use self::delete_directory_response::DeleteDirectoryResponse;
+mod list_directory_response;
+pub use self::list_directory_response::ListDirectoryResponse;
@@ -0,0 +1,222 @@
+pub mod requests;
+pub mod responses;
+use crate::share::{encode_path, generate_share_uri, ShareNameRequired};
+use azure_sdk_core::headers::{
+    CONTENT_MD5, COPY_ID, COPY_STATUS, FILE_CACHE_CONTROL, FILE_CONTENT_DISPOSITION,
+    FILE_CONTENT_ENCODING, FILE_CONTENT_LANGUAGE, FILE_CONTENT_LENGTH, FILE_CONTENT_TYPE,
+    META_PREFIX,
+};
+use azure_sdk_core::{
+    enumerations,
+    errors::{AzureError, TraversingError},
+    parsing::{from_azure_time, FromStringOptional},
+    util::HeaderMapExt,
+    CacheControlOption, ContentDispositionOption, ContentEncodingOption, ContentLanguageOption,
+    ContentTypeOption,
+};
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Utc};
+use http::request::Builder;
+use hyper::header;
+use std::collections::HashMap;
+use std::{fmt, str::FromStr};
+
+create_enum!(
+    CopyStatus,
+    (Pending, "pending"),
+    (Success, "success"),
+    (Aborted, "aborted"),
+    (Failed, "failed")
+);
+
+pub trait FilePathSupport<'a> {
+    type O;
+    fn with_file_path(self, file_path: &'a str) -> Self::O;
+}
+
+pub trait FilePathRequired<'a> {
+    /// Path of the file relative to the share root, using `/` as separator.
+    fn file_path(&self) -> &'a str;
+}
+
+pub trait FileSizeSupport {
+    type O;
+    fn with_file_size(self, file_size: u64) -> Self::O;
+}
+
+pub trait FileSizeRequired {
+    fn file_size(&self) -> u64;
+
+    fn add_header(&self, builder: &mut Builder) {
+        builder.header(FILE_CONTENT_LENGTH, &self.file_size().to_string() as &str);
+    }
+}
+
+pub trait FileSizeOption {
+    fn file_size(&self) -> Option<u64>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(file_size) = self.file_size() {
+            builder.header(FILE_CONTENT_LENGTH, &file_size.to_string() as &str);
+        }
+    }
+}
+
+// The Files service stores the HTTP properties under x-ms- prefixed
+// headers so the blob flavoured add_header of the core traits cannot be used.
+pub(crate) fn add_content_headers<'a, T>(t: &T, builder: &mut Builder)
+where
+    T: ContentTypeOption<'a>
+        + ContentEncodingOption<'a>
+        + ContentLanguageOption<'a>
+        + CacheControlOption<'a>
+        + ContentDispositionOption<'a>,
+{
+    if let Some(content_type) = t.content_type() {
+        builder.header(FILE_CONTENT_TYPE, content_type);
+    }
+    if let Some(content_encoding) = t.content_encoding() {
+        builder.header(FILE_CONTENT_ENCODING, content_encoding);
+    }
+    if let Some(content_language) = t.content_language() {
+        builder.header(FILE_CONTENT_LANGUAGE, content_language);
+    }
+    if let Some(cache_control) = t.cache_control() {
+        builder.header(FILE_CACHE_CONTROL, cache_control);
+    }
+    if let Some(content_disposition) = t.content_disposition() {
+        builder.header(FILE_CONTENT_DISPOSITION, content_disposition);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileProperties {
+    pub share_name: String,
+    pub path: String,
+    pub last_modified: DateTime<Utc>,
+    pub etag: String,
+    /// Size of the whole file, even when only a range has been retrieved.
+    pub content_length: u64,
+    pub content_type: Option<String>,
+    pub content_encoding: Option<String>,
+    pub content_language: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+    pub content_md5: Option<String>,
+    pub copy_id: Option<String>,
+    pub copy_status: Option<CopyStatus>,
+    /// The metadata keys, without the `x-ms-meta-` prefix.
+    pub metadata: HashMap<String, String>,
+}
+
+impl FileProperties {
+    pub(crate) fn from_headers(
+        share_name: &str,
+        path: &str,
+        h: &header::HeaderMap,
+    ) -> Result<FileProperties, AzureError> {
+        let last_modified = h.get_as_str(header::LAST_MODIFIED).ok_or_else(|| {
+            static LM: header::HeaderName = header::LAST_MODIFIED;
+            AzureError::HeaderNotFound(LM.as_str().to_owned())
+        })?;
+        let last_modified = from_azure_time(last_modified)?;
+
+        let etag = h.get_as_string(header::ETAG).ok_or_else(|| {
+            static E: header::HeaderName = header::ETAG;
+            AzureError::HeaderNotFound(E.as_str().to_owned())
+        })?;
+
+        // a ranged get reports the file size after the slash of
+        // Content-Range (ie bytes 0-511/1024)
+        let content_length = match h
+            .get_as_str(header::CONTENT_RANGE)
+            .and_then(|cr| cr.rsplit('/').next())
+        {
+            Some(total) => total.parse::<u64>()?,
+            None => h
+                .get(header::CONTENT_LENGTH)
+                .ok_or_else(|| {
+                    static CL: header::HeaderName = header::CONTENT_LENGTH;
+                    AzureError::HeaderNotFound(CL.as_str().to_owned())
+                })?
+                .to_str()?
+                .parse::<u64>()?,
+        };
+
+        let mut metadata = HashMap::new();
+        for (key, value) in h {
+            if key.as_str().starts_with(META_PREFIX) {
+                metadata.insert(
+                    key.as_str()[META_PREFIX.len()..].to_owned(),
+                    value.to_str()?.to_owned(),
+                );
+            }
+        }
+
+        Ok(FileProperties {
+            share_name: share_name.to_owned(),
+            path: path.to_owned(),
+            last_modified,
+            etag,
+            content_length,
+            content_type: h.get_as_string(header::CONTENT_TYPE),
+            content_encoding: h.get_as_string(header::CONTENT_ENCODING),
+            content_language: h.get_as_string(header::CONTENT_LANGUAGE),
+            cache_control: h.get_as_string(header::CACHE_CONTROL),
+            content_disposition: h.get_as_string(header::CONTENT_DISPOSITION),
+            content_md5: h.get_as_string(CONTENT_MD5),
+            copy_id: h.get_as_string(COPY_ID),
+            copy_status: h.get_as_enum(COPY_STATUS)?,
+            metadata,
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn generate_file_uri<'a, T>(t: &T, params: Option<&str>) -> String
+where
+    T: ClientRequired<'a> + ShareNameRequired<'a> + FilePathRequired<'a>,
+{
+    let uri = format!(
+        "{}/{}",
+        generate_share_uri(t, None),
+        encode_path(t.file_path())
+    );
+
+    match params {
+        Some(ref params) => format!("{}?{}", uri, params),
+        None => uri,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hyper::header::{HeaderMap, HeaderValue};
+
+    #[test]
+    fn file_properties_from_ranged_get() {
+        let mut h = HeaderMap::new();
+        h.insert(
+            header::LAST_MODIFIED,
+            HeaderValue::from_static("Wed, 10 May 2017 17:52:33 GMT"),
+        );
+        h.insert(
+            header::ETAG,
+            HeaderValue::from_static("\"0x8D497D0A7DA3C8B\""),
+        );
+        h.insert(header::CONTENT_LENGTH, HeaderValue::from_static("512"));
+        h.insert(
+            header::CONTENT_RANGE,
+            HeaderValue::from_static("bytes 0-511/1024"),
+        );
+        h.insert(COPY_STATUS, HeaderValue::from_static("success"));
+        h.insert("x-ms-meta-owner", HeaderValue::from_static("e2e"));
+
+        let properties = FileProperties::from_headers("share", "dir/file.txt", &h).unwrap();
+        assert_eq!(properties.content_length, 1024);
+        assert_eq!(properties.copy_status, Some(CopyStatus::Success));
+        assert_eq!(properties.metadata["owner"], "e2e");
+        assert_eq!(properties.content_type, None);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::file::responses::ClearRangeResponse;
+use crate::file::{generate_file_uri, FilePathRequired, FilePathSupport};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::FILE_WRITE;
+use azure_sdk_core::range::Range;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, RangeRequired, RangeSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    p_range: PhantomData<RangeSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    range: Option<&'a Range>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> ClearRangeBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> ClearRangeBuilder<'a, No, No, No> {
+        ClearRangeBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            p_range: PhantomData {},
+            range: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> ClientRequired<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet, RangeSet> ShareNameRequired<'a>
+    for ClearRangeBuilder<'a, Yes, FilePathSet, RangeSet>
+where
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, RangeSet> FilePathRequired<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, Yes, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> RangeRequired<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn range(&self) -> &'a Range {
+        self.range.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> TimeoutOption
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> ClientRequestIdOption<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> ShareNameSupport<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    type O = ClearRangeBuilder<'a, Yes, FilePathSet, RangeSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            range: self.range,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> FilePathSupport<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    type O = ClearRangeBuilder<'a, ShareNameSet, Yes, RangeSet>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            range: self.range,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> RangeSupport<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    type O = ClearRangeBuilder<'a, ShareNameSet, FilePathSet, Yes>;
+
+    #[inline]
+    fn with_range(self, range: &'a Range) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: Some(range),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> TimeoutSupport
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    type O = ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> ClientRequestIdSupport<'a>
+    for ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    type O = ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet, RangeSet>
+    ClearRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+}
+
+impl<'a> ClearRangeBuilder<'a, Yes, Yes, Yes> {
+    pub async fn finalize(self) -> Result<ClearRangeResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, Some("comp=range"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("clear_range uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                request.header(FILE_WRITE, "clear");
+                RangeRequired::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        ClearRangeResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,334 @@
+use crate::file::responses::CopyFileResponse;
+use crate::file::{generate_file_uri, FilePathRequired, FilePathSupport};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, CopySourceRequired, CopySourceSupport,
+    MetadataOption, MetadataSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    p_copy_source: PhantomData<CopySourceSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    copy_source: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> CopyFileBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> CopyFileBuilder<'a, No, No, No> {
+        CopyFileBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            p_copy_source: PhantomData {},
+            copy_source: None,
+            metadata: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> ClientRequired<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet, CopySourceSet> ShareNameRequired<'a>
+    for CopyFileBuilder<'a, Yes, FilePathSet, CopySourceSet>
+where
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, CopySourceSet> FilePathRequired<'a>
+    for CopyFileBuilder<'a, ShareNameSet, Yes, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> CopySourceRequired<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn copy_source(&self) -> &'a str {
+        self.copy_source.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> MetadataOption<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> TimeoutOption
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> ClientRequestIdOption<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> ShareNameSupport<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyFileBuilder<'a, Yes, FilePathSet, CopySourceSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        CopyFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_copy_source: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> FilePathSupport<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyFileBuilder<'a, ShareNameSet, Yes, CopySourceSet>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        CopyFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_copy_source: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> CopySourceSupport<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyFileBuilder<'a, ShareNameSet, FilePathSet, Yes>;
+
+    #[inline]
+    fn with_copy_source(self, copy_source: &'a str) -> Self::O {
+        CopyFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_copy_source: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            copy_source: Some(copy_source),
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> MetadataSupport<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CopyFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_copy_source: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            copy_source: self.copy_source,
+            metadata: Some(metadata),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> TimeoutSupport
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CopyFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_copy_source: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet> ClientRequestIdSupport<'a>
+    for CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+    type O = CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CopyFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_copy_source: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            copy_source: self.copy_source,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet, CopySourceSet>
+    CopyFileBuilder<'a, ShareNameSet, FilePathSet, CopySourceSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    CopySourceSet: ToAssign,
+{
+}
+
+impl<'a> CopyFileBuilder<'a, Yes, Yes, Yes> {
+    /// The copy runs asynchronously when the answer is `CopyStatus::Pending`:
+    /// poll `get_file_properties` to follow its progress.
+    pub async fn finalize(self) -> Result<CopyFileResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, None);
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        trace!("copy_file uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                CopySourceRequired::add_header(&self, request);
+                MetadataOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        CopyFileResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,602 @@
+use crate::file::responses::CreateFileResponse;
+use crate::file::{
+    add_content_headers, generate_file_uri, FilePathRequired, FilePathSupport, FileSizeRequired,
+    FileSizeSupport,
+};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::FILE_TYPE;
+use azure_sdk_core::{
+    CacheControlOption, CacheControlSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContentDispositionOption, ContentDispositionSupport, ContentEncodingOption,
+    ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport, ContentTypeOption,
+    ContentTypeSupport, MetadataOption, MetadataSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    p_file_size: PhantomData<FileSizeSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    file_size: Option<u64>,
+    content_type: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> CreateFileBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> CreateFileBuilder<'a, No, No, No> {
+        CreateFileBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            p_file_size: PhantomData {},
+            file_size: None,
+            content_type: None,
+            content_encoding: None,
+            content_language: None,
+            cache_control: None,
+            content_disposition: None,
+            metadata: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ClientRequired<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet, FileSizeSet> ShareNameRequired<'a>
+    for CreateFileBuilder<'a, Yes, FilePathSet, FileSizeSet>
+where
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FileSizeSet> FilePathRequired<'a>
+    for CreateFileBuilder<'a, ShareNameSet, Yes, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> FileSizeRequired
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn file_size(&self) -> u64 {
+        self.file_size.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentTypeOption<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentEncodingOption<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentLanguageOption<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> CacheControlOption<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentDispositionOption<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> MetadataOption<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> TimeoutOption
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ClientRequestIdOption<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ShareNameSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, Yes, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> FilePathSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, Yes, FileSizeSet>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> FileSizeSupport
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, Yes>;
+
+    #[inline]
+    fn with_file_size(self, file_size: u64) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: Some(file_size),
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentTypeSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_content_type(self, content_type: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: Some(content_type),
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentEncodingSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_content_encoding(self, content_encoding: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: Some(content_encoding),
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentLanguageSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_content_language(self, content_language: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: Some(content_language),
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> CacheControlSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_cache_control(self, cache_control: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: Some(cache_control),
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ContentDispositionSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_content_disposition(self, content_disposition: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: Some(content_disposition),
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> MetadataSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: Some(metadata),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> TimeoutSupport
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet> ClientRequestIdSupport<'a>
+    for CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+    type O = CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_file_size: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            file_size: self.file_size,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet, FileSizeSet>
+    CreateFileBuilder<'a, ShareNameSet, FilePathSet, FileSizeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    FileSizeSet: ToAssign,
+{
+}
+
+impl<'a> CreateFileBuilder<'a, Yes, Yes, Yes> {
+    /// Creates an empty file of `file_size` bytes: its content is written with
+    /// `put_range`.
+    pub async fn finalize(self) -> Result<CreateFileResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, None);
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        trace!("create_file uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                request.header(FILE_TYPE, "file");
+                FileSizeRequired::add_header(&self, request);
+                add_content_headers(&self, request);
+                MetadataOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreateFileResponse::from_headers(&headers)
+    }
+}
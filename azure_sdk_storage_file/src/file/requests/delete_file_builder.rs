@@ -0,0 +1,219 @@
+use crate::file::responses::DeleteFileResponse;
+use crate::file::{generate_file_uri, FilePathRequired, FilePathSupport};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> DeleteFileBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> DeleteFileBuilder<'a, No, No> {
+        DeleteFileBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequired<'a>
+    for DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet> ShareNameRequired<'a> for DeleteFileBuilder<'a, Yes, FilePathSet>
+where
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> FilePathRequired<'a> for DeleteFileBuilder<'a, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutOption
+    for DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdOption<'a>
+    for DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareNameSupport<'a>
+    for DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = DeleteFileBuilder<'a, Yes, FilePathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        DeleteFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> FilePathSupport<'a>
+    for DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = DeleteFileBuilder<'a, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        DeleteFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutSupport
+    for DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = DeleteFileBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DeleteFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdSupport<'a>
+    for DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = DeleteFileBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DeleteFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet> DeleteFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+}
+
+impl<'a> DeleteFileBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<DeleteFileResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, None);
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        trace!("delete_file uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        DeleteFileResponse::from_headers(&headers)
+    }
+}
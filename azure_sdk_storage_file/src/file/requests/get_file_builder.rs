@@ -0,0 +1,319 @@
+use crate::file::responses::GetFileResponse;
+use crate::file::{generate_file_uri, FilePathRequired, FilePathSupport, FileProperties};
+use crate::share::{
+    ShareNameRequired, ShareNameSupport, ShareSnapshotOption, ShareSnapshotSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::range::Range;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, RangeOption, RangeSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    range: Option<&'a Range>,
+    share_snapshot: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetFileBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetFileBuilder<'a, No, No> {
+        GetFileBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            range: None,
+            share_snapshot: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequired<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet> ShareNameRequired<'a> for GetFileBuilder<'a, Yes, FilePathSet>
+where
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> FilePathRequired<'a> for GetFileBuilder<'a, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> RangeOption<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn range(&self) -> Option<&'a Range> {
+        self.range
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareSnapshotOption<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn share_snapshot(&self) -> Option<&'a str> {
+        self.share_snapshot
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutOption for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdOption<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareNameSupport<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileBuilder<'a, Yes, FilePathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        GetFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            range: self.range,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> FilePathSupport<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileBuilder<'a, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        GetFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            range: self.range,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> RangeSupport<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_range(self, range: &'a Range) -> Self::O {
+        GetFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: Some(range),
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareSnapshotSupport<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_share_snapshot(self, share_snapshot: &'a str) -> Self::O {
+        GetFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            share_snapshot: Some(share_snapshot),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutSupport for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            share_snapshot: self.share_snapshot,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdSupport<'a>
+    for GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet> GetFileBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+}
+
+impl<'a> GetFileBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<GetFileResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, None);
+
+        let mut f_first = true;
+        if let Some(nm) = ShareSnapshotOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+            f_first = false;
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}{}{}", uri, if f_first { "?" } else { "&" }, nm);
+        }
+
+        trace!("get_file uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                RangeOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let expected_status_code = if self.range().is_some() {
+            StatusCode::PARTIAL_CONTENT
+        } else {
+            StatusCode::OK
+        };
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, expected_status_code).await?;
+        let properties =
+            FileProperties::from_headers(self.share_name(), self.file_path(), &headers)?;
+        GetFileResponse::from_response(&headers, properties, &body)
+    }
+}
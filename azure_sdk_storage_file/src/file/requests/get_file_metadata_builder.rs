@@ -0,0 +1,265 @@
+use crate::file::responses::GetFileMetadataResponse;
+use crate::file::{generate_file_uri, FilePathRequired, FilePathSupport};
+use crate::share::{
+    ShareNameRequired, ShareNameSupport, ShareSnapshotOption, ShareSnapshotSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    share_snapshot: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetFileMetadataBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetFileMetadataBuilder<'a, No, No> {
+        GetFileMetadataBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            share_snapshot: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequired<'a>
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet> ShareNameRequired<'a> for GetFileMetadataBuilder<'a, Yes, FilePathSet>
+where
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> FilePathRequired<'a> for GetFileMetadataBuilder<'a, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareSnapshotOption<'a>
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn share_snapshot(&self) -> Option<&'a str> {
+        self.share_snapshot
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutOption
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdOption<'a>
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareNameSupport<'a>
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileMetadataBuilder<'a, Yes, FilePathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        GetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> FilePathSupport<'a>
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileMetadataBuilder<'a, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        GetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareSnapshotSupport<'a>
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_share_snapshot(self, share_snapshot: &'a str) -> Self::O {
+        GetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            share_snapshot: Some(share_snapshot),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutSupport
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            share_snapshot: self.share_snapshot,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdSupport<'a>
+    for GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet> GetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+}
+
+impl<'a> GetFileMetadataBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<GetFileMetadataResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, Some("comp=metadata"));
+
+        if let Some(nm) = ShareSnapshotOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("get_file_metadata uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        GetFileMetadataResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,20 @@
+mod clear_range_builder;
+pub use self::clear_range_builder::ClearRangeBuilder;
+mod copy_file_builder;
+pub use self::copy_file_builder::CopyFileBuilder;
+mod create_file_builder;
+pub use self::create_file_builder::CreateFileBuilder;
+mod delete_file_builder;
+pub use self::delete_file_builder::DeleteFileBuilder;
+mod get_file_builder;
+pub use self::get_file_builder::GetFileBuilder;
+mod get_file_metadata_builder;
+pub use self::get_file_metadata_builder::GetFileMetadataBuilder;
+mod get_file_properties_builder;
+pub use self::get_file_properties_builder::GetFilePropertiesBuilder;
+mod put_range_builder;
+pub use self::put_range_builder::PutRangeBuilder;
+mod set_file_metadata_builder;
+pub use self::set_file_metadata_builder::SetFileMetadataBuilder;
+mod set_file_properties_builder;
+pub use self::set_file_properties_builder::SetFilePropertiesBuilder;
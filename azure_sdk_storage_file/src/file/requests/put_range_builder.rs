@@ -0,0 +1,408 @@
+use crate::file::responses::PutRangeResponse;
+use crate::file::{generate_file_uri, FilePathRequired, FilePathSupport};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::FILE_WRITE;
+use azure_sdk_core::range::Range;
+use azure_sdk_core::{
+    BodyRequired, BodySupport, ClientRequestIdOption, ClientRequestIdSupport, ContentMD5Option,
+    ContentMD5Support, RangeRequired, RangeSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    p_range: PhantomData<RangeSet>,
+    p_body: PhantomData<BodySet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    range: Option<&'a Range>,
+    body: Option<&'a [u8]>,
+    content_md5: Option<&'a [u8]>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> PutRangeBuilder<'a, No, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> PutRangeBuilder<'a, No, No, No, No> {
+        PutRangeBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            p_range: PhantomData {},
+            range: None,
+            p_body: PhantomData {},
+            body: None,
+            content_md5: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> ClientRequired<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet, RangeSet, BodySet> ShareNameRequired<'a>
+    for PutRangeBuilder<'a, Yes, FilePathSet, RangeSet, BodySet>
+where
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, RangeSet, BodySet> FilePathRequired<'a>
+    for PutRangeBuilder<'a, ShareNameSet, Yes, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, BodySet> RangeRequired<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, Yes, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn range(&self) -> &'a Range {
+        self.range.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet> BodyRequired<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+{
+    #[inline]
+    fn body(&self) -> &'a [u8] {
+        self.body.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> ContentMD5Option<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn content_md5(&self) -> Option<&'a [u8]> {
+        self.content_md5
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> TimeoutOption
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> ClientRequestIdOption<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> ShareNameSupport<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutRangeBuilder<'a, Yes, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> FilePathSupport<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutRangeBuilder<'a, ShareNameSet, Yes, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> RangeSupport<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutRangeBuilder<'a, ShareNameSet, FilePathSet, Yes, BodySet>;
+
+    #[inline]
+    fn with_range(self, range: &'a Range) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: Some(range),
+            body: self.body,
+            content_md5: self.content_md5,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> BodySupport<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, Yes>;
+
+    #[inline]
+    fn with_body(self, body: &'a [u8]) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: Some(body),
+            content_md5: self.content_md5,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> ContentMD5Support<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_content_md5(self, content_md5: &'a [u8]) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: Some(content_md5),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> TimeoutSupport
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet> ClientRequestIdSupport<'a>
+    for PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+    PutRangeBuilder<'a, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+{
+}
+
+impl<'a> PutRangeBuilder<'a, Yes, Yes, Yes, Yes> {
+    /// The body length must match the range length, up to 4 MiB.
+    pub async fn finalize(self) -> Result<PutRangeResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, Some("comp=range"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("put_range uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                request.header(FILE_WRITE, "update");
+                RangeRequired::add_header(&self, request);
+                ContentMD5Option::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(self.body()),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        PutRangeResponse::from_headers(&headers)
+    }
+}
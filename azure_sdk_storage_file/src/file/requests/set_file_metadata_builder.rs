@@ -0,0 +1,263 @@
+use crate::file::responses::SetFileMetadataResponse;
+use crate::file::{generate_file_uri, FilePathRequired, FilePathSupport};
+use crate::share::{ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, MetadataOption, MetadataSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetFileMetadataBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetFileMetadataBuilder<'a, No, No> {
+        SetFileMetadataBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            metadata: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequired<'a>
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, FilePathSet> ShareNameRequired<'a> for SetFileMetadataBuilder<'a, Yes, FilePathSet>
+where
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> FilePathRequired<'a> for SetFileMetadataBuilder<'a, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> MetadataOption<'a>
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutOption
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdOption<'a>
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ShareNameSupport<'a>
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = SetFileMetadataBuilder<'a, Yes, FilePathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        SetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> FilePathSupport<'a>
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = SetFileMetadataBuilder<'a, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        SetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> MetadataSupport<'a>
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        SetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            metadata: Some(metadata),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> TimeoutSupport
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            metadata: self.metadata,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, FilePathSet> ClientRequestIdSupport<'a>
+    for SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+    type O = SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetFileMetadataBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, FilePathSet> SetFileMetadataBuilder<'a, ShareNameSet, FilePathSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+{
+}
+
+impl<'a> SetFileMetadataBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<SetFileMetadataResponse, AzureError> {
+        let mut uri = generate_file_uri(&self, Some("comp=metadata"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("set_file_metadata uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                MetadataOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        SetFileMetadataResponse::from_headers(&headers)
+    }
+}
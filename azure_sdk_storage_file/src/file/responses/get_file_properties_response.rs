@@ -0,0 +1,25 @@
+use crate::file::FileProperties;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct GetFilePropertiesResponse {
+    pub properties: FileProperties,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl GetFilePropertiesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        properties: FileProperties,
+    ) -> Result<GetFilePropertiesResponse, AzureError> {
+        Ok(GetFilePropertiesResponse {
+            properties,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use crate::file::FileProperties;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct GetFileResponse {
+    pub properties: FileProperties,
+    pub request_id: RequestId,
+    pub data: Vec<u8>,
+    pub date: DateTime<Utc>,
+}
+
+impl GetFileResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        properties: FileProperties,
+        body: &[u8],
+    ) -> Result<GetFileResponse, AzureError> {
+        Ok(GetFileResponse {
+            properties,
+            request_id: request_id_from_headers(headers)?,
+            data: body.to_vec(),
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,20 @@
+mod clear_range_response;
+pub use self::clear_range_response::ClearRangeResponse;
+mod copy_file_response;
+pub use self::copy_file_response::CopyFileResponse;
+mod create_file_response;
+pub use self::create_file_response::CreateFileResponse;
+mod delete_file_response;
+pub use self::delete_file_response::DeleteFileResponse;
+mod get_file_response;
+pub use self::get_file_response::GetFileResponse;
+mod get_file_metadata_response;
+pub use self::get_file_metadata_response::GetFileMetadataResponse;
+mod get_file_properties_response;
+pub use self::get_file_properties_response::GetFilePropertiesResponse;
+mod put_range_response;
+pub use self::put_range_response::PutRangeResponse;
+mod set_file_metadata_response;
+pub use self::set_file_metadata_response::SetFileMetadataResponse;
+mod set_file_properties_response;
+pub use self::set_file_properties_response::SetFilePropertiesResponse;
@@ -0,0 +1,123 @@
+#![recursion_limit = "128"]
+#![allow(clippy::needless_lifetimes)]
+
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate azure_sdk_core;
+pub mod directory;
+pub mod file;
+pub mod prelude;
+pub mod share;
+use azure_sdk_core::No;
+use azure_sdk_storage_core::client::Client;
+
+pub trait Share {
+    fn list_shares<'a>(&'a self) -> share::requests::ListSharesBuilder<'a>;
+    fn create_share<'a>(&'a self) -> share::requests::CreateShareBuilder<'a, No>;
+    fn delete_share<'a>(&'a self) -> share::requests::DeleteShareBuilder<'a, No>;
+    fn get_share_properties<'a>(&'a self) -> share::requests::GetSharePropertiesBuilder<'a, No>;
+    fn set_share_quota<'a>(&'a self) -> share::requests::SetShareQuotaBuilder<'a, No, No>;
+    fn create_share_snapshot<'a>(&'a self) -> share::requests::CreateShareSnapshotBuilder<'a, No>;
+}
+
+pub trait Directory {
+    fn create_directory<'a>(&'a self) -> directory::requests::CreateDirectoryBuilder<'a, No, No>;
+    fn delete_directory<'a>(&'a self) -> directory::requests::DeleteDirectoryBuilder<'a, No, No>;
+    fn list_directory<'a>(&'a self) -> directory::requests::ListDirectoryBuilder<'a, No>;
+}
+
+pub trait File {
+    fn create_file<'a>(&'a self) -> file::requests::CreateFileBuilder<'a, No, No, No>;
+    fn delete_file<'a>(&'a self) -> file::requests::DeleteFileBuilder<'a, No, No>;
+    fn put_range<'a>(&'a self) -> file::requests::PutRangeBuilder<'a, No, No, No, No>;
+    fn clear_range<'a>(&'a self) -> file::requests::ClearRangeBuilder<'a, No, No, No>;
+    fn get_file<'a>(&'a self) -> file::requests::GetFileBuilder<'a, No, No>;
+    fn get_file_properties<'a>(&'a self) -> file::requests::GetFilePropertiesBuilder<'a, No, No>;
+    fn set_file_properties<'a>(&'a self) -> file::requests::SetFilePropertiesBuilder<'a, No, No>;
+    fn get_file_metadata<'a>(&'a self) -> file::requests::GetFileMetadataBuilder<'a, No, No>;
+    fn set_file_metadata<'a>(&'a self) -> file::requests::SetFileMetadataBuilder<'a, No, No>;
+    fn copy_file<'a>(&'a self) -> file::requests::CopyFileBuilder<'a, No, No, No>;
+}
+
+impl Share for Client {
+    fn list_shares<'a>(&'a self) -> share::requests::ListSharesBuilder<'a> {
+        share::requests::ListSharesBuilder::new(self)
+    }
+
+    fn create_share<'a>(&'a self) -> share::requests::CreateShareBuilder<'a, No> {
+        share::requests::CreateShareBuilder::new(self)
+    }
+
+    fn delete_share<'a>(&'a self) -> share::requests::DeleteShareBuilder<'a, No> {
+        share::requests::DeleteShareBuilder::new(self)
+    }
+
+    fn get_share_properties<'a>(&'a self) -> share::requests::GetSharePropertiesBuilder<'a, No> {
+        share::requests::GetSharePropertiesBuilder::new(self)
+    }
+
+    fn set_share_quota<'a>(&'a self) -> share::requests::SetShareQuotaBuilder<'a, No, No> {
+        share::requests::SetShareQuotaBuilder::new(self)
+    }
+
+    fn create_share_snapshot<'a>(&'a self) -> share::requests::CreateShareSnapshotBuilder<'a, No> {
+        share::requests::CreateShareSnapshotBuilder::new(self)
+    }
+}
+
+impl Directory for Client {
+    fn create_directory<'a>(&'a self) -> directory::requests::CreateDirectoryBuilder<'a, No, No> {
+        directory::requests::CreateDirectoryBuilder::new(self)
+    }
+
+    fn delete_directory<'a>(&'a self) -> directory::requests::DeleteDirectoryBuilder<'a, No, No> {
+        directory::requests::DeleteDirectoryBuilder::new(self)
+    }
+
+    fn list_directory<'a>(&'a self) -> directory::requests::ListDirectoryBuilder<'a, No> {
+        directory::requests::ListDirectoryBuilder::new(self)
+    }
+}
+
+impl File for Client {
+    fn create_file<'a>(&'a self) -> file::requests::CreateFileBuilder<'a, No, No, No> {
+        file::requests::CreateFileBuilder::new(self)
+    }
+
+    fn delete_file<'a>(&'a self) -> file::requests::DeleteFileBuilder<'a, No, No> {
+        file::requests::DeleteFileBuilder::new(self)
+    }
+
+    fn put_range<'a>(&'a self) -> file::requests::PutRangeBuilder<'a, No, No, No, No> {
+        file::requests::PutRangeBuilder::new(self)
+    }
+
+    fn clear_range<'a>(&'a self) -> file::requests::ClearRangeBuilder<'a, No, No, No> {
+        file::requests::ClearRangeBuilder::new(self)
+    }
+
+    fn get_file<'a>(&'a self) -> file::requests::GetFileBuilder<'a, No, No> {
+        file::requests::GetFileBuilder::new(self)
+    }
+
+    fn get_file_properties<'a>(&'a self) -> file::requests::GetFilePropertiesBuilder<'a, No, No> {
+        file::requests::GetFilePropertiesBuilder::new(self)
+    }
+
+    fn set_file_properties<'a>(&'a self) -> file::requests::SetFilePropertiesBuilder<'a, No, No> {
+        file::requests::SetFilePropertiesBuilder::new(self)
+    }
+
+    fn get_file_metadata<'a>(&'a self) -> file::requests::GetFileMetadataBuilder<'a, No, No> {
+        file::requests::GetFileMetadataBuilder::new(self)
+    }
+
+    fn set_file_metadata<'a>(&'a self) -> file::requests::SetFileMetadataBuilder<'a, No, No> {
+        file::requests::SetFileMetadataBuilder::new(self)
+    }
+
+    fn copy_file<'a>(&'a self) -> file::requests::CopyFileBuilder<'a, No, No, No> {
+        file::requests::CopyFileBuilder::new(self)
+    }
+}
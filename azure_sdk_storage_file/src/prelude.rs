@@ -0,0 +1,6 @@
+pub use crate::directory::DirectoryPathSupport;
+pub use crate::file::{FilePathSupport, FileSizeSupport};
+pub use crate::share::{ShareNameSupport, ShareQuotaSupport, ShareSnapshotSupport};
+pub use crate::Directory as DirectoryTrait;
+pub use crate::File as FileTrait;
+pub use crate::Share as ShareTrait;
@@ -0,0 +1,176 @@
+pub mod requests;
+pub mod responses;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::SHARE_QUOTA;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse};
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Utc};
+use http::request::Builder;
+use std::collections::HashMap;
+use url::form_urlencoded;
+use xml::{Element, Xml};
+
+pub trait ShareNameSupport<'a> {
+    type O;
+    fn with_share_name(self, share_name: &'a str) -> Self::O;
+}
+
+pub trait ShareNameRequired<'a> {
+    fn share_name(&self) -> &'a str;
+}
+
+pub trait ShareQuotaSupport {
+    type O;
+    fn with_share_quota(self, share_quota: u64) -> Self::O;
+}
+
+pub trait ShareQuotaOption {
+    /// Maximum size of the share, in gibibytes.
+    fn share_quota(&self) -> Option<u64>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(share_quota) = self.share_quota() {
+            builder.header(SHARE_QUOTA, &share_quota.to_string() as &str);
+        }
+    }
+}
+
+pub trait ShareQuotaRequired {
+    /// Maximum size of the share, in gibibytes.
+    fn share_quota(&self) -> u64;
+
+    fn add_header(&self, builder: &mut Builder) {
+        builder.header(SHARE_QUOTA, &self.share_quota().to_string() as &str);
+    }
+}
+
+pub trait ShareSnapshotSupport<'a> {
+    type O;
+    fn with_share_snapshot(self, share_snapshot: &'a str) -> Self::O;
+}
+
+pub trait ShareSnapshotOption<'a> {
+    /// The opaque snapshot identifier returned by Create Share Snapshot.
+    fn share_snapshot(&self) -> Option<&'a str>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.share_snapshot().map(|share_snapshot| {
+            format!(
+                "sharesnapshot={}",
+                form_urlencoded::byte_serialize(share_snapshot.as_bytes()).collect::<String>()
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Share {
+    pub name: String,
+    /// Set only for the share snapshots returned when listing with `include_snapshots`.
+    pub snapshot: Option<String>,
+    pub last_modified: DateTime<Utc>,
+    pub etag: String,
+    /// Maximum size of the share, in gibibytes.
+    pub quota: u64,
+    pub metadata: HashMap<String, String>,
+}
+
+impl Share {
+    pub(crate) fn parse(elem: &Element) -> Result<Share, AzureError> {
+        let name = cast_must::<String>(elem, &["Name"])?;
+        let snapshot = cast_optional::<String>(elem, &["Snapshot"])?;
+        let last_modified = cast_must::<DateTime<Utc>>(elem, &["Properties", "Last-Modified"])?;
+        let etag = cast_must::<String>(elem, &["Properties", "Etag"])?;
+        let quota = cast_must::<u64>(elem, &["Properties", "Quota"])?;
+
+        let mut metadata = HashMap::new();
+        for m in traverse(elem, &["Metadata"], true)? {
+            for key in &m.children {
+                if let Xml::ElementNode(elem) = key {
+                    metadata.insert(elem.name.to_owned(), elem.content_str());
+                }
+            }
+        }
+
+        Ok(Share {
+            name,
+            snapshot,
+            last_modified,
+            etag,
+            quota,
+            metadata,
+        })
+    }
+}
+
+/// Percent-encodes each segment of a `/` separated path, leaving the
+/// separators untouched. Spaces become `%20` since a `+` in a path is
+/// taken literally by the service.
+pub(crate) fn encode_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            form_urlencoded::byte_serialize(segment.as_bytes())
+                .collect::<String>()
+                .replace('+', "%20")
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[inline]
+pub(crate) fn generate_share_uri<'a, T>(t: &T, params: Option<&str>) -> String
+where
+    T: ClientRequired<'a> + ShareNameRequired<'a>,
+{
+    match params {
+        Some(ref params) => format!(
+            "{}/{}?{}",
+            t.client().file_uri(),
+            form_urlencoded::byte_serialize(t.share_name().as_bytes()).collect::<String>(),
+            params
+        ),
+        None => format!(
+            "{}/{}",
+            t.client().file_uri(),
+            form_urlencoded::byte_serialize(t.share_name().as_bytes()).collect::<String>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_share_snapshot() {
+        let body = "<Share>
+  <Name>myshare</Name>
+  <Snapshot>2017-05-10T17:52:33.9551861Z</Snapshot>
+  <Properties>
+    <Last-Modified>Wed, 10 May 2017 17:52:33 GMT</Last-Modified>
+    <Etag>\"0x8D497D0A7DA3C8B\"</Etag>
+    <Quota>5120</Quota>
+  </Properties>
+  <Metadata>
+    <owner>e2e</owner>
+  </Metadata>
+</Share>";
+
+        let elem: Element = body.parse().unwrap();
+        let share = Share::parse(&elem).unwrap();
+        assert_eq!(share.name, "myshare");
+        assert_eq!(
+            share.snapshot,
+            Some("2017-05-10T17:52:33.9551861Z".to_owned())
+        );
+        assert_eq!(share.quota, 5120);
+        assert_eq!(share.etag, "\"0x8D497D0A7DA3C8B\"");
+        assert_eq!(share.metadata["owner"], "e2e");
+    }
+
+    #[test]
+    fn encode_path_keeps_separators() {
+        assert_eq!(encode_path("dir one/file+1.txt"), "dir%20one/file%2B1.txt");
+        assert_eq!(encode_path("a/b/c"), "a/b/c");
+    }
+}
@@ -0,0 +1,192 @@
+use crate::share::responses::CreateShareSnapshotResponse;
+use crate::share::{generate_share_uri, ShareNameRequired, ShareNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, MetadataOption, MetadataSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    share_name: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> CreateShareSnapshotBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> CreateShareSnapshotBuilder<'a, No> {
+        CreateShareSnapshotBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            metadata: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequired<'a> for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ShareNameRequired<'a> for CreateShareSnapshotBuilder<'a, Yes> {
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> MetadataOption<'a> for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, ShareNameSet> TimeoutOption for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequestIdOption<'a> for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet> ShareNameSupport<'a> for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = CreateShareSnapshotBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        CreateShareSnapshotBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: Some(share_name),
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> MetadataSupport<'a> for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = CreateShareSnapshotBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateShareSnapshotBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            metadata: Some(metadata),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> TimeoutSupport for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = CreateShareSnapshotBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateShareSnapshotBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            metadata: self.metadata,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequestIdSupport<'a> for CreateShareSnapshotBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = CreateShareSnapshotBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateShareSnapshotBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet> CreateShareSnapshotBuilder<'a, ShareNameSet> where ShareNameSet: ToAssign {}
+
+impl<'a> CreateShareSnapshotBuilder<'a, Yes> {
+    pub async fn finalize(self) -> Result<CreateShareSnapshotResponse, AzureError> {
+        let mut uri = generate_share_uri(&self, Some("restype=share&comp=snapshot"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("create_share_snapshot uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                MetadataOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreateShareSnapshotResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,195 @@
+use crate::share::responses::GetSharePropertiesResponse;
+use crate::share::{
+    generate_share_uri, ShareNameRequired, ShareNameSupport, ShareSnapshotOption,
+    ShareSnapshotSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    share_name: Option<&'a str>,
+    share_snapshot: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetSharePropertiesBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetSharePropertiesBuilder<'a, No> {
+        GetSharePropertiesBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            share_snapshot: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequired<'a> for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ShareNameRequired<'a> for GetSharePropertiesBuilder<'a, Yes> {
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> ShareSnapshotOption<'a> for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn share_snapshot(&self) -> Option<&'a str> {
+        self.share_snapshot
+    }
+}
+
+impl<'a, ShareNameSet> TimeoutOption for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequestIdOption<'a> for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet> ShareNameSupport<'a> for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = GetSharePropertiesBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        GetSharePropertiesBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: Some(share_name),
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ShareSnapshotSupport<'a> for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = GetSharePropertiesBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_share_snapshot(self, share_snapshot: &'a str) -> Self::O {
+        GetSharePropertiesBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            share_snapshot: Some(share_snapshot),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> TimeoutSupport for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = GetSharePropertiesBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetSharePropertiesBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            share_snapshot: self.share_snapshot,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet> ClientRequestIdSupport<'a> for GetSharePropertiesBuilder<'a, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+{
+    type O = GetSharePropertiesBuilder<'a, ShareNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetSharePropertiesBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            share_snapshot: self.share_snapshot,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet> GetSharePropertiesBuilder<'a, ShareNameSet> where ShareNameSet: ToAssign {}
+
+impl<'a> GetSharePropertiesBuilder<'a, Yes> {
+    pub async fn finalize(self) -> Result<GetSharePropertiesResponse, AzureError> {
+        let mut uri = generate_share_uri(&self, Some("restype=share"));
+
+        if let Some(nm) = ShareSnapshotOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("get_share_properties uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        GetSharePropertiesResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,298 @@
+use crate::share::responses::ListSharesResponse;
+use crate::share::Share;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::parsing::{cast_optional, traverse};
+use azure_sdk_core::{
+    request_id_from_headers, ClientRequestIdOption, ClientRequestIdSupport, IncludeMetadataOption,
+    IncludeMetadataSupport, IncludeSnapshotsOption, IncludeSnapshotsSupport, MaxResultsOption,
+    MaxResultsSupport, NextMarkerOption, NextMarkerSupport, PrefixOption, PrefixSupport,
+    TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use xml::Element;
+
+#[derive(Debug, Clone)]
+pub struct ListSharesBuilder<'a> {
+    client: &'a Client,
+    prefix: Option<&'a str>,
+    next_marker: Option<&'a str>,
+    max_results: Option<u32>,
+    include_metadata: bool,
+    include_snapshots: bool,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> ListSharesBuilder<'a> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> ListSharesBuilder<'a> {
+        ListSharesBuilder {
+            client,
+            prefix: None,
+            next_marker: None,
+            max_results: None,
+            include_metadata: false,
+            include_snapshots: false,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a> ClientRequired<'a> for ListSharesBuilder<'a> {
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> PrefixOption<'a> for ListSharesBuilder<'a> {
+    #[inline]
+    fn prefix(&self) -> Option<&'a str> {
+        self.prefix
+    }
+}
+
+impl<'a> NextMarkerOption<'a> for ListSharesBuilder<'a> {
+    #[inline]
+    fn next_marker(&self) -> Option<&'a str> {
+        self.next_marker
+    }
+}
+
+impl<'a> MaxResultsOption for ListSharesBuilder<'a> {
+    #[inline]
+    fn max_results(&self) -> Option<u32> {
+        self.max_results
+    }
+}
+
+impl<'a> IncludeMetadataOption for ListSharesBuilder<'a> {
+    #[inline]
+    fn include_metadata(&self) -> bool {
+        self.include_metadata
+    }
+}
+
+impl<'a> IncludeSnapshotsOption for ListSharesBuilder<'a> {
+    #[inline]
+    fn include_snapshots(&self) -> bool {
+        self.include_snapshots
+    }
+}
+
+impl<'a> TimeoutOption for ListSharesBuilder<'a> {
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a> ClientRequestIdOption<'a> for ListSharesBuilder<'a> {
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a> PrefixSupport<'a> for ListSharesBuilder<'a> {
+    type O = ListSharesBuilder<'a>;
+
+    #[inline]
+    fn with_prefix(self, prefix: &'a str) -> Self::O {
+        ListSharesBuilder {
+            client: self.client,
+            prefix: Some(prefix),
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            include_metadata: self.include_metadata,
+            include_snapshots: self.include_snapshots,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> NextMarkerSupport<'a> for ListSharesBuilder<'a> {
+    type O = ListSharesBuilder<'a>;
+
+    #[inline]
+    fn with_next_marker(self, next_marker: &'a str) -> Self::O {
+        ListSharesBuilder {
+            client: self.client,
+            prefix: self.prefix,
+            next_marker: Some(next_marker),
+            max_results: self.max_results,
+            include_metadata: self.include_metadata,
+            include_snapshots: self.include_snapshots,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> MaxResultsSupport for ListSharesBuilder<'a> {
+    type O = ListSharesBuilder<'a>;
+
+    #[inline]
+    fn with_max_results(self, max_results: u32) -> Self::O {
+        ListSharesBuilder {
+            client: self.client,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: Some(max_results),
+            include_metadata: self.include_metadata,
+            include_snapshots: self.include_snapshots,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> IncludeMetadataSupport for ListSharesBuilder<'a> {
+    type O = ListSharesBuilder<'a>;
+
+    #[inline]
+    fn with_include_metadata(self) -> Self::O {
+        ListSharesBuilder {
+            client: self.client,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            include_metadata: true,
+            include_snapshots: self.include_snapshots,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> IncludeSnapshotsSupport for ListSharesBuilder<'a> {
+    type O = ListSharesBuilder<'a>;
+
+    #[inline]
+    fn with_include_snapshots(self) -> Self::O {
+        ListSharesBuilder {
+            client: self.client,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            include_metadata: self.include_metadata,
+            include_snapshots: true,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> TimeoutSupport for ListSharesBuilder<'a> {
+    type O = ListSharesBuilder<'a>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ListSharesBuilder {
+            client: self.client,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            include_metadata: self.include_metadata,
+            include_snapshots: self.include_snapshots,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> ClientRequestIdSupport<'a> for ListSharesBuilder<'a> {
+    type O = ListSharesBuilder<'a>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ListSharesBuilder {
+            client: self.client,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            include_metadata: self.include_metadata,
+            include_snapshots: self.include_snapshots,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a> ListSharesBuilder<'a> {}
+
+impl<'a> ListSharesBuilder<'a> {
+    pub async fn finalize(self) -> Result<ListSharesResponse, AzureError> {
+        let mut uri = format!("{}/?comp=list", self.client().file_uri());
+
+        if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = MaxResultsOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let mut include = Vec::new();
+        if self.include_metadata() {
+            include.push("metadata");
+        }
+        if self.include_snapshots() {
+            include.push("snapshots");
+        }
+        if !include.is_empty() {
+            uri = format!("{}&include={}", uri, include.join(","));
+        }
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("list_shares uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
+                .await?;
+        let incomplete_vector = incomplete_vector_from_response(&body)?;
+        let request_id = request_id_from_headers(&headers)?;
+        Ok(ListSharesResponse {
+            incomplete_vector,
+            request_id,
+        })
+    }
+}
+
+fn incomplete_vector_from_response(body: &str) -> Result<IncompleteVector<Share>, AzureError> {
+    let elem: Element = body.parse()?;
+
+    let mut v = Vec::new();
+
+    for share in traverse(&elem, &["Shares", "Share"], true)? {
+        v.push(Share::parse(share)?);
+    }
+
+    let next_marker = match cast_optional::<String>(&elem, &["NextMarker"])? {
+        Some(ref nm) if nm.is_empty() => None,
+        Some(nm) => Some(nm),
+        None => None,
+    };
+
+    Ok(IncompleteVector::new(next_marker, v))
+}
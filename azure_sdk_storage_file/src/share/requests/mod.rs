@@ -0,0 +1,12 @@
+mod create_share_builder;
+pub use self::create_share_builder::CreateShareBuilder;
+mod create_share_snapshot_builder;
+pub use self::create_share_snapshot_builder::CreateShareSnapshotBuilder;
+mod delete_share_builder;
+pub use self::delete_share_builder::DeleteShareBuilder;
+mod get_share_properties_builder;
+pub use self::get_share_properties_builder::GetSharePropertiesBuilder;
+mod list_shares_builder;
+pub use self::list_shares_builder::ListSharesBuilder;
+mod set_share_quota_builder;
+pub use self::set_share_quota_builder::SetShareQuotaBuilder;
@@ -0,0 +1,221 @@
+use crate::share::responses::SetShareQuotaResponse;
+use crate::share::{
+    generate_share_uri, ShareNameRequired, ShareNameSupport, ShareQuotaRequired, ShareQuotaSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    client: &'a Client,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_share_quota: PhantomData<ShareQuotaSet>,
+    share_name: Option<&'a str>,
+    share_quota: Option<u64>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetShareQuotaBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetShareQuotaBuilder<'a, No, No> {
+        SetShareQuotaBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_share_quota: PhantomData {},
+            share_quota: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, ShareQuotaSet> ClientRequired<'a>
+    for SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, ShareQuotaSet> ShareNameRequired<'a> for SetShareQuotaBuilder<'a, Yes, ShareQuotaSet>
+where
+    ShareQuotaSet: ToAssign,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet> ShareQuotaRequired for SetShareQuotaBuilder<'a, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+{
+    #[inline]
+    fn share_quota(&self) -> u64 {
+        self.share_quota.unwrap()
+    }
+}
+
+impl<'a, ShareNameSet, ShareQuotaSet> TimeoutOption
+    for SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ShareNameSet, ShareQuotaSet> ClientRequestIdOption<'a>
+    for SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ShareNameSet, ShareQuotaSet> ShareNameSupport<'a>
+    for SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    type O = SetShareQuotaBuilder<'a, Yes, ShareQuotaSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        SetShareQuotaBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_share_quota: PhantomData {},
+            share_name: Some(share_name),
+            share_quota: self.share_quota,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, ShareQuotaSet> ShareQuotaSupport
+    for SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    type O = SetShareQuotaBuilder<'a, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_share_quota(self, share_quota: u64) -> Self::O {
+        SetShareQuotaBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_share_quota: PhantomData {},
+            share_name: self.share_name,
+            share_quota: Some(share_quota),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, ShareQuotaSet> TimeoutSupport
+    for SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    type O = SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetShareQuotaBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_share_quota: PhantomData {},
+            share_name: self.share_name,
+            share_quota: self.share_quota,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ShareNameSet, ShareQuotaSet> ClientRequestIdSupport<'a>
+    for SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+    type O = SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetShareQuotaBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_share_quota: PhantomData {},
+            share_name: self.share_name,
+            share_quota: self.share_quota,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ShareNameSet, ShareQuotaSet> SetShareQuotaBuilder<'a, ShareNameSet, ShareQuotaSet>
+where
+    ShareNameSet: ToAssign,
+    ShareQuotaSet: ToAssign,
+{
+}
+
+impl<'a> SetShareQuotaBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<SetShareQuotaResponse, AzureError> {
+        let mut uri = generate_share_uri(&self, Some("restype=share&comp=properties"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("set_share_quota uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ShareQuotaRequired::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        SetShareQuotaResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,12 @@
+mod create_share_response;
+pub use self::create_share_response::CreateShareResponse;
+mod create_share_snapshot_response;
+pub use self::create_share_snapshot_response::CreateShareSnapshotResponse;
+mod delete_share_response;
+pub use self::delete_share_response::DeleteShareResponse;
+mod get_share_properties_response;
+pub use self::get_share_properties_response::GetSharePropertiesResponse;
+mod list_shares_response;
+pub use self::list_shares_response::ListSharesResponse;
+mod set_share_quota_response;
+pub use self::set_share_quota_response::SetShareQuotaResponse;
@@ -0,0 +1,228 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_file::directory::DirectoryEntry;
+use azure_sdk_storage_file::file::CopyStatus;
+use azure_sdk_storage_file::prelude::*;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn share_quota_and_snapshots() {
+    let share_name: &'static str = "azuresdkrustetoetsshare1";
+
+    let client = initialize().unwrap();
+
+    client
+        .create_share()
+        .with_share_name(share_name)
+        .with_share_quota(1)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .set_share_quota()
+        .with_share_name(share_name)
+        .with_share_quota(2)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_share_properties()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.quota, 2);
+
+    let snapshot = client
+        .create_share_snapshot()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .list_shares()
+        .with_prefix(share_name)
+        .with_include_snapshots()
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.incomplete_vector.len(), 2);
+    assert!(res
+        .incomplete_vector
+        .iter()
+        .any(|share| share.snapshot.as_ref() == Some(&snapshot.snapshot)));
+
+    client
+        .delete_share()
+        .with_share_name(share_name)
+        .with_include_snapshots()
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn directories_and_files() {
+    let share_name: &'static str = "azuresdkrustetoetsshare2";
+    let data = b"something interesting to say";
+
+    let client = initialize().unwrap();
+
+    client
+        .create_share()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .create_directory()
+        .with_share_name(share_name)
+        .with_directory_path("my dir")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .create_file()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .with_file_size(data.len() as u64)
+        .with_content_type("text/plain")
+        .finalize()
+        .await
+        .unwrap();
+
+    let range = Range::new(0, data.len() as u64 - 1);
+    client
+        .put_range()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .with_range(&range)
+        .with_body(&data[..])
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_file()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(&res.data[..], &data[..]);
+    assert_eq!(res.properties.content_type, Some("text/plain".to_owned()));
+
+    let range = Range::new(0, 8);
+    let res = client
+        .get_file()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .with_range(&range)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(&res.data[..], &data[0..9]);
+    assert_eq!(res.properties.content_length, data.len() as u64);
+
+    let mut metadata = HashMap::new();
+    metadata.insert("source", "e2e");
+    client
+        .set_file_metadata()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_file_metadata()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.metadata["source"], "e2e");
+
+    client
+        .set_file_properties()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .with_file_size(1024)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_file_properties()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.properties.content_length, 1024);
+
+    let source = format!("{}/{}/my%20dir/file.txt", client.file_uri(), share_name);
+    let res = client
+        .copy_file()
+        .with_share_name(share_name)
+        .with_file_path("copy.txt")
+        .with_copy_source(&source)
+        .finalize()
+        .await
+        .unwrap();
+    assert_ne!(res.copy_status, CopyStatus::Failed);
+
+    let res = client
+        .list_directory()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(res.incomplete_vector.contains(&DirectoryEntry::Directory {
+        name: "my dir".to_owned()
+    }));
+    assert!(res
+        .incomplete_vector
+        .iter()
+        .any(|entry| entry.name() == "copy.txt"));
+
+    client
+        .delete_file()
+        .with_share_name(share_name)
+        .with_file_path("my dir/file.txt")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .delete_directory()
+        .with_share_name(share_name)
+        .with_directory_path("my dir")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .delete_share()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+fn initialize() -> Result<Client, AzureError> {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    Ok(Client::new(&account, &master_key)?)
+}
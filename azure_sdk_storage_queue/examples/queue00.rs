@@ -0,0 +1,67 @@
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_queue::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let queue_name = std::env::args()
+        .nth(1)
+        .expect("please specify the queue name as command line parameter");
+
+    let client = Client::new(&account, &master_key)?;
+
+    let response = client
+        .list_queues()
+        .with_include_metadata()
+        .finalize()
+        .await?;
+    println!(
+        "List queues returned {} queues.",
+        response.incomplete_vector.len()
+    );
+    for queue in response.incomplete_vector.iter() {
+        println!("\t{}\t{:?}", queue.name, queue.metadata);
+    }
+
+    client
+        .create_queue()
+        .with_queue_name(&queue_name)
+        .finalize()
+        .await?;
+
+    let response = client
+        .put_message()
+        .with_queue_name(&queue_name)
+        .with_message_body("hello from Rust!")
+        .finalize()
+        .await?;
+    println!("put_message response == {:?}", response);
+
+    let response = client
+        .get_messages()
+        .with_queue_name(&queue_name)
+        .with_visibility_timeout(10)
+        .finalize()
+        .await?;
+
+    for message in response.messages.iter() {
+        println!("received {:?}", message);
+
+        client
+            .delete_message()
+            .with_queue_name(&queue_name)
+            .with_message_id(&message.message_id)
+            .with_pop_receipt(&message.pop_receipt)
+            .finalize()
+            .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+#![recursion_limit = "128"]
+#![allow(clippy::needless_lifetimes)]
+
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate azure_sdk_core;
+pub mod prelude;
+pub mod queue;
+use azure_sdk_core::No;
+use azure_sdk_storage_core::client::Client;
+
+pub trait Queue {
+    fn list_queues<'a>(&'a self) -> queue::requests::ListQueuesBuilder<'a>;
+    fn create_queue<'a>(&'a self) -> queue::requests::CreateQueueBuilder<'a, No>;
+    fn delete_queue<'a>(&'a self) -> queue::requests::DeleteQueueBuilder<'a, No>;
+    fn get_queue_metadata<'a>(&'a self) -> queue::requests::GetQueueMetadataBuilder<'a, No>;
+    fn set_queue_metadata<'a>(&'a self) -> queue::requests::SetQueueMetadataBuilder<'a, No>;
+    fn put_message<'a>(&'a self) -> queue::requests::PutMessageBuilder<'a, No, No>;
+    fn get_messages<'a>(&'a self) -> queue::requests::GetMessagesBuilder<'a, No>;
+    fn peek_messages<'a>(&'a self) -> queue::requests::PeekMessagesBuilder<'a, No>;
+    fn delete_message<'a>(&'a self) -> queue::requests::DeleteMessageBuilder<'a, No, No, No>;
+    fn update_message<'a>(&'a self) -> queue::requests::UpdateMessageBuilder<'a, No, No, No, No>;
+    fn clear_messages<'a>(&'a self) -> queue::requests::ClearMessagesBuilder<'a, No>;
+    fn stream_messages<'a>(&'a self) -> queue::GetMessagesStreamBuilder<'a, No>;
+}
+
+impl Queue for Client {
+    fn list_queues<'a>(&'a self) -> queue::requests::ListQueuesBuilder<'a> {
+        queue::requests::ListQueuesBuilder::new(self)
+    }
+
+    fn create_queue<'a>(&'a self) -> queue::requests::CreateQueueBuilder<'a, No> {
+        queue::requests::CreateQueueBuilder::new(self)
+    }
+
+    fn delete_queue<'a>(&'a self) -> queue::requests::DeleteQueueBuilder<'a, No> {
+        queue::requests::DeleteQueueBuilder::new(self)
+    }
+
+    fn get_queue_metadata<'a>(&'a self) -> queue::requests::GetQueueMetadataBuilder<'a, No> {
+        queue::requests::GetQueueMetadataBuilder::new(self)
+    }
+
+    fn set_queue_metadata<'a>(&'a self) -> queue::requests::SetQueueMetadataBuilder<'a, No> {
+        queue::requests::SetQueueMetadataBuilder::new(self)
+    }
+
+    fn put_message<'a>(&'a self) -> queue::requests::PutMessageBuilder<'a, No, No> {
+        queue::requests::PutMessageBuilder::new(self)
+    }
+
+    fn get_messages<'a>(&'a self) -> queue::requests::GetMessagesBuilder<'a, No> {
+        queue::requests::GetMessagesBuilder::new(self)
+    }
+
+    fn peek_messages<'a>(&'a self) -> queue::requests::PeekMessagesBuilder<'a, No> {
+        queue::requests::PeekMessagesBuilder::new(self)
+    }
+
+    fn delete_message<'a>(&'a self) -> queue::requests::DeleteMessageBuilder<'a, No, No, No> {
+        queue::requests::DeleteMessageBuilder::new(self)
+    }
+
+    fn update_message<'a>(&'a self) -> queue::requests::UpdateMessageBuilder<'a, No, No, No, No> {
+        queue::requests::UpdateMessageBuilder::new(self)
+    }
+
+    fn clear_messages<'a>(&'a self) -> queue::requests::ClearMessagesBuilder<'a, No> {
+        queue::requests::ClearMessagesBuilder::new(self)
+    }
+
+    fn stream_messages<'a>(&'a self) -> queue::GetMessagesStreamBuilder<'a, No> {
+        queue::GetMessagesStreamBuilder::new(self)
+    }
+}
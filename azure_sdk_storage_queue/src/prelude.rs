@@ -0,0 +1,5 @@
+pub use crate::queue::{
+    MessageBodySupport, MessageIdSupport, MessageTTLSupport, NumberOfMessagesSupport,
+    PopReceiptSupport, QueueNameSupport, VisibilityTimeoutSupport,
+};
+pub use crate::Queue as QueueTrait;
@@ -0,0 +1,251 @@
+use crate::queue::requests::GetMessagesBuilder;
+use crate::queue::{
+    NumberOfMessagesOption, NumberOfMessagesSupport, QueueMessage, QueueNameRequired,
+    QueueNameSupport, VisibilityTimeoutOption, VisibilityTimeoutSupport,
+};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use futures::stream::Stream;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    number_of_messages: Option<u32>,
+    visibility_timeout: Option<u64>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetMessagesStreamBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetMessagesStreamBuilder<'a, No> {
+        GetMessagesStreamBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            number_of_messages: None,
+            visibility_timeout: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequired<'a> for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> QueueNameRequired<'a> for GetMessagesStreamBuilder<'a, Yes> {
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet> NumberOfMessagesOption for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn number_of_messages(&self) -> Option<u32> {
+        self.number_of_messages
+    }
+}
+
+impl<'a, QueueNameSet> VisibilityTimeoutOption for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn visibility_timeout(&self) -> Option<u64> {
+        self.visibility_timeout
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutOption for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdOption<'a> for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, QueueNameSet> QueueNameSupport<'a> for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesStreamBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        GetMessagesStreamBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> NumberOfMessagesSupport for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesStreamBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_number_of_messages(self, number_of_messages: u32) -> Self::O {
+        GetMessagesStreamBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: Some(number_of_messages),
+            visibility_timeout: self.visibility_timeout,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> VisibilityTimeoutSupport for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesStreamBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O {
+        GetMessagesStreamBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: Some(visibility_timeout),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutSupport for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesStreamBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetMessagesStreamBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdSupport<'a> for GetMessagesStreamBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesStreamBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetMessagesStreamBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, QueueNameSet> GetMessagesStreamBuilder<'a, QueueNameSet> where QueueNameSet: ToAssign {}
+
+impl<'a> GetMessagesStreamBuilder<'a, Yes> {
+    /// Retrieves the messages of the queue in batches of `number_of_messages`
+    /// (at most 32). The stream ends as soon as the queue returns no visible
+    /// messages. Each message must be deleted before its visibility timeout
+    /// expires, otherwise it will be returned again.
+    pub fn finalize(self) -> impl Stream<Item = Result<Vec<QueueMessage>, AzureError>> + 'a {
+        let client = self.client().clone();
+        let queue_name = self.queue_name().to_owned();
+        let number_of_messages = self.number_of_messages();
+        let visibility_timeout = VisibilityTimeoutOption::visibility_timeout(&self);
+        let timeout = self.timeout();
+        let client_request_id = self.client_request_id().map(|v| v.to_owned());
+
+        futures::stream::unfold(true, move |has_more| {
+            let client = client.clone();
+            let queue_name = queue_name.clone();
+            let client_request_id = client_request_id.clone();
+
+            async move {
+                if !has_more {
+                    return None;
+                }
+
+                let mut req = GetMessagesBuilder::new(&client).with_queue_name(&queue_name);
+
+                if let Some(number_of_messages) = number_of_messages {
+                    req = req.with_number_of_messages(number_of_messages);
+                }
+                if let Some(visibility_timeout) = visibility_timeout {
+                    req = req.with_visibility_timeout(visibility_timeout);
+                }
+                if let Some(timeout) = timeout {
+                    req = req.with_timeout(timeout);
+                }
+                if let Some(ref client_request_id) = &client_request_id {
+                    req = req.with_client_request_id(client_request_id);
+                }
+
+                match req.finalize().await {
+                    Ok(response) if response.messages.is_empty() => None,
+                    Ok(response) => Some((Ok(response.messages), true)),
+                    Err(err) => Some((Err(err), false)),
+                }
+            }
+        })
+    }
+}
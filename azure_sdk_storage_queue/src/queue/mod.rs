@@ -0,0 +1,312 @@
+mod get_messages_stream_builder;
+pub use self::get_messages_stream_builder::GetMessagesStreamBuilder;
+pub mod requests;
+pub mod responses;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse};
+use azure_sdk_storage_core::ClientRequired;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use url::form_urlencoded;
+use xml::{Element, Xml};
+
+pub trait QueueNameSupport<'a> {
+    type O;
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O;
+}
+
+pub trait QueueNameRequired<'a> {
+    fn queue_name(&self) -> &'a str;
+}
+
+pub trait MessageIdSupport<'a> {
+    type O;
+    fn with_message_id(self, message_id: &'a str) -> Self::O;
+}
+
+pub trait MessageIdRequired<'a> {
+    fn message_id(&self) -> &'a str;
+}
+
+pub trait PopReceiptSupport<'a> {
+    type O;
+    fn with_pop_receipt(self, pop_receipt: &'a str) -> Self::O;
+}
+
+pub trait PopReceiptRequired<'a> {
+    fn pop_receipt(&self) -> &'a str;
+
+    fn to_uri_parameter(&self) -> String {
+        format!(
+            "popreceipt={}",
+            form_urlencoded::byte_serialize(self.pop_receipt().as_bytes()).collect::<String>()
+        )
+    }
+}
+
+pub trait VisibilityTimeoutSupport {
+    type O;
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O;
+}
+
+pub trait VisibilityTimeoutOption {
+    fn visibility_timeout(&self) -> Option<u64>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.visibility_timeout()
+            .map(|visibility_timeout| format!("visibilitytimeout={}", visibility_timeout))
+    }
+}
+
+pub trait VisibilityTimeoutRequired {
+    fn visibility_timeout(&self) -> u64;
+
+    fn to_uri_parameter(&self) -> String {
+        format!("visibilitytimeout={}", self.visibility_timeout())
+    }
+}
+
+pub trait MessageTTLSupport {
+    type O;
+    fn with_message_ttl(self, message_ttl: i64) -> Self::O;
+}
+
+pub trait MessageTTLOption {
+    /// Time to live of the message, in seconds. `-1` means the message never expires.
+    fn message_ttl(&self) -> Option<i64>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.message_ttl().map(|message_ttl| format!("messagettl={}", message_ttl))
+    }
+}
+
+pub trait NumberOfMessagesSupport {
+    type O;
+    fn with_number_of_messages(self, number_of_messages: u32) -> Self::O;
+}
+
+pub trait NumberOfMessagesOption {
+    fn number_of_messages(&self) -> Option<u32>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.number_of_messages()
+            .map(|number_of_messages| format!("numofmessages={}", number_of_messages))
+    }
+}
+
+pub trait MessageBodySupport<'a> {
+    type O;
+    fn with_message_body(self, message_body: &'a str) -> Self::O;
+}
+
+pub trait MessageBodyRequired<'a> {
+    fn message_body(&self) -> &'a str;
+
+    fn to_xml(&self) -> String {
+        message_body_to_xml(self.message_body())
+    }
+}
+
+pub trait MessageBodyOption<'a> {
+    fn message_body(&self) -> Option<&'a str>;
+
+    fn to_xml(&self) -> Option<String> {
+        self.message_body().map(message_body_to_xml)
+    }
+}
+
+// The text is sent as is (XML-escaped): callers wanting to store binary
+// data must encode it (for example in base64) beforehand.
+fn message_body_to_xml(message_body: &str) -> String {
+    format!(
+        "<QueueMessage><MessageText>{}</MessageText></QueueMessage>",
+        xml::escape(message_body)
+    )
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Queue {
+    pub name: String,
+    pub metadata: HashMap<String, String>,
+}
+
+impl Queue {
+    pub(crate) fn parse(elem: &Element) -> Result<Queue, AzureError> {
+        let name = cast_must::<String>(elem, &["Name"])?;
+
+        let mut metadata = HashMap::new();
+        for m in traverse(elem, &["Metadata"], true)? {
+            for key in &m.children {
+                if let Xml::ElementNode(elem) = key {
+                    metadata.insert(elem.name.to_owned(), elem.content_str());
+                }
+            }
+        }
+
+        Ok(Queue { name, metadata })
+    }
+}
+
+/// A message retrieved with Get Messages. The message stays invisible to
+/// the other consumers until `time_next_visible`: use `pop_receipt` to
+/// delete or update it before then.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueMessage {
+    pub message_id: String,
+    pub insertion_time: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+    pub pop_receipt: String,
+    pub time_next_visible: DateTime<Utc>,
+    pub dequeue_count: u64,
+    pub message_text: String,
+}
+
+impl QueueMessage {
+    pub(crate) fn parse(elem: &Element) -> Result<QueueMessage, AzureError> {
+        Ok(QueueMessage {
+            message_id: cast_must::<String>(elem, &["MessageId"])?,
+            insertion_time: cast_must::<DateTime<Utc>>(elem, &["InsertionTime"])?,
+            expiration_time: cast_must::<DateTime<Utc>>(elem, &["ExpirationTime"])?,
+            pop_receipt: cast_must::<String>(elem, &["PopReceipt"])?,
+            time_next_visible: cast_must::<DateTime<Utc>>(elem, &["TimeNextVisible"])?,
+            dequeue_count: cast_must::<u64>(elem, &["DequeueCount"])?,
+            message_text: cast_optional::<String>(elem, &["MessageText"])?.unwrap_or_default(),
+        })
+    }
+}
+
+/// A message retrieved with Peek Messages. Peeking does not change the
+/// visibility of the message so no pop receipt is returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeekedMessage {
+    pub message_id: String,
+    pub insertion_time: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+    pub dequeue_count: u64,
+    pub message_text: String,
+}
+
+impl PeekedMessage {
+    pub(crate) fn parse(elem: &Element) -> Result<PeekedMessage, AzureError> {
+        Ok(PeekedMessage {
+            message_id: cast_must::<String>(elem, &["MessageId"])?,
+            insertion_time: cast_must::<DateTime<Utc>>(elem, &["InsertionTime"])?,
+            expiration_time: cast_must::<DateTime<Utc>>(elem, &["ExpirationTime"])?,
+            dequeue_count: cast_must::<u64>(elem, &["DequeueCount"])?,
+            message_text: cast_optional::<String>(elem, &["MessageText"])?.unwrap_or_default(),
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn generate_queue_uri<'a, T>(t: &T, params: Option<&str>) -> String
+where
+    T: ClientRequired<'a> + QueueNameRequired<'a>,
+{
+    match params {
+        Some(ref params) => format!(
+            "{}/{}?{}",
+            t.client().queue_uri(),
+            form_urlencoded::byte_serialize(t.queue_name().as_bytes()).collect::<String>(),
+            params
+        ),
+        None => format!(
+            "{}/{}",
+            t.client().queue_uri(),
+            form_urlencoded::byte_serialize(t.queue_name().as_bytes()).collect::<String>(),
+        ),
+    }
+}
+
+#[inline]
+pub(crate) fn generate_messages_uri<'a, T>(t: &T, params: Option<&str>) -> String
+where
+    T: ClientRequired<'a> + QueueNameRequired<'a>,
+{
+    match params {
+        Some(ref params) => format!("{}/messages?{}", generate_queue_uri(t, None), params),
+        None => format!("{}/messages", generate_queue_uri(t, None)),
+    }
+}
+
+#[inline]
+pub(crate) fn generate_message_uri<'a, T>(t: &T, params: Option<&str>) -> String
+where
+    T: ClientRequired<'a> + QueueNameRequired<'a> + MessageIdRequired<'a>,
+{
+    let uri = format!(
+        "{}/{}",
+        generate_messages_uri(t, None),
+        form_urlencoded::byte_serialize(t.message_id().as_bytes()).collect::<String>()
+    );
+
+    match params {
+        Some(ref params) => format!("{}?{}", uri, params),
+        None => uri,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_queue_message() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<QueueMessagesList>
+  <QueueMessage>
+    <MessageId>5974b586-0df3-4e2d-ad0c-18e3892bfca2</MessageId>
+    <InsertionTime>Fri, 09 Oct 2009 21:04:30 GMT</InsertionTime>
+    <ExpirationTime>Fri, 16 Oct 2009 21:04:30 GMT</ExpirationTime>
+    <PopReceipt>YzQ4Yzg1MDItYTc0Ny00OWNjLTkxYTUtZGM0MDFiZDAwYzEw</PopReceipt>
+    <TimeNextVisible>Fri, 09 Oct 2009 23:29:20 GMT</TimeNextVisible>
+    <DequeueCount>1</DequeueCount>
+    <MessageText>hello &amp; goodbye</MessageText>
+  </QueueMessage>
+</QueueMessagesList>";
+
+        let elem: Element = body.parse().unwrap();
+        let messages = traverse(&elem, &["QueueMessage"], true).unwrap();
+        assert_eq!(messages.len(), 1);
+
+        let message = QueueMessage::parse(messages[0]).unwrap();
+        assert_eq!(message.message_id, "5974b586-0df3-4e2d-ad0c-18e3892bfca2");
+        assert_eq!(
+            message.pop_receipt,
+            "YzQ4Yzg1MDItYTc0Ny00OWNjLTkxYTUtZGM0MDFiZDAwYzEw"
+        );
+        assert_eq!(message.dequeue_count, 1);
+        assert_eq!(message.message_text, "hello & goodbye");
+        assert_eq!(
+            message.time_next_visible,
+            DateTime::parse_from_rfc2822("Fri, 09 Oct 2009 23:29:20 GMT")
+                .unwrap()
+                .with_timezone(&Utc)
+        );
+    }
+
+    #[test]
+    fn parse_queue_with_metadata() {
+        let body = "<Queue>
+  <Name>myqueue</Name>
+  <Metadata>
+    <color>red</color>
+    <size>large</size>
+  </Metadata>
+</Queue>";
+
+        let elem: Element = body.parse().unwrap();
+        let queue = Queue::parse(&elem).unwrap();
+        assert_eq!(queue.name, "myqueue");
+        assert_eq!(queue.metadata.len(), 2);
+        assert_eq!(queue.metadata["color"], "red");
+    }
+
+    #[test]
+    fn message_body_is_escaped() {
+        assert_eq!(
+            message_body_to_xml("<a & b>"),
+            "<QueueMessage><MessageText>&lt;a &amp; b&gt;</MessageText></QueueMessage>"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::queue::responses::ClearMessagesResponse;
+use crate::queue::{generate_messages_uri, QueueNameRequired, QueueNameSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ClearMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> ClearMessagesBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> ClearMessagesBuilder<'a, No> {
+        ClearMessagesBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequired<'a> for ClearMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> QueueNameRequired<'a> for ClearMessagesBuilder<'a, Yes> {
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutOption for ClearMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdOption<'a> for ClearMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, QueueNameSet> QueueNameSupport<'a> for ClearMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = ClearMessagesBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        ClearMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutSupport for ClearMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = ClearMessagesBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ClearMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdSupport<'a> for ClearMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = ClearMessagesBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ClearMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, QueueNameSet> ClearMessagesBuilder<'a, QueueNameSet> where QueueNameSet: ToAssign {}
+
+impl<'a> ClearMessagesBuilder<'a, Yes> {
+    pub async fn finalize(self) -> Result<ClearMessagesResponse, AzureError> {
+        let mut uri = generate_messages_uri(&self, None);
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        trace!("clear_messages uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        ClearMessagesResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,203 @@
+use crate::queue::responses::CreateQueueResponse;
+use crate::queue::{generate_queue_uri, QueueNameRequired, QueueNameSupport};
+use azure_sdk_core::errors::{
+    extract_status_headers_and_body, unexpected_status_error, AzureError,
+};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, MetadataOption, MetadataSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> CreateQueueBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> CreateQueueBuilder<'a, No> {
+        CreateQueueBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            metadata: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequired<'a> for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> QueueNameRequired<'a> for CreateQueueBuilder<'a, Yes> {
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet> MetadataOption<'a> for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutOption for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdOption<'a> for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, QueueNameSet> QueueNameSupport<'a> for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = CreateQueueBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        CreateQueueBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> MetadataSupport<'a> for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = CreateQueueBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateQueueBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            metadata: Some(metadata),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutSupport for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = CreateQueueBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateQueueBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            metadata: self.metadata,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdSupport<'a> for CreateQueueBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = CreateQueueBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateQueueBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            metadata: self.metadata,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, QueueNameSet> CreateQueueBuilder<'a, QueueNameSet> where QueueNameSet: ToAssign {}
+
+impl<'a> CreateQueueBuilder<'a, Yes> {
+    pub async fn finalize(self) -> Result<CreateQueueResponse, AzureError> {
+        let mut uri = generate_queue_uri(&self, None);
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        trace!("create_queue uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                MetadataOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(&[]),
+        )?;
+
+        // the service answers 204 if the queue already exists with the same metadata
+        let (status, headers, body) = extract_status_headers_and_body(future_response).await?;
+        if status != StatusCode::CREATED && status != StatusCode::NO_CONTENT {
+            return Err(unexpected_status_error(
+                StatusCode::CREATED,
+                status,
+                &headers,
+                std::str::from_utf8(&body)?,
+            ));
+        }
+
+        CreateQueueResponse::from_headers(&headers, status == StatusCode::CREATED)
+    }
+}
@@ -0,0 +1,285 @@
+use crate::queue::responses::DeleteMessageResponse;
+use crate::queue::{
+    generate_message_uri, MessageIdRequired, MessageIdSupport, PopReceiptRequired,
+    PopReceiptSupport, QueueNameRequired, QueueNameSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    client: &'a Client,
+    p_queue_name: PhantomData<QueueNameSet>,
+    p_message_id: PhantomData<MessageIdSet>,
+    p_pop_receipt: PhantomData<PopReceiptSet>,
+    queue_name: Option<&'a str>,
+    message_id: Option<&'a str>,
+    pop_receipt: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> DeleteMessageBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> DeleteMessageBuilder<'a, No, No, No> {
+        DeleteMessageBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            p_message_id: PhantomData {},
+            message_id: None,
+            p_pop_receipt: PhantomData {},
+            pop_receipt: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> ClientRequired<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, MessageIdSet, PopReceiptSet> QueueNameRequired<'a>
+    for DeleteMessageBuilder<'a, Yes, MessageIdSet, PopReceiptSet>
+where
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, PopReceiptSet> MessageIdRequired<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, Yes, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    #[inline]
+    fn message_id(&self) -> &'a str {
+        self.message_id.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet> PopReceiptRequired<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, Yes>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+{
+    #[inline]
+    fn pop_receipt(&self) -> &'a str {
+        self.pop_receipt.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> TimeoutOption
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> ClientRequestIdOption<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> QueueNameSupport<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    type O = DeleteMessageBuilder<'a, Yes, MessageIdSet, PopReceiptSet>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: Some(queue_name),
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> MessageIdSupport<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    type O = DeleteMessageBuilder<'a, QueueNameSet, Yes, PopReceiptSet>;
+
+    #[inline]
+    fn with_message_id(self, message_id: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: Some(message_id),
+            pop_receipt: self.pop_receipt,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> PopReceiptSupport<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    type O = DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, Yes>;
+
+    #[inline]
+    fn with_pop_receipt(self, pop_receipt: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: Some(pop_receipt),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> TimeoutSupport
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    type O = DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> ClientRequestIdSupport<'a>
+    for DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    type O = DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+    DeleteMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+}
+
+impl<'a> DeleteMessageBuilder<'a, Yes, Yes, Yes> {
+    pub async fn finalize(self) -> Result<DeleteMessageResponse, AzureError> {
+        let mut uri =
+            generate_message_uri(&self, Some(&PopReceiptRequired::to_uri_parameter(&self)));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("delete_message uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        DeleteMessageResponse::from_headers(&headers)
+    }
+}
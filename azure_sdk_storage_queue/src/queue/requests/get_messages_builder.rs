@@ -0,0 +1,242 @@
+use crate::queue::responses::GetMessagesResponse;
+use crate::queue::{
+    generate_messages_uri, NumberOfMessagesOption, NumberOfMessagesSupport, QueueNameRequired,
+    QueueNameSupport, VisibilityTimeoutOption, VisibilityTimeoutSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    number_of_messages: Option<u32>,
+    visibility_timeout: Option<u64>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetMessagesBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetMessagesBuilder<'a, No> {
+        GetMessagesBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            number_of_messages: None,
+            visibility_timeout: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequired<'a> for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> QueueNameRequired<'a> for GetMessagesBuilder<'a, Yes> {
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet> NumberOfMessagesOption for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn number_of_messages(&self) -> Option<u32> {
+        self.number_of_messages
+    }
+}
+
+impl<'a, QueueNameSet> VisibilityTimeoutOption for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn visibility_timeout(&self) -> Option<u64> {
+        self.visibility_timeout
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutOption for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdOption<'a> for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, QueueNameSet> QueueNameSupport<'a> for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> NumberOfMessagesSupport for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_number_of_messages(self, number_of_messages: u32) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: Some(number_of_messages),
+            visibility_timeout: self.visibility_timeout,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> VisibilityTimeoutSupport for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: Some(visibility_timeout),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> TimeoutSupport for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet> ClientRequestIdSupport<'a> for GetMessagesBuilder<'a, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+{
+    type O = GetMessagesBuilder<'a, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, QueueNameSet> GetMessagesBuilder<'a, QueueNameSet> where QueueNameSet: ToAssign {}
+
+impl<'a> GetMessagesBuilder<'a, Yes> {
+    /// Retrieves up to `number_of_messages` messages (one by default) and
+    /// hides them from the other consumers for `visibility_timeout` seconds
+    /// (30 by default).
+    pub async fn finalize(self) -> Result<GetMessagesResponse, AzureError> {
+        let mut uri = generate_messages_uri(&self, None);
+
+        let mut params = Vec::new();
+        if let Some(nm) = NumberOfMessagesOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if !params.is_empty() {
+            uri = format!("{}?{}", uri, params.join("&"));
+        }
+
+        trace!("get_messages uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
+                .await?;
+        GetMessagesResponse::from_response(&headers, &body)
+    }
+}
@@ -0,0 +1,22 @@
+mod clear_messages_builder;
+pub use self::clear_messages_builder::ClearMessagesBuilder;
+mod create_queue_builder;
+pub use self::create_queue_builder::CreateQueueBuilder;
+mod delete_message_builder;
+pub use self::delete_message_builder::DeleteMessageBuilder;
+mod delete_queue_builder;
+pub use self::delete_queue_builder::DeleteQueueBuilder;
+mod get_messages_builder;
+pub use self::get_messages_builder::GetMessagesBuilder;
+mod get_queue_metadata_builder;
+pub use self::get_queue_metadata_builder::GetQueueMetadataBuilder;
+mod list_queues_builder;
+pub use self::list_queues_builder::ListQueuesBuilder;
+mod peek_messages_builder;
+pub use self::peek_messages_builder::PeekMessagesBuilder;
+mod put_message_builder;
+pub use self::put_message_builder::PutMessageBuilder;
+mod set_queue_metadata_builder;
+pub use self::set_queue_metadata_builder::SetQueueMetadataBuilder;
+mod update_message_builder;
+pub use self::update_message_builder::UpdateMessageBuilder;
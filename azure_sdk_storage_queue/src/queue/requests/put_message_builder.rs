@@ -0,0 +1,319 @@
+use crate::queue::responses::PutMessageResponse;
+use crate::queue::{
+    generate_messages_uri, MessageBodyRequired, MessageBodySupport, MessageTTLOption,
+    MessageTTLSupport, QueueNameRequired, QueueNameSupport, VisibilityTimeoutOption,
+    VisibilityTimeoutSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    client: &'a Client,
+    p_queue_name: PhantomData<QueueNameSet>,
+    p_message_body: PhantomData<MessageBodySet>,
+    queue_name: Option<&'a str>,
+    message_body: Option<&'a str>,
+    visibility_timeout: Option<u64>,
+    message_ttl: Option<i64>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> PutMessageBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> PutMessageBuilder<'a, No, No> {
+        PutMessageBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            p_message_body: PhantomData {},
+            message_body: None,
+            visibility_timeout: None,
+            message_ttl: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> ClientRequired<'a>
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, MessageBodySet> QueueNameRequired<'a> for PutMessageBuilder<'a, Yes, MessageBodySet>
+where
+    MessageBodySet: ToAssign,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet> MessageBodyRequired<'a> for PutMessageBuilder<'a, QueueNameSet, Yes>
+where
+    QueueNameSet: ToAssign,
+{
+    #[inline]
+    fn message_body(&self) -> &'a str {
+        self.message_body.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> VisibilityTimeoutOption
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    #[inline]
+    fn visibility_timeout(&self) -> Option<u64> {
+        self.visibility_timeout
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> MessageTTLOption
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    #[inline]
+    fn message_ttl(&self) -> Option<i64> {
+        self.message_ttl
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> TimeoutOption
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> ClientRequestIdOption<'a>
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> QueueNameSupport<'a>
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    type O = PutMessageBuilder<'a, Yes, MessageBodySet>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_body: PhantomData {},
+            queue_name: Some(queue_name),
+            message_body: self.message_body,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> MessageBodySupport<'a>
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    type O = PutMessageBuilder<'a, QueueNameSet, Yes>;
+
+    #[inline]
+    fn with_message_body(self, message_body: &'a str) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_body: PhantomData {},
+            queue_name: self.queue_name,
+            message_body: Some(message_body),
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> VisibilityTimeoutSupport
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    type O = PutMessageBuilder<'a, QueueNameSet, MessageBodySet>;
+
+    #[inline]
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_body: PhantomData {},
+            queue_name: self.queue_name,
+            message_body: self.message_body,
+            visibility_timeout: Some(visibility_timeout),
+            message_ttl: self.message_ttl,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> MessageTTLSupport
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    type O = PutMessageBuilder<'a, QueueNameSet, MessageBodySet>;
+
+    #[inline]
+    fn with_message_ttl(self, message_ttl: i64) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_body: PhantomData {},
+            queue_name: self.queue_name,
+            message_body: self.message_body,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: Some(message_ttl),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> TimeoutSupport
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    type O = PutMessageBuilder<'a, QueueNameSet, MessageBodySet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_body: PhantomData {},
+            queue_name: self.queue_name,
+            message_body: self.message_body,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageBodySet> ClientRequestIdSupport<'a>
+    for PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+    type O = PutMessageBuilder<'a, QueueNameSet, MessageBodySet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_body: PhantomData {},
+            queue_name: self.queue_name,
+            message_body: self.message_body,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, QueueNameSet, MessageBodySet> PutMessageBuilder<'a, QueueNameSet, MessageBodySet>
+where
+    QueueNameSet: ToAssign,
+    MessageBodySet: ToAssign,
+{
+}
+
+impl<'a> PutMessageBuilder<'a, Yes, Yes> {
+    pub async fn finalize(self) -> Result<PutMessageResponse, AzureError> {
+        let mut uri = generate_messages_uri(&self, None);
+
+        let mut params = Vec::new();
+        if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = MessageTTLOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if !params.is_empty() {
+            uri = format!("{}?{}", uri, params.join("&"));
+        }
+
+        trace!("put_message uri == {:?}", uri);
+
+        let body = MessageBodyRequired::to_xml(&self);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::POST,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(body.as_bytes()),
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::CREATED)
+                .await?;
+        PutMessageResponse::from_response(&headers, &body)
+    }
+}
@@ -0,0 +1,418 @@
+use crate::queue::responses::UpdateMessageResponse;
+use crate::queue::{
+    generate_message_uri, MessageBodyOption, MessageBodySupport, MessageIdRequired,
+    MessageIdSupport, PopReceiptRequired, PopReceiptSupport, QueueNameRequired, QueueNameSupport,
+    VisibilityTimeoutRequired, VisibilityTimeoutSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    client: &'a Client,
+    p_queue_name: PhantomData<QueueNameSet>,
+    p_message_id: PhantomData<MessageIdSet>,
+    p_pop_receipt: PhantomData<PopReceiptSet>,
+    p_visibility_timeout: PhantomData<VisibilityTimeoutSet>,
+    queue_name: Option<&'a str>,
+    message_id: Option<&'a str>,
+    pop_receipt: Option<&'a str>,
+    visibility_timeout: Option<u64>,
+    message_body: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> UpdateMessageBuilder<'a, No, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> UpdateMessageBuilder<'a, No, No, No, No> {
+        UpdateMessageBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            p_message_id: PhantomData {},
+            message_id: None,
+            p_pop_receipt: PhantomData {},
+            pop_receipt: None,
+            p_visibility_timeout: PhantomData {},
+            visibility_timeout: None,
+            message_body: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> ClientRequired<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> QueueNameRequired<'a>
+    for UpdateMessageBuilder<'a, Yes, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, PopReceiptSet, VisibilityTimeoutSet> MessageIdRequired<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, Yes, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    #[inline]
+    fn message_id(&self) -> &'a str {
+        self.message_id.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, VisibilityTimeoutSet> PopReceiptRequired<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, Yes, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    #[inline]
+    fn pop_receipt(&self) -> &'a str {
+        self.pop_receipt.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet> VisibilityTimeoutRequired
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, Yes>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+{
+    #[inline]
+    fn visibility_timeout(&self) -> u64 {
+        self.visibility_timeout.unwrap()
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> MessageBodyOption<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    #[inline]
+    fn message_body(&self) -> Option<&'a str> {
+        self.message_body
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> TimeoutOption
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> ClientRequestIdOption<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> QueueNameSupport<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    type O = UpdateMessageBuilder<'a, Yes, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: Some(queue_name),
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_body: self.message_body,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> MessageIdSupport<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    type O = UpdateMessageBuilder<'a, QueueNameSet, Yes, PopReceiptSet, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_message_id(self, message_id: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: Some(message_id),
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_body: self.message_body,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> PopReceiptSupport<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    type O = UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, Yes, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_pop_receipt(self, pop_receipt: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: Some(pop_receipt),
+            visibility_timeout: self.visibility_timeout,
+            message_body: self.message_body,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> VisibilityTimeoutSupport
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    type O = UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, Yes>;
+
+    #[inline]
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: Some(visibility_timeout),
+            message_body: self.message_body,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> MessageBodySupport<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    type O =
+        UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_message_body(self, message_body: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_body: Some(message_body),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> TimeoutSupport
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    type O =
+        UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_body: self.message_body,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> ClientRequestIdSupport<'a>
+    for UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+    type O =
+        UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_body: self.message_body,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+    UpdateMessageBuilder<'a, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+{
+}
+
+impl<'a> UpdateMessageBuilder<'a, Yes, Yes, Yes, Yes> {
+    /// Changes the visibility timeout of the message and, optionally, its
+    /// text. The returned pop receipt replaces the one used in this call.
+    pub async fn finalize(self) -> Result<UpdateMessageResponse, AzureError> {
+        let mut uri = generate_message_uri(
+            &self,
+            Some(&format!(
+                "{}&{}",
+                PopReceiptRequired::to_uri_parameter(&self),
+                VisibilityTimeoutRequired::to_uri_parameter(&self)
+            )),
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("update_message uri == {:?}", uri);
+
+        let body = MessageBodyOption::to_xml(&self);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(body.as_ref().map(|body| body.as_bytes()).unwrap_or(&[])),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        UpdateMessageResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,25 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateQueueResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    /// `false` if the queue already existed with the same metadata.
+    pub created: bool,
+}
+
+impl CreateQueueResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+        created: bool,
+    ) -> Result<CreateQueueResponse, AzureError> {
+        Ok(CreateQueueResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            created,
+        })
+    }
+}
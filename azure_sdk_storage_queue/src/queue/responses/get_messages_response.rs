@@ -0,0 +1,34 @@
+use crate::queue::QueueMessage;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::traverse;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use xml::Element;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetMessagesResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub messages: Vec<QueueMessage>,
+}
+
+impl GetMessagesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<GetMessagesResponse, AzureError> {
+        let elem: Element = body.parse()?;
+
+        let mut messages = Vec::new();
+        for message in traverse(&elem, &["QueueMessage"], true)? {
+            messages.push(QueueMessage::parse(message)?);
+        }
+
+        Ok(GetMessagesResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            messages,
+        })
+    }
+}
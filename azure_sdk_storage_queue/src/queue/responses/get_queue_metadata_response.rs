@@ -0,0 +1,40 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::META_PREFIX;
+use azure_sdk_core::{
+    approximate_messages_count_from_headers, date_from_headers, request_id_from_headers, RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetQueueMetadataResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub approximate_messages_count: u64,
+    /// The metadata keys, without the `x-ms-meta-` prefix.
+    pub metadata: HashMap<String, String>,
+}
+
+impl GetQueueMetadataResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+    ) -> Result<GetQueueMetadataResponse, AzureError> {
+        let mut metadata = HashMap::new();
+        for (key, value) in headers {
+            if key.as_str().starts_with(META_PREFIX) {
+                metadata.insert(
+                    key.as_str()[META_PREFIX.len()..].to_owned(),
+                    value.to_str()?.to_owned(),
+                );
+            }
+        }
+
+        Ok(GetQueueMetadataResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            approximate_messages_count: approximate_messages_count_from_headers(headers)?,
+            metadata,
+        })
+    }
+}
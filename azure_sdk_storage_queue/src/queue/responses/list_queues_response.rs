@@ -0,0 +1,15 @@
+use crate::queue::Queue;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::RequestId;
+
+#[derive(Debug, Clone)]
+pub struct ListQueuesResponse {
+    pub incomplete_vector: IncompleteVector<Queue>,
+    pub request_id: RequestId,
+}
+
+impl ListQueuesResponse {
+    pub fn is_complete(&self) -> bool {
+        self.incomplete_vector.is_complete()
+    }
+}
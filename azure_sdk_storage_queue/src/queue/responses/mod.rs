@@ -0,0 +1,22 @@
+mod clear_messages_response;
+pub use self::clear_messages_response::ClearMessagesResponse;
+mod create_queue_response;
+pub use self::create_queue_response::CreateQueueResponse;
+mod delete_message_response;
+pub use self::delete_message_response::DeleteMessageResponse;
+mod delete_queue_response;
+pub use self::delete_queue_response::DeleteQueueResponse;
+mod get_messages_response;
+pub use self::get_messages_response::GetMessagesResponse;
+mod get_queue_metadata_response;
+pub use self::get_queue_metadata_response::GetQueueMetadataResponse;
+mod list_queues_response;
+pub use self::list_queues_response::ListQueuesResponse;
+mod peek_messages_response;
+pub use self::peek_messages_response::PeekMessagesResponse;
+mod put_message_response;
+pub use self::put_message_response::PutMessageResponse;
+mod set_queue_metadata_response;
+pub use self::set_queue_metadata_response::SetQueueMetadataResponse;
+mod update_message_response;
+pub use self::update_message_response::UpdateMessageResponse;
@@ -0,0 +1,34 @@
+use crate::queue::PeekedMessage;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::traverse;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use xml::Element;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeekMessagesResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub messages: Vec<PeekedMessage>,
+}
+
+impl PeekMessagesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<PeekMessagesResponse, AzureError> {
+        let elem: Element = body.parse()?;
+
+        let mut messages = Vec::new();
+        for message in traverse(&elem, &["QueueMessage"], true)? {
+            messages.push(PeekedMessage::parse(message)?);
+        }
+
+        Ok(PeekMessagesResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            messages,
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, traverse_single_must};
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use xml::Element;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PutMessageResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub message_id: String,
+    pub insertion_time: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+    pub pop_receipt: String,
+    pub time_next_visible: DateTime<Utc>,
+}
+
+impl PutMessageResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<PutMessageResponse, AzureError> {
+        let elem: Element = body.parse()?;
+        let message = traverse_single_must(&elem, &["QueueMessage"])?;
+
+        Ok(PutMessageResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            message_id: cast_must::<String>(message, &["MessageId"])?,
+            insertion_time: cast_must::<DateTime<Utc>>(message, &["InsertionTime"])?,
+            expiration_time: cast_must::<DateTime<Utc>>(message, &["ExpirationTime"])?,
+            pop_receipt: cast_must::<String>(message, &["PopReceipt"])?,
+            time_next_visible: cast_must::<DateTime<Utc>>(message, &["TimeNextVisible"])?,
+        })
+    }
+}
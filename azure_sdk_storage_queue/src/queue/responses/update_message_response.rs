@@ -0,0 +1,10 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::RequestId;
+use chrono::{DateTime, Utc};
+
+response_from_headers!(UpdateMessageResponse ,
+                       pop_receipt_from_headers -> pop_receipt: String,
+                       time_next_visible_from_headers -> time_next_visible: DateTime<Utc>,
+                       request_id_from_headers -> request_id: RequestId,
+                       date_from_headers -> date: DateTime<Utc>
+);
@@ -0,0 +1,177 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_queue::prelude::*;
+use futures::stream::StreamExt;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn create_and_delete_queue() {
+    let queue_name: &'static str = "azuresdkrustetoetsq1";
+
+    let client = initialize().unwrap();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("source", "e2e");
+
+    let res = client
+        .create_queue()
+        .with_queue_name(queue_name)
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(res.created);
+
+    let res = client
+        .list_queues()
+        .with_prefix(queue_name)
+        .with_include_metadata()
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.incomplete_vector.len(), 1);
+    assert_eq!(res.incomplete_vector[0].metadata["source"], "e2e");
+
+    let mut metadata = HashMap::new();
+    metadata.insert("source", "e2e-updated");
+
+    client
+        .set_queue_metadata()
+        .with_queue_name(queue_name)
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    let res = client
+        .get_queue_metadata()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.metadata["source"], "e2e-updated");
+    assert_eq!(res.approximate_messages_count, 0);
+
+    client
+        .delete_queue()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn messages() {
+    let queue_name: &'static str = "azuresdkrustetoetsq2";
+
+    let client = initialize().unwrap();
+    client
+        .create_queue()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    for i in 0..3 {
+        client
+            .put_message()
+            .with_queue_name(queue_name)
+            .with_message_body(&format!("message <{}>", i))
+            .with_message_ttl(600)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    let res = client
+        .peek_messages()
+        .with_queue_name(queue_name)
+        .with_number_of_messages(32)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.messages.len(), 3);
+    assert!(res
+        .messages
+        .iter()
+        .any(|message| message.message_text == "message <0>"));
+
+    let res = client
+        .get_messages()
+        .with_queue_name(queue_name)
+        .with_visibility_timeout(60)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let message = &res.messages[0];
+
+    let res = client
+        .update_message()
+        .with_queue_name(queue_name)
+        .with_message_id(&message.message_id)
+        .with_pop_receipt(&message.pop_receipt)
+        .with_visibility_timeout(0)
+        .with_message_body("updated")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .delete_message()
+        .with_queue_name(queue_name)
+        .with_message_id(&message.message_id)
+        .with_pop_receipt(&res.pop_receipt)
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut stream = Box::pin(
+        client
+            .stream_messages()
+            .with_queue_name(queue_name)
+            .with_number_of_messages(32)
+            .finalize(),
+    );
+    let mut received = 0;
+    while let Some(messages) = stream.next().await {
+        let messages = messages.unwrap();
+        for message in messages.iter() {
+            client
+                .delete_message()
+                .with_queue_name(queue_name)
+                .with_message_id(&message.message_id)
+                .with_pop_receipt(&message.pop_receipt)
+                .finalize()
+                .await
+                .unwrap();
+        }
+        received += messages.len();
+    }
+    assert_eq!(received, 2);
+
+    client
+        .clear_messages()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .delete_queue()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+fn initialize() -> Result<Client, AzureError> {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    Ok(Client::new(&account, &master_key)?)
+}
@@ -5,13 +5,13 @@ use crate::TableEntry;
 use azure_sdk_core::errors::{
     check_status_extract_body, check_status_extract_headers_and_body, AzureError,
 };
+use azure_sdk_core::ResponseFuture;
 use azure_sdk_storage_core::client::Client;
 use azure_sdk_storage_core::{
     get_default_json_mime, get_json_mime_fullmetadata, get_json_mime_nometadata, ServiceType,
 };
 use futures::stream::Stream;
 use http::HeaderMap;
-use hyper::client::ResponseFuture;
 use hyper::header::{self, HeaderValue};
 use hyper::{Method, StatusCode};
 use serde::de::DeserializeOwned;
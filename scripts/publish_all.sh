@@ -26,6 +26,14 @@ cd azure_sdk_storage_table
 cargo publish
 cd ..
 
+cd azure_sdk_storage_queue
+cargo publish
+cd ..
+
+cd azure_sdk_storage_file
+cargo publish
+cd ..
+
 cd azure_sdk_auth_aad
 cargo publish
 cd ..